@@ -0,0 +1,302 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Blend modes from the W3C Compositing and Blending specification.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Alpha;
+use crate::Rgb;
+use crate::utility::clamped;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use tracing::Level;
+use tracing::span;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// BlendMode
+////////////////////////////////////////////////////////////////////////////////
+/// A blend mode, describing how the colors of a source layer are mixed with
+/// the colors of the backdrop beneath it.
+///
+/// The blend modes follow the [W3C Compositing and Blending] specification,
+/// and operate on gamma-encoded sRGB components, matching CSS and most image
+/// editors.
+///
+/// [W3C Compositing and Blending]: https://www.w3.org/TR/compositing-1/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BlendMode {
+    /// The source color is used.
+    #[default]
+    Normal,
+    /// The source and backdrop colors are multiplied.
+    Multiply,
+    /// The complements of the source and backdrop colors are multiplied and
+    /// complemented.
+    Screen,
+    /// Multiplies or screens the colors, depending on the backdrop color.
+    Overlay,
+    /// The darker of the source and backdrop colors is used.
+    Darken,
+    /// The lighter of the source and backdrop colors is used.
+    Lighten,
+    /// Brightens the backdrop color to reflect the source color.
+    ColorDodge,
+    /// Darkens the backdrop color to reflect the source color.
+    ColorBurn,
+    /// Multiplies or screens the colors, depending on the source color.
+    HardLight,
+    /// Darkens or lightens the colors, depending on the source color.
+    SoftLight,
+    /// Subtracts the darker of the source and backdrop colors from the
+    /// lighter.
+    Difference,
+    /// Like `Difference`, but with lower contrast.
+    Exclusion,
+    /// Uses the hue of the source color with the saturation and luminosity of
+    /// the backdrop color.
+    Hue,
+    /// Uses the saturation of the source color with the hue and luminosity of
+    /// the backdrop color.
+    Saturation,
+    /// Uses the hue and saturation of the source color with the luminosity of
+    /// the backdrop color.
+    Color,
+    /// Uses the luminosity of the source color with the hue and saturation of
+    /// the backdrop color.
+    Luminosity,
+}
+
+
+impl BlendMode {
+    /// Returns whether the blend mode operates on each color component
+    /// independently.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::BlendMode;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert!(BlendMode::Multiply.is_separable());
+    /// assert!(!BlendMode::Luminosity.is_separable());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn is_separable(&self) -> bool {
+        use BlendMode::*;
+        !matches!(self, Hue | Saturation | Color | Luminosity)
+    }
+
+    /// Applies the blend function to the given `[R, G, B]` component ratios of
+    /// the `backdrop` and `source` colors, ignoring alpha.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::BlendMode;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let backdrop = [0.5, 0.2, 1.0];
+    /// let source = [0.5, 1.0, 0.0];
+    ///
+    /// assert_eq!(BlendMode::Multiply.blend_ratios(backdrop, source),
+    ///     [0.25, 0.2, 0.0]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn blend_ratios(&self, backdrop: [f32; 3], source: [f32; 3])
+        -> [f32; 3]
+    {
+        use BlendMode::*;
+        let (cb, cs) = (backdrop, source);
+        match self {
+            Hue => set_lum(set_sat(cs, sat(cb)), lum(cb)),
+            Saturation => set_lum(set_sat(cb, sat(cs)), lum(cb)),
+            Color => set_lum(cs, lum(cb)),
+            Luminosity => set_lum(cb, lum(cs)),
+            _ => [
+                self.blend_separable(cb[0], cs[0]),
+                self.blend_separable(cb[1], cs[1]),
+                self.blend_separable(cb[2], cs[2]),
+            ],
+        }
+    }
+
+    /// Applies a separable blend function to a single component.
+    fn blend_separable(&self, cb: f32, cs: f32) -> f32 {
+        use BlendMode::*;
+        match self {
+            Normal => cs,
+            Multiply => cb * cs,
+            Screen => cb + cs - cb * cs,
+            Overlay => HardLight.blend_separable(cs, cb),
+            Darken => cb.min(cs),
+            Lighten => cb.max(cs),
+            ColorDodge => if cb <= 0.0 {
+                0.0
+            } else if cs >= 1.0 {
+                1.0
+            } else {
+                (cb / (1.0 - cs)).min(1.0)
+            },
+            ColorBurn => if cb >= 1.0 {
+                1.0
+            } else if cs <= 0.0 {
+                0.0
+            } else {
+                1.0 - ((1.0 - cb) / cs).min(1.0)
+            },
+            HardLight => if cs <= 0.5 {
+                Multiply.blend_separable(cb, 2.0 * cs)
+            } else {
+                Screen.blend_separable(cb, 2.0 * cs - 1.0)
+            },
+            SoftLight => if cs <= 0.5 {
+                cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+            } else {
+                let d = if cb <= 0.25 {
+                    ((16.0 * cb - 12.0) * cb + 4.0) * cb
+                } else {
+                    cb.sqrt()
+                };
+                cb + (2.0 * cs - 1.0) * (d - cb)
+            },
+            Difference => (cb - cs).abs(),
+            Exclusion => cb + cs - 2.0 * cb * cs,
+            Hue | Saturation | Color | Luminosity => unreachable!(
+                "non-separable blend mode applied to a single component"),
+        }
+    }
+
+    /// Blends the `source` color onto the `backdrop` color, then composites
+    /// the result over the backdrop.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ BlendMode, Rgb, Rgba };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let backdrop = Rgba::opaque(Rgb::new(200, 100, 50));
+    /// let source = Rgba::opaque(Rgb::new(128, 255, 0));
+    ///
+    /// let result = BlendMode::Multiply.blend(backdrop, source);
+    ///
+    /// assert_eq!(result, Rgba::opaque(Rgb::new(100, 100, 0)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn blend(&self, backdrop: Alpha<Rgb>, source: Alpha<Rgb>)
+        -> Alpha<Rgb>
+    {
+        let span = span!(Level::DEBUG, "BlendMode::blend");
+        let _enter = span.enter();
+
+        let (ab, as_) = (backdrop.alpha, source.alpha);
+        let cb = backdrop.color.ratios();
+        let cs = source.color.ratios();
+        let blended = self.blend_ratios(cb, cs);
+
+        let ao = as_ + ab * (1.0 - as_);
+        if ao <= 0.0 {
+            return Alpha {color: Rgb::default(), alpha: 0.0};
+        }
+
+        let mut co = [0; 3];
+        for i in 0..3 {
+            // Mix the blended color with the source based on backdrop
+            // coverage, then composite source-over.
+            let mixed = (1.0 - ab) * cs[i] + ab * clamped(blended[i], 0.0, 1.0);
+            let c = (as_ * mixed + ab * cb[i] * (1.0 - as_)) / ao;
+            co[i] = (u8::MAX as f32 * clamped(c, 0.0, 1.0) + 0.5) as u8;
+        }
+
+        Alpha {color: Rgb::from(co), alpha: ao}
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Non-separable blend helpers
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the luminosity of the given component ratios.
+fn lum(c: [f32; 3]) -> f32 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+
+/// Returns the given component ratios with the luminosity set to `l`.
+fn set_lum(c: [f32; 3], l: f32) -> [f32; 3] {
+    let d = l - lum(c);
+    clip_color([c[0] + d, c[1] + d, c[2] + d])
+}
+
+/// Brings the given component ratios into gamut while preserving their
+/// luminosity.
+fn clip_color(c: [f32; 3]) -> [f32; 3] {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+    let mut out = c;
+    for v in out.iter_mut() {
+        if n < 0.0 {
+            *v = l + (*v - l) * l / (l - n);
+        }
+        if x > 1.0 {
+            *v = l + (*v - l) * (1.0 - l) / (x - l);
+        }
+    }
+    out
+}
+
+/// Returns the saturation of the given component ratios.
+fn sat(c: [f32; 3]) -> f32 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+/// Returns the given component ratios with the saturation set to `s`.
+fn set_sat(c: [f32; 3], s: f32) -> [f32; 3] {
+    // Find the indices of the max, mid, and min components.
+    let mut idx = [0, 1, 2];
+    idx.sort_by(|&a, &b| c[a].partial_cmp(&c[b])
+        .unwrap_or(std::cmp::Ordering::Equal));
+    let (min, mid, max) = (idx[0], idx[1], idx[2]);
+
+    let mut out = [0.0; 3];
+    if c[max] > c[min] {
+        out[mid] = (c[mid] - c[min]) * s / (c[max] - c[min]);
+        out[max] = s;
+    }
+    out
+}
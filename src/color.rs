@@ -12,11 +12,34 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 // Local imports.
+use crate::Alpha;
+use crate::BlendMode;
 use crate::Cmyk;
+use crate::ColorNameDictionary;
+use crate::CompositeOperator;
+use crate::contrast;
+use crate::contrast::TextSize;
+use crate::contrast::WcagLevel;
+use crate::cvd;
+use crate::cvd::CvdKind;
+use crate::delta_e;
+use crate::delta_e::Cie94Application;
 use crate::Hsl;
 use crate::Hsv;
+use crate::HueInterpolation;
+use crate::HueWheel;
+use crate::InterpolationSpace;
+use crate::Lab;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::names;
+use crate::Oklab;
+use crate::Oklch;
+use crate::ParseColorError;
 use crate::Rgb;
+use crate::SplineKind;
 use crate::utility::clamped;
+use crate::utility::lerp_f32;
 use crate::Xyz;
 
 // External library imports.
@@ -36,12 +59,15 @@ use std::fmt::Debug;
 // Color
 ////////////////////////////////////////////////////////////////////////////////
 /// An RGB encoded color with extension methods.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Color {
     /// The base RGB format of the color.
-    rgb: Rgb
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    rgb: Rgb,
+    /// The alpha component of the color.
+    #[cfg_attr(feature = "serde", serde(default = "opaque_alpha"))]
+    alpha: u8,
 }
 
 impl Color {
@@ -72,9 +98,136 @@ impl Color {
         
         Color {
             rgb: color.into(),
+            alpha: u8::MAX,
         }
     }
 
+    /// Constructs a new `Color` by parsing a hex code.
+    ///
+    /// Three, four, six, and eight digit variations are acceptable. If no
+    /// alpha digits are given, the color will be fully opaque.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::from_hex_code("#7FFF4080").unwrap();
+    ///
+    /// assert_eq!(color, Color::new(Rgb::new(127, 255, 64)).with_alpha(0x80));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn from_hex_code(hex: &str) -> Result<Self, ParseColorError> {
+        let (rgb, alpha) = Rgb::from_hex_code_with_alpha(hex)?;
+        Ok(Color {
+            rgb,
+            alpha: alpha.unwrap_or(u8::MAX),
+        })
+    }
+
+    /// Constructs a new `Color` from a [CSS named color], ignoring ASCII case.
+    ///
+    /// The name `transparent` is accepted and returns transparent black. The
+    /// name `currentcolor` refers to a color from the surrounding context, so
+    /// it returns `None`; use [`Color::from_name_or_current`] to resolve it.
+    ///
+    /// [CSS named color]: https://www.w3.org/TR/css-color-4/#named-colors
+    /// [`Color::from_name_or_current`]: #method.from_name_or_current
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::from_name("RebeccaPurple");
+    ///
+    /// assert_eq!(color, Some(Color::new(Rgb::new(0x66, 0x33, 0x99))));
+    /// assert_eq!(Color::from_name("transparent").map(|c| c.alpha()), Some(0));
+    /// assert_eq!(Color::from_name("currentcolor"), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name.eq_ignore_ascii_case("transparent") {
+            return Some(Color {rgb: Rgb::default(), alpha: 0});
+        }
+        names::css_color(name).map(Color::new)
+    }
+
+    /// Constructs a new `Color` from a [CSS named color], ignoring ASCII case,
+    /// using the given `current` color for the name `currentcolor`.
+    ///
+    /// [CSS named color]: https://www.w3.org/TR/css-color-4/#named-colors
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let current = Color::new(Rgb::new(127, 255, 64));
+    ///
+    /// assert_eq!(Color::from_name_or_current("currentColor", current),
+    ///     Some(current));
+    /// assert_eq!(Color::from_name_or_current("teal", current),
+    ///     Color::from_name("teal"));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn from_name_or_current(name: &str, current: Color) -> Option<Self> {
+        if name.eq_ignore_ascii_case("currentcolor") {
+            Some(current)
+        } else {
+            Color::from_name(name)
+        }
+    }
+
+    /// Returns the color with the given alpha component.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64}).with_alpha(64);
+    ///
+    /// assert_eq!(color.alpha(), 64);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn with_alpha(mut self, alpha: u8) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
     /// Returns the red [`Rgb`] component of the color.
     ///
     /// [`Rgb`]: rgb/struct.Rgb.html
@@ -153,6 +306,55 @@ impl Color {
         self.rgb.b
     }
 
+    /// Returns the alpha component of the color as an octet, from 0 for fully
+    /// transparent to 255 for fully opaque.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// assert_eq!(color.alpha(), 255);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn alpha(&self) -> u8 {
+        self.alpha
+    }
+
+    /// Returns the alpha component of the color as a ratio.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64}).with_alpha(51);
+    ///
+    /// assert_eq!(color.alpha_ratio(), 0.2);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn alpha_ratio(&self) -> f32 {
+        self.alpha as f32 / u8::MAX as f32
+    }
+
     /// Returns the cyan [`Cymk`] component of the color.
     ///
     /// [`Cymk`]: cmyk/struct.Cymk.html
@@ -387,9 +589,9 @@ impl Color {
         Hsv::from(self.rgb).value()
     }
 
-    /// Sets the red [`Rgb`] component of the color.
+    /// Returns the lightness [`Lab`] component of the color.
     ///
-    /// [`Rgb`]: rgb/struct.Rgb.html
+    /// [`Lab`]: lab/struct.Lab.html
     ///
     /// # Example
     ///
@@ -398,11 +600,9 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
-    ///
-    /// color.set_red(80);
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// assert_eq!(color.red(), 80);
+    /// assert_eq!(color.lab_lightness(), 90.04419);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -411,13 +611,13 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_red(&mut self, value: u8) {
-        self.rgb.r = value;
+    pub fn lab_lightness(&self) -> f32 {
+        Lab::from(self.rgb).lightness()
     }
 
-    /// Sets the green [`Rgb`] component of the color.
+    /// Returns the a (green-red) [`Lab`] component of the color.
     ///
-    /// [`Rgb`]: rgb/struct.Rgb.html
+    /// [`Lab`]: lab/struct.Lab.html
     ///
     /// # Example
     ///
@@ -426,11 +626,9 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
-    ///
-    /// color.set_green(80);
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// assert_eq!(color.green(), 80);
+    /// assert_eq!(color.lab_a(), -66.133766);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -439,13 +637,13 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_green(&mut self, value: u8) {
-        self.rgb.g = value;
+    pub fn lab_a(&self) -> f32 {
+        Lab::from(self.rgb).a()
     }
 
-    /// Sets the blue [`Rgb`] component of the color.
+    /// Returns the b (blue-yellow) [`Lab`] component of the color.
     ///
-    /// [`Rgb`]: rgb/struct.Rgb.html
+    /// [`Lab`]: lab/struct.Lab.html
     ///
     /// # Example
     ///
@@ -454,11 +652,9 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
-    ///
-    /// color.set_blue(80);
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// assert_eq!(color.blue(), 80);
+    /// assert_eq!(color.lab_b(), 74.71625);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -467,18 +663,13 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_blue(&mut self, value: u8) {
-        self.rgb.b = value;
+    pub fn lab_b(&self) -> f32 {
+        Lab::from(self.rgb).b()
     }
 
-    /// Sets the cyan [`Cymk`] component of the color.
-    ///
-    /// Note that the CYMK color space has more degrees of freedom than
-    /// necessary, so multiple CYMK values may denote the same color. Thus 
-    /// setting a component value using `set_cyan` may not result in a
-    /// color with the given value in the cyan component.
+    /// Returns the chroma [`Lch`] component of the color.
     ///
-    /// [`Cymk`]: cymk/struct.Cymk.html
+    /// [`Lch`]: lch/struct.Lch.html
     ///
     /// # Example
     ///
@@ -487,11 +678,9 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
-    ///
-    /// color.set_cyan(80);
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// assert_eq!(color.cyan(), 80);
+    /// assert_eq!(color.lch_chroma(), 99.78072);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -500,20 +689,13 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_cyan(&mut self, value: u8) {
-        let mut t = Cmyk::from(self.rgb);
-        t.c = value;
-        self.rgb = Rgb::from(t);
+    pub fn lch_chroma(&self) -> f32 {
+        Lch::from(self.rgb).chroma()
     }
 
-    /// Sets the magenta [`Cymk`] component of the color. 
-    ///
-    /// Note that the CYMK color space has more degrees of freedom than
-    /// necessary, so multiple CYMK values may denote the same color. Thus 
-    /// setting a component value using `set_magenta` may not result in a
-    /// color with the given value in the magenta component.
+    /// Returns the hue [`Lch`] component of the color in degrees.
     ///
-    /// [`Cymk`]: cymk/struct.Cymk.html
+    /// [`Lch`]: lch/struct.Lch.html
     ///
     /// # Example
     ///
@@ -522,11 +704,9 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
-    ///
-    /// color.set_magenta(80);
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// assert_eq!(color, Rgb {r: 127, g: 175, b: 64}.into());
+    /// assert_eq!(color.lch_hue(), 131.51308);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -535,20 +715,13 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_magenta(&mut self, value: u8) {
-        let mut t = Cmyk::from(self.rgb);
-        t.m = value;
-        self.rgb = Rgb::from(t);
+    pub fn lch_hue(&self) -> f32 {
+        Lch::from(self.rgb).hue()
     }
 
-    /// Sets the yellow [`Cymk`] component of the color.
-    ///
-    /// Note that the CYMK color space has more degrees of freedom than
-    /// necessary, so multiple CYMK values may denote the same color. Thus 
-    /// setting a component value using `set_yellow` may not result in a
-    /// color with the given value in the yellow component.
+    /// Sets the red [`Rgb`] component of the color.
     ///
-    /// [`Cymk`]: cymk/struct.Cymk.html
+    /// [`Rgb`]: rgb/struct.Rgb.html
     ///
     /// # Example
     ///
@@ -559,9 +732,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.set_yellow(80);
+    /// color.set_red(80);
     ///
-    /// assert_eq!(color, Rgb {r: 127, g: 255, b: 175}.into());
+    /// assert_eq!(color.red(), 80);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -570,20 +743,13 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_yellow(&mut self, value: u8) {
-        let mut t = Cmyk::from(self.rgb);
-        t.y = value;
-        self.rgb = Rgb::from(t);
+    pub fn set_red(&mut self, value: u8) {
+        self.rgb.r = value;
     }
 
-    /// Sets the key [`Cymk`] component of the color.
-    ///
-    /// Note that the CYMK color space has more degrees of freedom than
-    /// necessary, so multiple CYMK values may denote the same color. Thus 
-    /// setting a component value using `set_key` may not result in a
-    /// color with the given value in the key component.
+    /// Sets the green [`Rgb`] component of the color.
     ///
-    /// [`Cymk`]: cymk/struct.Cymk.html
+    /// [`Rgb`]: rgb/struct.Rgb.html
     ///
     /// # Example
     ///
@@ -594,9 +760,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.set_key(80);
+    /// color.set_green(80);
     ///
-    /// assert_eq!(color, Rgb {r: 87, g: 175, b: 44}.into());
+    /// assert_eq!(color.green(), 80);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -605,21 +771,13 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_key(&mut self, value: u8) {
-        let mut t = Cmyk::from(self.rgb);
-        t.k = value;
-        self.rgb = Rgb::from(t);
+    pub fn set_green(&mut self, value: u8) {
+        self.rgb.g = value;
     }
 
-    /// Sets the hue [`Hsl`]/['Hsv'] component of the color in degrees.
-    ///
-    /// Note that the HSL/HSV color space has more degrees of freedom than
-    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `set_hue` may not result in a
-    /// color with the given value in the hue component.
+    /// Sets the blue [`Rgb`] component of the color.
     ///
-    /// [`Hsl`]: hsl/struct.Hsl.html
-    /// [`Hsv`]: hsv/struct.Hsv.html
+    /// [`Rgb`]: rgb/struct.Rgb.html
     ///
     /// # Example
     ///
@@ -630,9 +788,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.set_hue(0.24);
+    /// color.set_blue(80);
     ///
-    /// assert_eq!(color, Rgb {r: 255, g: 64, b: 63}.into());
+    /// assert_eq!(color.blue(), 80);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -641,20 +799,11 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_hue(&mut self, value: f32) {
-        let mut t = Hsv::from(self.rgb);
-        t.set_hue(value);
-        self.rgb = Rgb::from(t);
+    pub fn set_blue(&mut self, value: u8) {
+        self.rgb.b = value;
     }
 
-    /// Sets the saturation [`Hsl`] component of the color as a ratio.
-    ///
-    /// Note that the HSL/HSV color space has more degrees of freedom than
-    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `set_hsl_saturation` may not result in a
-    /// color with the given value in the hsl_saturation component.
-    ///
-    /// [`Hsl`]: hsl/struct.Hsl.html
+    /// Sets the alpha component of the color as an octet.
     ///
     /// # Example
     ///
@@ -665,9 +814,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.set_hsl_saturation(0.24);
+    /// color.set_alpha(80);
     ///
-    /// assert_eq!(color, Rgb {r: 151, g: 181, b: 136}.into());
+    /// assert_eq!(color.alpha(), 80);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -676,20 +825,11 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_hsl_saturation(&mut self, value: f32) {
-        let mut t = Hsl::from(self.rgb);
-        t.set_saturation(value);
-        self.rgb = Rgb::from(t);
+    pub fn set_alpha(&mut self, value: u8) {
+        self.alpha = value;
     }
 
-    /// Sets the saturation [`Hsv`] component of the color as a ratio.
-    ///
-    /// Note that the HSL/HSV color space has more degrees of freedom than
-    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `set_hsv_saturation` may not result in a
-    /// color with the given value in the hsv_saturation component.
-    ///
-    /// [`Hsv`]: hsv/struct.Hsv.html
+    /// Sets the alpha component of the color as a ratio.
     ///
     /// # Example
     ///
@@ -700,9 +840,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.set_hsv_saturation(0.24);
+    /// color.set_alpha_ratio(0.5);
     ///
-    /// assert_eq!(color, Rgb {r: 213, g: 255, b: 193}.into());
+    /// assert_eq!(color.alpha(), 128);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -711,20 +851,18 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_hsv_saturation(&mut self, value: f32) {
-        let mut t = Hsv::from(self.rgb);
-        t.set_saturation(value);
-        self.rgb = Rgb::from(t);
+    pub fn set_alpha_ratio(&mut self, value: f32) {
+        self.alpha = (u8::MAX as f32 * clamped(value, 0.0, 1.0) + 0.5) as u8;
     }
 
-    /// Sets the lightness [`Hsl`] component of the color as a ratio.
+    /// Sets the cyan [`Cymk`] component of the color.
     ///
-    /// Note that the HSL/HSV color space has more degrees of freedom than
-    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `set_lightness` may not result in a
-    /// color with the given value in the lightness component.
+    /// Note that the CYMK color space has more degrees of freedom than
+    /// necessary, so multiple CYMK values may denote the same color. Thus 
+    /// setting a component value using `set_cyan` may not result in a
+    /// color with the given value in the cyan component.
     ///
-    /// [`Hsl`]: hsl/struct.Hsl.html
+    /// [`Cymk`]: cymk/struct.Cymk.html
     ///
     /// # Example
     ///
@@ -735,9 +873,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.set_lightness(0.24);
+    /// color.set_cyan(80);
     ///
-    /// assert_eq!(color, Rgb {r: 48, g: 97, b: 24}.into());
+    /// assert_eq!(color.cyan(), 80);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -746,20 +884,20 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_lightness(&mut self, value: f32) {
-        let mut t = Hsl::from(self.rgb);
-        t.set_lightness(value);
+    pub fn set_cyan(&mut self, value: u8) {
+        let mut t = Cmyk::from(self.rgb);
+        t.c = value;
         self.rgb = Rgb::from(t);
     }
 
-    /// Sets the value [`Hsv`] component of the color as a ratio.
+    /// Sets the magenta [`Cymk`] component of the color. 
     ///
-    /// Note that the HSL/HSV color space has more degrees of freedom than
-    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `set_value` may not result in a
-    /// color with the given value in the value component.
+    /// Note that the CYMK color space has more degrees of freedom than
+    /// necessary, so multiple CYMK values may denote the same color. Thus 
+    /// setting a component value using `set_magenta` may not result in a
+    /// color with the given value in the magenta component.
     ///
-    /// [`Hsv`]: hsv/struct.Hsv.html
+    /// [`Cymk`]: cymk/struct.Cymk.html
     ///
     /// # Example
     ///
@@ -770,9 +908,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.set_value(0.24);
+    /// color.set_magenta(80);
     ///
-    /// assert_eq!(color, Rgb {r: 30, g: 61, b: 15}.into());
+    /// assert_eq!(color, Rgb {r: 127, g: 175, b: 64}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -781,22 +919,20 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn set_value(&mut self, value: f32) {
-        let mut t = Hsv::from(self.rgb);
-        t.set_value(value);
+    pub fn set_magenta(&mut self, value: u8) {
+        let mut t = Cmyk::from(self.rgb);
+        t.m = value;
         self.rgb = Rgb::from(t);
     }
 
-    /// Shifts the hue [`Hsl`]/['Hsv'] component of the color by the given 
-    /// number of degrees.
+    /// Sets the yellow [`Cymk`] component of the color.
     ///
-    /// Note that the HSL/HSV color space has more degrees of freedom than
-    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `shift_hue` may not result in a
-    /// color with the given value in the hue component.
+    /// Note that the CYMK color space has more degrees of freedom than
+    /// necessary, so multiple CYMK values may denote the same color. Thus 
+    /// setting a component value using `set_yellow` may not result in a
+    /// color with the given value in the yellow component.
     ///
-    /// [`Hsl`]: hsl/struct.Hsl.html
-    /// [`Hsv`]: hsv/struct.Hsv.html
+    /// [`Cymk`]: cymk/struct.Cymk.html
     ///
     /// # Example
     ///
@@ -807,9 +943,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.shift_hue(65.0);
+    /// color.set_yellow(80);
     ///
-    /// assert_eq!(color, Rgb {r: 63, g: 255, b: 207}.into());
+    /// assert_eq!(color, Rgb {r: 127, g: 255, b: 175}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -818,20 +954,20 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn shift_hue(&mut self, degrees: f32) {
-        let h = self.hue();
-        self.set_hue(h + degrees);
+    pub fn set_yellow(&mut self, value: u8) {
+        let mut t = Cmyk::from(self.rgb);
+        t.y = value;
+        self.rgb = Rgb::from(t);
     }
 
-    /// Increases the saturation [`Hsl`] component of the color by the given 
-    /// ratio.
+    /// Sets the key [`Cymk`] component of the color.
     ///
-    /// Note that the HSL/HSV color space has more degrees of freedom than
-    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `hsl_saturate` may not result in a
-    /// color with the given value in the saturation component.
+    /// Note that the CYMK color space has more degrees of freedom than
+    /// necessary, so multiple CYMK values may denote the same color. Thus 
+    /// setting a component value using `set_key` may not result in a
+    /// color with the given value in the key component.
     ///
-    /// [`Hsl`]: hsl/struct.Hsl.html
+    /// [`Cymk`]: cymk/struct.Cymk.html
     ///
     /// # Example
     ///
@@ -842,9 +978,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.hsl_saturate(0.34);
+    /// color.set_key(80);
     ///
-    /// assert_eq!(color, Rgb {r: 132, g: 235, b: 82}.into());
+    /// assert_eq!(color, Rgb {r: 87, g: 175, b: 44}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -853,21 +989,21 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsl_saturate(&mut self, value: f32) {
-        let s = self.hsl_saturation();
-        let v = clamped(value, 0.0, 1.0);
-        self.set_hsl_saturation(s + (s * v));
+    pub fn set_key(&mut self, value: u8) {
+        let mut t = Cmyk::from(self.rgb);
+        t.k = value;
+        self.rgb = Rgb::from(t);
     }
 
-    /// Decreases the saturation [`Hsl`] component of the color by the given 
-    /// ratio.
+    /// Sets the hue [`Hsl`]/['Hsv'] component of the color in degrees.
     ///
     /// Note that the HSL/HSV color space has more degrees of freedom than
     /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `hsl_saturate` may not result in a
-    /// color with the given value in the saturation component.
+    /// setting a component value using `set_hue` may not result in a
+    /// color with the given value in the hue component.
     ///
     /// [`Hsl`]: hsl/struct.Hsl.html
+    /// [`Hsv`]: hsv/struct.Hsv.html
     ///
     /// # Example
     ///
@@ -878,9 +1014,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.hsl_desaturate(0.34);
+    /// color.set_hue(0.24);
     ///
-    /// assert_eq!(color, Rgb {r: 145, g: 196, b: 121}.into());
+    /// assert_eq!(color, Rgb {r: 255, g: 64, b: 63}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -889,21 +1025,20 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsl_desaturate(&mut self, value: f32) {
-        let s = self.hsl_saturation();
-        let v = clamped(value, 0.0, 1.0);
-        self.set_hsl_saturation(s - (s * v));
+    pub fn set_hue(&mut self, value: f32) {
+        let mut t = Hsv::from(self.rgb);
+        t.set_hue(value);
+        self.rgb = Rgb::from(t);
     }
 
-    /// Increases the saturation [`Hsv`] component of the color by the given 
-    /// ratio.
+    /// Sets the saturation [`Hsl`] component of the color as a ratio.
     ///
     /// Note that the HSL/HSV color space has more degrees of freedom than
     /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `hsv_saturate` may not result in a
-    /// color with the given value in the saturation component.
+    /// setting a component value using `set_hsl_saturation` may not result in a
+    /// color with the given value in the hsl_saturation component.
     ///
-    /// [`Hsv`]: hsv/struct.Hsv.html
+    /// [`Hsl`]: hsl/struct.Hsl.html
     ///
     /// # Example
     ///
@@ -914,9 +1049,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.hsv_saturate(0.34);
+    /// color.set_hsl_saturation(0.24);
     ///
-    /// assert_eq!(color, Rgb {r: 84, g: 255, b: 0}.into());
+    /// assert_eq!(color, Rgb {r: 151, g: 181, b: 136}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -925,19 +1060,18 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsv_saturate(&mut self, value: f32) {
-        let s = self.hsv_saturation();
-        let v = clamped(value, 0.0, 1.0);
-        self.set_hsv_saturation(s + (s * v));
+    pub fn set_hsl_saturation(&mut self, value: f32) {
+        let mut t = Hsl::from(self.rgb);
+        t.set_saturation(value);
+        self.rgb = Rgb::from(t);
     }
 
-    /// Decreases the saturation [`Hsv`] component of the color by the given 
-    /// ratio.
+    /// Sets the saturation [`Hsv`] component of the color as a ratio.
     ///
     /// Note that the HSL/HSV color space has more degrees of freedom than
     /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `hsv_saturate` may not result in a
-    /// color with the given value in the saturation component.
+    /// setting a component value using `set_hsv_saturation` may not result in a
+    /// color with the given value in the hsv_saturation component.
     ///
     /// [`Hsv`]: hsv/struct.Hsv.html
     ///
@@ -950,9 +1084,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.hsv_desaturate(0.34);
+    /// color.set_hsv_saturation(0.24);
     ///
-    /// assert_eq!(color, Rgb {r: 170, g: 255, b: 128}.into());
+    /// assert_eq!(color, Rgb {r: 213, g: 255, b: 193}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -961,18 +1095,17 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsv_desaturate(&mut self, value: f32) {
-        let s = self.hsv_saturation();
-        let v = clamped(value, 0.0, 1.0);
-        self.set_hsv_saturation(s - (s * v));
+    pub fn set_hsv_saturation(&mut self, value: f32) {
+        let mut t = Hsv::from(self.rgb);
+        t.set_saturation(value);
+        self.rgb = Rgb::from(t);
     }
 
-    /// Increases the lightness [`Hsl`] component of the color by the given 
-    /// ratio.
+    /// Sets the lightness [`Hsl`] component of the color as a ratio.
     ///
     /// Note that the HSL/HSV color space has more degrees of freedom than
     /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `lighten` may not result in a
+    /// setting a component value using `set_lightness` may not result in a
     /// color with the given value in the lightness component.
     ///
     /// [`Hsl`]: hsl/struct.Hsl.html
@@ -986,9 +1119,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.lighten(0.34);
+    /// color.set_lightness(0.24);
     ///
-    /// assert_eq!(color, Rgb {r: 205, g: 238, b: 189}.into());
+    /// assert_eq!(color, Rgb {r: 48, g: 97, b: 24}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -997,21 +1130,20 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn lighten(&mut self, value: f32) {
-        let l = self.lightness();
-        let v = clamped(value, 0.0, 1.0);
-        self.set_lightness(l + (l * v));
+    pub fn set_lightness(&mut self, value: f32) {
+        let mut t = Hsl::from(self.rgb);
+        t.set_lightness(value);
+        self.rgb = Rgb::from(t);
     }
 
-    /// Decreases the lightness [`Hsl`] component of the color by the given 
-    /// ratio.
+    /// Sets the value [`Hsv`] component of the color as a ratio.
     ///
     /// Note that the HSL/HSV color space has more degrees of freedom than
     /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
-    /// setting a component value using `darken` may not result in a
-    /// color with the given value in the lightness component.
+    /// setting a component value using `set_value` may not result in a
+    /// color with the given value in the value component.
     ///
-    /// [`Hsl`]: hsl/struct.Hsl.html
+    /// [`Hsv`]: hsv/struct.Hsv.html
     ///
     /// # Example
     ///
@@ -1022,9 +1154,9 @@ impl Color {
     /// # //-------------------------------------------------------------------
     /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// color.darken(0.34);
+    /// color.set_value(0.24);
     ///
-    /// assert_eq!(color, Rgb {r: 83, g: 168, b: 42}.into());
+    /// assert_eq!(color, Rgb {r: 30, g: 61, b: 15}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1033,15 +1165,21 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn darken(&mut self, value: f32) {
-        let l = self.lightness();
-        let v = clamped(value, 0.0, 1.0);
-        self.set_lightness(l - (l * v));
+    pub fn set_value(&mut self, value: f32) {
+        let mut t = Hsv::from(self.rgb);
+        t.set_value(value);
+        self.rgb = Rgb::from(t);
     }
 
-    /// Returns an array containing the [`[R, G, B]`] component octets.
+    /// Sets the chroma [`Lch`] component of the color, leaving its lightness
+    /// and hue unchanged.
     ///
-    /// [`[R, G, B]`]: rgb/struct.Rgb.html
+    /// Note that colors with a high chroma may fall outside of the RGB gamut,
+    /// in which case the resulting components are clipped. Thus setting a
+    /// component value using `set_lch_chroma` may not result in a color with
+    /// the given value in the chroma component.
+    ///
+    /// [`Lch`]: lch/struct.Lch.html
     ///
     /// # Example
     ///
@@ -1050,11 +1188,11 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// let octets = color.rgb_octets();
+    /// color.set_lch_chroma(24.0);
     ///
-    /// assert_eq!(octets, [127u8, 255u8, 64u8]);
+    /// assert_eq!(color, Rgb {r: 209, g: 234, b: 192}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1063,13 +1201,21 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn rgb_octets(&self) -> [u8; 3] {
-        self.rgb.octets()
+    pub fn set_lch_chroma(&mut self, value: f32) {
+        let mut t = Lch::from(self.rgb);
+        t.set_chroma(value);
+        self.rgb = Rgb::from(t);
     }
 
-    /// Returns an array containing the [`[C, M, Y, K]`] component octets.
+    /// Sets the hue [`Lch`] component of the color in degrees, leaving its
+    /// lightness and chroma unchanged.
     ///
-    /// [`[C, M, Y, K]`]: cmyk/struct.Cmyk.html
+    /// Note that colors with a high chroma may fall outside of the RGB gamut,
+    /// in which case the resulting components are clipped. Thus setting a
+    /// component value using `set_lch_hue` may not result in a color with the
+    /// given value in the hue component.
+    ///
+    /// [`Lch`]: lch/struct.Lch.html
     ///
     /// # Example
     ///
@@ -1078,11 +1224,11 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// let octets = color.cmyk_octets();
+    /// color.set_lch_hue(200.0);
     ///
-    /// assert_eq!(octets, [128u8, 0u8, 191u8, 0u8]);
+    /// assert_eq!(color, Rgb {r: 0, g: 255, b: 255}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1091,13 +1237,22 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn cmyk_octets(&self) -> [u8; 4] {
-        Cmyk::from(self.rgb).octets()
+    pub fn set_lch_hue(&mut self, value: f32) {
+        let mut t = Lch::from(self.rgb);
+        t.set_hue(value);
+        self.rgb = Rgb::from(t);
     }
 
-    /// Returns an array containing the [`[H, S, L]`] components.
+    /// Shifts the hue [`Hsl`]/['Hsv'] component of the color by the given 
+    /// number of degrees.
     ///
-    /// [`[H, S, L]`]: hsl/struct.Hsl.html
+    /// Note that the HSL/HSV color space has more degrees of freedom than
+    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
+    /// setting a component value using `shift_hue` may not result in a
+    /// color with the given value in the hue component.
+    ///
+    /// [`Hsl`]: hsl/struct.Hsl.html
+    /// [`Hsv`]: hsv/struct.Hsv.html
     ///
     /// # Example
     ///
@@ -1106,11 +1261,11 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// let components = color.hsl_components();
+    /// color.shift_hue(65.0);
     ///
-    /// assert_eq!(components, [100.20943f32, 0.5987461f32, 0.6254902f32]);
+    /// assert_eq!(color, Rgb {r: 63, g: 255, b: 207}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1119,13 +1274,20 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsl_components(&self) -> [f32; 3] {
-        Hsl::from(self.rgb).components()
+    pub fn shift_hue(&mut self, degrees: f32) {
+        let h = self.hue();
+        self.set_hue(h + degrees);
     }
 
-    /// Returns an array containing the [`[H, S, V]`] components.
+    /// Shifts the hue [`Lch`] component of the color by the given number of
+    /// degrees, leaving its lightness and chroma unchanged.
     ///
-    /// [`[H, S, V]`]: hsv/struct.Hsv.html
+    /// Unlike [`shift_hue`], this preserves the perceived lightness of the
+    /// color. Note that colors with a high chroma may fall outside of the RGB
+    /// gamut, in which case the resulting components are clipped.
+    ///
+    /// [`Lch`]: lch/struct.Lch.html
+    /// [`shift_hue`]: #method.shift_hue
     ///
     /// # Example
     ///
@@ -1134,11 +1296,11 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// let components = color.hsv_components();
+    /// color.lch_shift_hue(65.0);
     ///
-    /// assert_eq!(components, [100.20943f32, 0.7490196f32, 1.0f32]);
+    /// assert_eq!(color, Rgb {r: 0, g: 255, b: 255}.into());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1147,12 +1309,918 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsv_components(&self) -> [f32; 3] {
-        Hsv::from(self.rgb).components()
+    pub fn lch_shift_hue(&mut self, degrees: f32) {
+        let h = self.lch_hue();
+        self.set_lch_hue(h + degrees);
     }
 
-    /// Returns an array containing the [`[R, G, B]`] component ratios.
-    ///
+    /// Increases the saturation [`Hsl`] component of the color by the given 
+    /// ratio.
+    ///
+    /// Note that the HSL/HSV color space has more degrees of freedom than
+    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
+    /// setting a component value using `hsl_saturate` may not result in a
+    /// color with the given value in the saturation component.
+    ///
+    /// [`Hsl`]: hsl/struct.Hsl.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// color.hsl_saturate(0.34);
+    ///
+    /// assert_eq!(color, Rgb {r: 132, g: 235, b: 82}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hsl_saturate(&mut self, value: f32) {
+        let s = self.hsl_saturation();
+        let v = clamped(value, 0.0, 1.0);
+        self.set_hsl_saturation(s + (s * v));
+    }
+
+    /// Decreases the saturation [`Hsl`] component of the color by the given 
+    /// ratio.
+    ///
+    /// Note that the HSL/HSV color space has more degrees of freedom than
+    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
+    /// setting a component value using `hsl_saturate` may not result in a
+    /// color with the given value in the saturation component.
+    ///
+    /// [`Hsl`]: hsl/struct.Hsl.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// color.hsl_desaturate(0.34);
+    ///
+    /// assert_eq!(color, Rgb {r: 145, g: 196, b: 121}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hsl_desaturate(&mut self, value: f32) {
+        let s = self.hsl_saturation();
+        let v = clamped(value, 0.0, 1.0);
+        self.set_hsl_saturation(s - (s * v));
+    }
+
+    /// Increases the saturation [`Hsv`] component of the color by the given 
+    /// ratio.
+    ///
+    /// Note that the HSL/HSV color space has more degrees of freedom than
+    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
+    /// setting a component value using `hsv_saturate` may not result in a
+    /// color with the given value in the saturation component.
+    ///
+    /// [`Hsv`]: hsv/struct.Hsv.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// color.hsv_saturate(0.34);
+    ///
+    /// assert_eq!(color, Rgb {r: 84, g: 255, b: 0}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hsv_saturate(&mut self, value: f32) {
+        let s = self.hsv_saturation();
+        let v = clamped(value, 0.0, 1.0);
+        self.set_hsv_saturation(s + (s * v));
+    }
+
+    /// Decreases the saturation [`Hsv`] component of the color by the given 
+    /// ratio.
+    ///
+    /// Note that the HSL/HSV color space has more degrees of freedom than
+    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
+    /// setting a component value using `hsv_saturate` may not result in a
+    /// color with the given value in the saturation component.
+    ///
+    /// [`Hsv`]: hsv/struct.Hsv.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// color.hsv_desaturate(0.34);
+    ///
+    /// assert_eq!(color, Rgb {r: 170, g: 255, b: 128}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hsv_desaturate(&mut self, value: f32) {
+        let s = self.hsv_saturation();
+        let v = clamped(value, 0.0, 1.0);
+        self.set_hsv_saturation(s - (s * v));
+    }
+
+    /// Increases the lightness [`Hsl`] component of the color by the given 
+    /// ratio.
+    ///
+    /// Note that the HSL/HSV color space has more degrees of freedom than
+    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
+    /// setting a component value using `lighten` may not result in a
+    /// color with the given value in the lightness component.
+    ///
+    /// [`Hsl`]: hsl/struct.Hsl.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// color.lighten(0.34);
+    ///
+    /// assert_eq!(color, Rgb {r: 205, g: 238, b: 189}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lighten(&mut self, value: f32) {
+        let l = self.lightness();
+        let v = clamped(value, 0.0, 1.0);
+        self.set_lightness(l + (l * v));
+    }
+
+    /// Decreases the lightness [`Hsl`] component of the color by the given 
+    /// ratio.
+    ///
+    /// Note that the HSL/HSV color space has more degrees of freedom than
+    /// necessary, so multiple HSL/HSV values may denote the same color. Thus 
+    /// setting a component value using `darken` may not result in a
+    /// color with the given value in the lightness component.
+    ///
+    /// [`Hsl`]: hsl/struct.Hsl.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// color.darken(0.34);
+    ///
+    /// assert_eq!(color, Rgb {r: 83, g: 168, b: 42}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn darken(&mut self, value: f32) {
+        let l = self.lightness();
+        let v = clamped(value, 0.0, 1.0);
+        self.set_lightness(l - (l * v));
+    }
+
+    /// Returns the complement of the color, whose hue is opposite on the given
+    /// [`HueWheel`]. The alpha component is preserved.
+    ///
+    /// [`HueWheel`]: enum.HueWheel.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueWheel, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 255, g: 0, b: 0});
+    ///
+    /// assert_eq!(color.complement(HueWheel::Hsv),
+    ///     Color::new(Rgb {r: 0, g: 255, b: 255}));
+    /// assert_eq!(color.complement(HueWheel::Ryb),
+    ///     Color::new(Rgb {r: 0, g: 255, b: 0}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn complement(&self, wheel: HueWheel) -> Self {
+        self.hue_rotated(wheel, 180.0)
+    }
+
+    /// Returns the triadic harmony of the color: the color followed by the
+    /// colors 120 and 240 degrees around the given [`HueWheel`]. The alpha
+    /// component is preserved.
+    ///
+    /// [`HueWheel`]: enum.HueWheel.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueWheel, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 255, g: 0, b: 0});
+    ///
+    /// let [first, second, third] = color.triadic(HueWheel::Ryb);
+    ///
+    /// assert_eq!(first, color);
+    /// assert_eq!(second, Color::new(Rgb {r: 255, g: 255, b: 0}));
+    /// assert_eq!(third, Color::new(Rgb {r: 0, g: 0, b: 255}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn triadic(&self, wheel: HueWheel) -> [Self; 3] {
+        [
+            *self,
+            self.hue_rotated(wheel, 120.0),
+            self.hue_rotated(wheel, 240.0),
+        ]
+    }
+
+    /// Returns the tetradic (rectangle) harmony of the color: the color
+    /// followed by the colors 60, 180, and 240 degrees around the given
+    /// [`HueWheel`], forming two complementary pairs. The alpha component is
+    /// preserved.
+    ///
+    /// [`HueWheel`]: enum.HueWheel.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueWheel, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 255, g: 0, b: 0});
+    ///
+    /// let colors = color.tetradic(HueWheel::Hsv);
+    ///
+    /// assert_eq!(colors[1], Color::new(Rgb {r: 255, g: 255, b: 0}));
+    /// assert_eq!(colors[2], Color::new(Rgb {r: 0, g: 255, b: 255}));
+    /// assert_eq!(colors[3], Color::new(Rgb {r: 0, g: 0, b: 255}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn tetradic(&self, wheel: HueWheel) -> [Self; 4] {
+        [
+            *self,
+            self.hue_rotated(wheel, 60.0),
+            self.hue_rotated(wheel, 180.0),
+            self.hue_rotated(wheel, 240.0),
+        ]
+    }
+
+    /// Returns the square harmony of the color: the color followed by the
+    /// colors 90, 180, and 270 degrees around the given [`HueWheel`]. The
+    /// alpha component is preserved.
+    ///
+    /// [`HueWheel`]: enum.HueWheel.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueWheel, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 255, g: 0, b: 0});
+    ///
+    /// let colors = color.square(HueWheel::Hsv);
+    ///
+    /// assert_eq!(colors[1], Color::new(Rgb {r: 127, g: 255, b: 0}));
+    /// assert_eq!(colors[2], Color::new(Rgb {r: 0, g: 255, b: 255}));
+    /// assert_eq!(colors[3], Color::new(Rgb {r: 127, g: 0, b: 255}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn square(&self, wheel: HueWheel) -> [Self; 4] {
+        [
+            *self,
+            self.hue_rotated(wheel, 90.0),
+            self.hue_rotated(wheel, 180.0),
+            self.hue_rotated(wheel, 270.0),
+        ]
+    }
+
+    /// Returns the split-complementary harmony of the color: the color
+    /// followed by the two colors 30 degrees to either side of its complement
+    /// on the given [`HueWheel`]. The alpha component is preserved.
+    ///
+    /// [`HueWheel`]: enum.HueWheel.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueWheel, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 255, g: 0, b: 0});
+    ///
+    /// let colors = color.split_complementary(HueWheel::Hsv);
+    ///
+    /// assert_eq!(colors[1], Color::new(Rgb {r: 0, g: 255, b: 127}));
+    /// assert_eq!(colors[2], Color::new(Rgb {r: 0, g: 127, b: 255}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn split_complementary(&self, wheel: HueWheel) -> [Self; 3] {
+        [
+            *self,
+            self.hue_rotated(wheel, 150.0),
+            self.hue_rotated(wheel, 210.0),
+        ]
+    }
+
+    /// Returns `count` analogous colors, with hues evenly spaced over `spread`
+    /// degrees of the given [`HueWheel`] and centered on the color's hue. The
+    /// colors are ordered by increasing hue, so for an odd `count` the color
+    /// itself is in the middle. The alpha component is preserved.
+    ///
+    /// [`HueWheel`]: enum.HueWheel.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueWheel, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 255, g: 0, b: 0});
+    ///
+    /// let colors = color.analogous(3, 60.0, HueWheel::Hsv);
+    ///
+    /// assert_eq!(colors.len(), 3);
+    /// assert_eq!(colors[0], Color::new(Rgb {r: 255, g: 0, b: 127}));
+    /// assert_eq!(colors[1], color);
+    /// assert_eq!(colors[2], Color::new(Rgb {r: 255, g: 127, b: 0}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn analogous(&self, count: usize, spread: f32, wheel: HueWheel)
+        -> Vec<Self>
+    {
+        if count == 1 {
+            return vec![*self];
+        }
+        let step = spread / (count as f32 - 1.0);
+        (0..count)
+            .map(|i| self.hue_rotated(wheel, step * i as f32 - spread / 2.0))
+            .collect()
+    }
+
+    /// Returns `count` monochromatic colors, which share the [`Oklch`] hue and
+    /// chroma of the color and have evenly spaced lightnesses, ordered from
+    /// darkest to lightest. Black and white are excluded. Chroma is reduced
+    /// only where a lightness would otherwise be outside of the sRGB gamut.
+    /// The alpha component is preserved.
+    ///
+    /// [`Oklch`]: struct.Oklch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 0x33, g: 0x66, b: 0x99});
+    ///
+    /// let colors = color.monochromatic(3);
+    ///
+    /// assert_eq!(colors[0], Color::new(Rgb {r: 0, g: 34, b: 66}));
+    /// assert_eq!(colors[1], Color::new(Rgb {r: 51, g: 102, b: 153}));
+    /// assert_eq!(colors[2], Color::new(Rgb {r: 125, g: 179, b: 234}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn monochromatic(&self, count: usize) -> Vec<Self> {
+        let oklch = Oklch::from(self.rgb);
+        (1..=count)
+            .map(|i| {
+                let l = i as f32 / (count as f32 + 1.0);
+                Color {
+                    rgb: Rgb::from(Oklch {l, ..oklch}.srgb_gamut_mapped()),
+                    alpha: self.alpha,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the color with its hue rotated by the given number of degrees
+    /// on the given wheel.
+    fn hue_rotated(&self, wheel: HueWheel, degrees: f32) -> Self {
+        Color {
+            rgb: wheel.rotate(self.rgb, degrees),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Returns `count` tints of the color, mixed in RGB toward white in even
+    /// steps and ordered from the color to white. Neither the color itself
+    /// nor white are included. The alpha component is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 0, g: 102, b: 204});
+    ///
+    /// let tints = color.tints(2);
+    ///
+    /// assert_eq!(tints[0], Color::new(Rgb {r: 85, g: 153, b: 221}));
+    /// assert_eq!(tints[1], Color::new(Rgb {r: 170, g: 204, b: 238}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn tints(&self, count: usize) -> Vec<Self> {
+        self.mixed_toward(Rgb::new(255, 255, 255), count)
+    }
+
+    /// Returns `count` shades of the color, mixed in RGB toward black in even
+    /// steps and ordered from the color to black. Neither the color itself
+    /// nor black are included. The alpha component is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 0, g: 102, b: 204});
+    ///
+    /// let shades = color.shades(2);
+    ///
+    /// assert_eq!(shades[0], Color::new(Rgb {r: 0, g: 67, b: 135}));
+    /// assert_eq!(shades[1], Color::new(Rgb {r: 0, g: 33, b: 67}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn shades(&self, count: usize) -> Vec<Self> {
+        self.mixed_toward(Rgb::new(0, 0, 0), count)
+    }
+
+    /// Returns `count` tones of the color, mixed in RGB toward middle gray in
+    /// even steps and ordered from the color to gray. Neither the color
+    /// itself nor gray are included. The alpha component is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 0, g: 102, b: 204});
+    ///
+    /// let tones = color.tones(2);
+    ///
+    /// assert_eq!(tones[0], Color::new(Rgb {r: 42, g: 110, b: 178}));
+    /// assert_eq!(tones[1], Color::new(Rgb {r: 85, g: 119, b: 153}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn tones(&self, count: usize) -> Vec<Self> {
+        self.mixed_toward(Rgb::new(128, 128, 128), count)
+    }
+
+    /// Returns `count` colors mixed from the color toward the given target
+    /// in even steps, excluding both endpoints.
+    fn mixed_toward(&self, target: Rgb, count: usize) -> Vec<Self> {
+        (1..=count)
+            .map(|i| {
+                let amount = i as f32 / (count as f32 + 1.0);
+                Color {
+                    rgb: Rgb::linear_interpolate(self.rgb, target, amount),
+                    alpha: self.alpha,
+                }
+            })
+            .collect()
+    }
+
+    /// The steps of a conventional color scale, as used by [`scale`].
+    ///
+    /// [`scale`]: #method.scale
+    pub const SCALE_STEPS: [u16; 11] = [
+        50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950
+    ];
+
+    /// Returns a color scale with a color for each of the given steps, in the
+    /// style of the Tailwind and Material Design palettes.
+    ///
+    /// Each step is a number from 0 to 1000, where larger numbers are darker.
+    /// The [`Oklch`] lightness of each color is spaced evenly by step, with
+    /// step 50 at a lightness of 0.97 and step 950 at a lightness of 0.27, so
+    /// the conventional [`SCALE_STEPS`] give a perceptually even ramp. The
+    /// colors keep the hue and chroma of this color, though chroma is reduced
+    /// where a lightness would otherwise be outside of the sRGB gamut. The
+    /// alpha component is preserved.
+    ///
+    /// [`Oklch`]: struct.Oklch.html
+    /// [`SCALE_STEPS`]: #associatedconstant.SCALE_STEPS
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let brand = Color::new(Rgb {r: 0x25, g: 0x63, b: 0xEB});
+    ///
+    /// let scale = brand.scale(&Color::SCALE_STEPS);
+    ///
+    /// assert_eq!(scale.len(), 11);
+    /// assert_eq!(scale[0], Color::new(Rgb {r: 240, g: 245, b: 255}));
+    /// assert_eq!(scale[5], Color::new(Rgb {r: 63, g: 125, b: 255}));
+    /// assert_eq!(scale[10], Color::new(Rgb {r: 0, g: 27, b: 103}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn scale(&self, steps: &[u16]) -> Vec<Self> {
+        let span = span!(Level::DEBUG, "Color::scale");
+        let _enter = span.enter();
+
+        const LIGHTEST: (f32, f32) = (50.0, 0.97);
+        const DARKEST: (f32, f32) = (950.0, 0.27);
+        let slope = (DARKEST.1 - LIGHTEST.1) / (DARKEST.0 - LIGHTEST.0);
+
+        let oklch = Oklch::from(self.rgb);
+        steps
+            .iter()
+            .map(|&step| {
+                let l = LIGHTEST.1 + (f32::from(step) - LIGHTEST.0) * slope;
+                let l = clamped(l, 0.0, 1.0);
+                Color {
+                    rgb: Rgb::from(Oklch {l, ..oklch}.srgb_gamut_mapped()),
+                    alpha: self.alpha,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns an array containing the [`[R, G, B]`] component octets.
+    ///
+    /// [`[R, G, B]`]: rgb/struct.Rgb.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// let octets = color.rgb_octets();
+    ///
+    /// assert_eq!(octets, [127u8, 255u8, 64u8]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn rgb_octets(&self) -> [u8; 3] {
+        self.rgb.octets()
+    }
+
+    /// Returns an array containing the [`[C, M, Y, K]`] component octets.
+    ///
+    /// [`[C, M, Y, K]`]: cmyk/struct.Cmyk.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// let octets = color.cmyk_octets();
+    ///
+    /// assert_eq!(octets, [128u8, 0u8, 191u8, 0u8]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn cmyk_octets(&self) -> [u8; 4] {
+        Cmyk::from(self.rgb).octets()
+    }
+
+    /// Returns an array containing the [`[H, S, L]`] components.
+    ///
+    /// [`[H, S, L]`]: hsl/struct.Hsl.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// let components = color.hsl_components();
+    ///
+    /// assert_eq!(components, [100.20943f32, 0.5987461f32, 0.6254902f32]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hsl_components(&self) -> [f32; 3] {
+        Hsl::from(self.rgb).components()
+    }
+
+    /// Returns an array containing the [`[H, S, V]`] components.
+    ///
+    /// [`[H, S, V]`]: hsv/struct.Hsv.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// let components = color.hsv_components();
+    ///
+    /// assert_eq!(components, [100.20943f32, 0.7490196f32, 1.0f32]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hsv_components(&self) -> [f32; 3] {
+        Hsv::from(self.rgb).components()
+    }
+
+    /// Returns an array containing the [`[L, a, b]`] components.
+    ///
+    /// [`[L, a, b]`]: lab/struct.Lab.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// let components = color.lab_components();
+    ///
+    /// assert_eq!(components, [90.04419f32, -66.133766f32, 74.71625f32]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lab_components(&self) -> [f32; 3] {
+        Lab::from(self.rgb).components()
+    }
+
+    /// Returns an array containing the [`[L, C, h]`] components.
+    ///
+    /// [`[L, C, h]`]: lch/struct.Lch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// let components = color.lch_components();
+    ///
+    /// assert_eq!(components, [90.04419f32, 99.78072f32, 131.51308f32]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lch_components(&self) -> [f32; 3] {
+        Lch::from(self.rgb).components()
+    }
+
+    /// Returns an array containing the [`[L, a, b]`] components.
+    ///
+    /// [`[L, a, b]`]: oklab/struct.Oklab.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// let components = color.oklab_components();
+    ///
+    /// assert_eq!(components, [0.8922204, -0.18318596, 0.16697866]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn oklab_components(&self) -> [f32; 3] {
+        Oklab::from(self.rgb).components()
+    }
+
+    /// Returns an array containing the [`[L, C, h]`] components.
+    ///
+    /// [`[L, C, h]`]: oklch/struct.Oklch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// let components = color.oklch_components();
+    ///
+    /// assert_eq!(components, [0.8922204, 0.24786885, 137.65004]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn oklch_components(&self) -> [f32; 3] {
+        Oklch::from(self.rgb).components()
+    }
+
+    /// Returns an array containing the linear-light [`[R, G, B]`] components.
+    ///
+    /// [`[R, G, B]`]: linear_rgb/struct.LinearRgb.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// let components = color.linear_rgb_components();
+    ///
+    /// assert_eq!(components, [0.21223073, 1.0, 0.051269468]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn linear_rgb_components(&self) -> [f32; 3] {
+        LinearRgb::from(self.rgb).components()
+    }
+
+    /// Returns an array containing the [`[R, G, B]`] component ratios.
+    ///
     /// [`[R, G, B]`]: rgb/struct.Rgb.html
     ///
     /// # Example
@@ -1162,11 +2230,1369 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
-    ///
-    /// let ratios = color.rgb_ratios();
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// let ratios = color.rgb_ratios();
+    ///
+    /// assert_eq!(ratios, [0.49803922f32, 1.0f32, 0.2509804f32]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn rgb_ratios(&self) -> [f32; 3] {
+        self.rgb.ratios()
+    }
+
+    /// Returns an array containing the [`[C, M, Y, K]`] component ratios.
+    ///
+    /// [`[C, M, Y, K]`]: cmyk/struct.Cmyk.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// let ratios = color.cmyk_ratios();
+    ///
+    /// assert_eq!(ratios, [0.5019608f32, 0.0f32, 0.7490196f32, 0.0f32]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn cmyk_ratios(&self) -> [f32; 4] {
+        Cmyk::from(self.rgb).ratios()
+    }
+
+    /// Returns the [`Rgb`] hex code of the color.
+    ///
+    /// [`Rgb`]: rgb/struct.Rgb.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// assert_eq!(color.rgb_hex(), 0x7FFF40);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn rgb_hex(&self) -> u32 {
+        self.rgb.hex()
+    }
+
+    /// Returns the [`Rgba`] hex code of the color, with the alpha component in
+    /// the lowest byte.
+    ///
+    /// [`Rgba`]: type.Rgba.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64}).with_alpha(0x80);
+    ///
+    /// assert_eq!(color.rgba_hex(), 0x7FFF4080);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn rgba_hex(&self) -> u32 {
+        self.rgb.hex() << 8 | self.alpha as u32
+    }
+
+    /// Returns the [`Cmyk`] hex code of the color.
+    ///
+    /// [`Cmyk`]: cmyk/struct.Cmyk.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    ///
+    /// assert_eq!(color.cmyk_hex(), 0x8000BF00);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn cmyk_hex(&self) -> u32 {
+        Cmyk::from(self.rgb).hex()
+    }
+
+    /// Returns the [CSS name] of the color, if it exactly matches a named
+    /// color. Transparent black is named `transparent`, and any other color
+    /// which is not fully opaque has no name. If the color has more than one
+    /// name, such as `aqua` and `cyan`, the alphabetically first is returned.
+    ///
+    /// [CSS name]: https://www.w3.org/TR/css-color-4/#named-colors
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb::new(0x00, 0x80, 0x80));
+    ///
+    /// assert_eq!(color.name(), Some("teal"));
+    /// assert_eq!(color.with_alpha(128).name(), None);
+    /// assert_eq!(Color::new(Rgb::new(0, 255, 255)).name(), Some("aqua"));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn name(&self) -> Option<&'static str> {
+        match self.alpha {
+            0 if self.rgb == Rgb::default() => Some("transparent"),
+            u8::MAX => names::css_name(self.rgb),
+            _ => None,
+        }
+    }
+
+    /// Returns the [CSS name] of the named color which is perceptually
+    /// nearest to this color, ignoring alpha. Distance is measured in the
+    /// [`Oklab`] color space.
+    ///
+    /// [CSS name]: https://www.w3.org/TR/css-color-4/#named-colors
+    /// [`Oklab`]: struct.Oklab.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Color::new(Rgb::new(250, 10, 20));
+    ///
+    /// assert_eq!(color.nearest_name(), "red");
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn nearest_name(&self) -> &'static str {
+        let span = span!(Level::DEBUG, "Color::nearest_name");
+        let _enter = span.enter();
+
+        names::nearest_css_name(self.rgb)
+    }
+
+    /// Returns the name of the color in the given [`ColorNameDictionary`]
+    /// which is perceptually nearest to this color, ignoring alpha. Returns
+    /// `None` if the dictionary is empty.
+    ///
+    /// [`ColorNameDictionary`]: struct.ColorNameDictionary.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, ColorNameDictionary, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let brand = ColorNameDictionary::from_pairs(vec![
+    ///     ("Brand Orange", Rgb::new(0xF2, 0x6B, 0x1D)),
+    ///     ("Brand Navy", Rgb::new(0x1B, 0x2A, 0x4A)),
+    /// ]);
+    /// let color = Color::new(Rgb::new(20, 30, 90));
+    ///
+    /// assert_eq!(color.nearest_name_in(&brand), Some("Brand Navy"));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn nearest_name_in<'d>(&self, dictionary: &'d ColorNameDictionary)
+        -> Option<&'d str>
+    {
+        dictionary.nearest(self.rgb)
+    }
+
+    /// Performs an [`Rgb`] component-wise linear interpolation between given 
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Rgb`]: rgb/struct.Rgb.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let lerp_color = Color::rgb_linear_interpolate(color_a, color_b, 0.65);
+    ///
+    /// assert_eq!(lerp_color, Rgb {r: 54, g: 182, b: 86}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn rgb_linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Rgb::linear_interpolate(start, end, amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Rgb`] component-wise cubic interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The interpolation function will be
+    /// consistent with the slopes given by `start_slope` and `end_slope`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Rgb`]: hsv/struct.Rgb.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb::new(24, 68, 91));
+    /// let color_b = Color::new(Rgb::new(84, 228, 155));
+    ///
+    /// let cerp_color = Color::rgb_cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.39);
+    ///
+    /// assert_eq!(cerp_color, 
+    ///     Color::new(Rgb::new(44, 122, 112)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn rgb_cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Rgb::cubic_interpolate(
+            start,
+            end,
+            start_slope,
+            end_slope,
+            amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Cmyk`] component-wise linear interpolation between given 
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Cmyk`]: cmyk/struct.Cmyk.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let lerp_color = Color::cmyk_linear_interpolate(color_a, color_b, 0.65);
+    ///
+    /// assert_eq!(lerp_color, Rgb {r: 44, g: 183, b: 98}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn cmyk_linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Cmyk::linear_interpolate(start, end, amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Cmyk`] component-wise cubic interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The interpolation function will be
+    /// consistent with the slopes given by `start_slope` and `end_slope`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Cmyk`]: hsv/struct.Cmyk.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let cerp_color = Color::rgb_cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.39);
+    ///
+    /// assert_eq!(cerp_color, 
+    ///     Color::new(Rgb::new(89, 217, 75)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn cmyk_cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Cmyk::cubic_interpolate(
+            start,
+            end,
+            start_slope,
+            end_slope,
+            amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Hsl`] component-wise linear interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The hue is interpolated around the hue
+    /// circle in the direction given by `hue_interpolation`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Hsl`]: hsl/struct.Hsl.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueInterpolation, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let lerp_color = Color::hsl_linear_interpolate(
+    ///     color_a, color_b, 0.65, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(lerp_color, Rgb {r: 28, g: 186, b: 76}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hsl_linear_interpolate<C, D>(
+        start: C,
+        end: D,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Hsl::linear_interpolate(
+            start,
+            end,
+            amount,
+            hue_interpolation))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Hsl`] component-wise cubic interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The interpolation function will be
+    /// consistent with the slopes given by `start_slope` and `end_slope`. The
+    /// hue is interpolated around the hue circle in the direction given by
+    /// `hue_interpolation`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Hsl`]: hsv/struct.Hsl.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueInterpolation, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let cerp_color = Color::hsl_cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.39, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(cerp_color, 
+    ///     Color::new(Rgb::new(50, 214, 50)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hsl_cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Hsl::cubic_interpolate(
+            start,
+            end,
+            start_slope,
+            end_slope,
+            amount,
+            hue_interpolation))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Hsv`] component-wise linear interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The hue is interpolated around the hue
+    /// circle in the direction given by `hue_interpolation`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Hsv`]: hsv/struct.Hsv.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueInterpolation, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let lerp_color = Color::hsv_linear_interpolate(
+    ///     color_a, color_b, 0.65, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(lerp_color, Rgb {r: 28, g: 182, b: 75}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hsv_linear_interpolate<C, D>(
+        start: C,
+        end: D,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Hsv::linear_interpolate(
+            start,
+            end,
+            amount,
+            hue_interpolation))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Hsv`] component-wise cubic interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The interpolation function will be
+    /// consistent with the slopes given by `start_slope` and `end_slope`. The
+    /// hue is interpolated around the hue circle in the direction given by
+    /// `hue_interpolation`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Hsv`]: hsv/struct.Hsv.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueInterpolation, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let cerp_color = Color::hsv_cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.39, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(cerp_color, 
+    ///     Color::new(Rgb::new(43, 217, 44)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hsv_cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Hsv::cubic_interpolate(
+            start,
+            end,
+            start_slope,
+            end_slope,
+            amount,
+            hue_interpolation))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+
+    /// Performs an [`Xyz`] component-wise linear interpolation between given 
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Xyz`]: hsv/struct.Xyz.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let lerp_color = Color::xyz_linear_interpolate(color_a, color_b, 0.65);
+    ///
+    /// assert_eq!(lerp_color, Rgb {r: 79, g: 193, b: 89}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn xyz_linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Xyz::linear_interpolate(start, end, amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Xyz`] component-wise cubic interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The interpolation function will be
+    /// consistent with the slopes given by `start_slope` and `end_slope`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Xyz`]: hsv/struct.Xyz.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let cerp_color = Color::xyz_cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.39);
+    ///
+    /// assert_eq!(cerp_color, 
+    ///     Color::new(Rgb::new(105, 225, 78)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn xyz_cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Xyz::cubic_interpolate(
+            start,
+            end,
+            start_slope,
+            end_slope,
+            amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs a [`LinearRgb`] component-wise linear interpolation between given 
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`LinearRgb`]: linear_rgb/struct.LinearRgb.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let lerp_color = Color::linear_rgb_linear_interpolate(color_a, color_b, 0.65);
+    ///
+    /// assert_eq!(lerp_color, Rgb {r: 79, g: 193, b: 89}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn linear_rgb_linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(LinearRgb::linear_interpolate(start, end, amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs a [`LinearRgb`] component-wise cubic interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The interpolation function will be
+    /// consistent with the slopes given by `start_slope` and `end_slope`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`LinearRgb`]: linear_rgb/struct.LinearRgb.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let cerp_color = Color::linear_rgb_cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.39);
+    ///
+    /// assert_eq!(cerp_color, 
+    ///     Color::new(Rgb::new(105, 225, 78)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn linear_rgb_cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(LinearRgb::cubic_interpolate(
+            start,
+            end,
+            start_slope,
+            end_slope,
+            amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs a [`Lab`] component-wise linear interpolation between given 
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Lab`]: lab/struct.Lab.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let lerp_color = Color::lab_linear_interpolate(color_a, color_b, 0.65);
+    ///
+    /// assert_eq!(lerp_color, Rgb {r: 68, g: 181, b: 92}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lab_linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Lab::linear_interpolate(start, end, amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs a [`Lab`] component-wise cubic interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The interpolation function will be
+    /// consistent with the slopes given by `start_slope` and `end_slope`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Lab`]: lab/struct.Lab.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let cerp_color = Color::lab_cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.39);
+    ///
+    /// assert_eq!(cerp_color, 
+    ///     Color::new(Rgb::new(98, 216, 82)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lab_cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Lab::cubic_interpolate(
+            start,
+            end,
+            start_slope,
+            end_slope,
+            amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Lch`] component-wise linear interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The hue is interpolated around the hue
+    /// circle in the direction given by `hue_interpolation`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Lch`]: lch/struct.Lch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueInterpolation, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let lerp_color = Color::lch_linear_interpolate(
+    ///     color_a, color_b, 0.65, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(lerp_color, Rgb {r: 31, g: 183, b: 100}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lch_linear_interpolate<C, D>(
+        start: C,
+        end: D,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Lch::linear_interpolate(
+            start,
+            end,
+            amount,
+            hue_interpolation))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Lch`] component-wise cubic interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The interpolation function will be
+    /// consistent with the slopes given by `start_slope` and `end_slope`. The
+    /// hue is interpolated around the hue circle in the direction given by
+    /// `hue_interpolation`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Lch`]: lch/struct.Lch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueInterpolation, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let cerp_color = Color::lch_cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.39, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(cerp_color, 
+    ///     Color::new(Rgb::new(73, 218, 90)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lch_cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Lch::cubic_interpolate(
+            start,
+            end,
+            start_slope,
+            end_slope,
+            amount,
+            hue_interpolation))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Oklab`] component-wise linear interpolation between given 
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Oklab`]: oklab/struct.Oklab.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let lerp_color = Color::oklab_linear_interpolate(color_a, color_b, 0.65);
+    ///
+    /// assert_eq!(lerp_color, Rgb {r: 61, g: 182, b: 96}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn oklab_linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Oklab::linear_interpolate(start, end, amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Oklab`] component-wise cubic interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The interpolation function will be
+    /// consistent with the slopes given by `start_slope` and `end_slope`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Oklab`]: oklab/struct.Oklab.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let cerp_color = Color::oklab_cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.39);
+    ///
+    /// assert_eq!(cerp_color, 
+    ///     Color::new(Rgb::new(93, 217, 87)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn oklab_cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Oklab::cubic_interpolate(
+            start,
+            end,
+            start_slope,
+            end_slope,
+            amount))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Oklch`] component-wise linear interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The hue is interpolated around the hue
+    /// circle in the direction given by `hue_interpolation`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Oklch`]: oklch/struct.Oklch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueInterpolation, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let lerp_color = Color::oklch_linear_interpolate(
+    ///     color_a, color_b, 0.65, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(lerp_color, Rgb {r: 32, g: 184, b: 104}.into());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn oklch_linear_interpolate<C, D>(
+        start: C,
+        end: D,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Oklch::linear_interpolate(
+            start,
+            end,
+            amount,
+            hue_interpolation))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Performs an [`Oklch`] component-wise cubic interpolation between given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 1 and 0. The interpolation function will be
+    /// consistent with the slopes given by `start_slope` and `end_slope`. The
+    /// hue is interpolated around the hue circle in the direction given by
+    /// `hue_interpolation`.
+    ///
+    /// The alpha component is interpolated linearly.
+    ///
+    /// [`Oklch`]: oklch/struct.Oklch.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueInterpolation, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// let cerp_color = Color::oklch_cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.39, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(cerp_color, 
+    ///     Color::new(Rgb::new(73, 218, 96)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn oklch_cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let (start, end): (Self, Self) = (start.into(), end.into());
+        Color::from(Oklch::cubic_interpolate(
+            start,
+            end,
+            start_slope,
+            end_slope,
+            amount,
+            hue_interpolation))
+            .with_alpha(Color::interpolated_alpha(start, end, amount))
+    }
+
+    /// Returns the alpha component linearly interpolated between the given
+    /// colors at the ratio given by `amount`.
+    fn interpolated_alpha(start: Color, end: Color, amount: f32) -> u8 {
+        lerp_f32(
+            f32::from(start.alpha),
+            f32::from(end.alpha),
+            amount).round() as u8
+    }
+
+    /// Interpolates along a spline of the given kind through the given
+    /// colors, returning the color located at the ratio given by `amount`,
+    /// which is clamped between 0 and 1. The colors are spaced evenly, the
+    /// spline is computed in the given color space, and hues take the shorter
+    /// way between neighboring colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, InterpolationSpace, Rgb, SplineKind };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let colors = [
+    ///     Color::new(Rgb {r: 0, g: 0, b: 80}),
+    ///     Color::new(Rgb {r: 200, g: 30, b: 90}),
+    ///     Color::new(Rgb {r: 250, g: 230, b: 60}),
+    /// ];
+    ///
+    /// let catmull_rom = Color::spline_interpolate(
+    ///     &colors, 0.5, SplineKind::CatmullRom, InterpolationSpace::Oklab);
+    /// let b_spline = Color::spline_interpolate(
+    ///     &colors, 0.5, SplineKind::BSpline, InterpolationSpace::Oklab);
+    /// let monotone = Color::spline_interpolate(
+    ///     &colors, 0.25, SplineKind::Monotone, InterpolationSpace::Oklab);
+    ///
+    /// assert_eq!(catmull_rom, Color::new(Rgb::new(200, 30, 90)));
+    /// assert_eq!(b_spline, Color::new(Rgb::new(174, 77, 94)));
+    /// assert_eq!(monotone, Color::new(Rgb::new(103, 26, 89)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn spline_interpolate(
+        colors: &[Color],
+        amount: f32,
+        kind: SplineKind,
+        space: InterpolationSpace)
+        -> Self
+    {
+        assert!(!colors.is_empty(), "no colors to interpolate");
+        space.spline(colors, kind, amount)
+    }
+
+    /// Mixes the given colors in the given color space as the CSS
+    /// [`color-mix()`] function does, returning `None` if the percentages are
+    /// invalid.
+    ///
+    /// The percentages give the proportion of each color, from 0 to 100. If
+    /// neither is given, they are both 50. If only one is given, the other
+    /// makes up the rest of 100. Percentages which do not sum to 100 are
+    /// scaled to do so, and if they summed to less than 100, the alpha of the
+    /// result is multiplied by their sum divided by 100. The percentages are
+    /// invalid if either is outside of the range 0 to 100 or if they sum to
+    /// 0.
+    ///
+    /// The colors are interpolated with premultiplied alpha, and hues are
+    /// interpolated in the direction given by `hue_interpolation`. The hue of
+    /// an achromatic color is replaced by the hue of the other color.
+    ///
+    /// [`color-mix()`]: https://www.w3.org/TR/css-color-5/#color-mix
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, HueInterpolation, InterpolationSpace, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let red = Color::new(Rgb {r: 255, g: 0, b: 0});
+    /// let blue = Color::new(Rgb {r: 0, g: 0, b: 255});
+    ///
+    /// // color-mix(in srgb, red, blue)
+    /// let mixed = Color::mix(
+    ///     red, blue,
+    ///     InterpolationSpace::Rgb,
+    ///     (None, None),
+    ///     HueInterpolation::Shorter);
+    /// assert_eq!(mixed, Some(Color::new(Rgb::new(128, 0, 128))));
+    ///
+    /// // color-mix(in srgb, red 30%, blue 20%)
+    /// let mixed = Color::mix(
+    ///     red, blue,
+    ///     InterpolationSpace::Rgb,
+    ///     (Some(30.0), Some(20.0)),
+    ///     HueInterpolation::Shorter);
+    /// assert_eq!(mixed, Some(Color::new(Rgb::new(153, 0, 102)).with_alpha(128)));
+    ///
+    /// // color-mix(in oklch longer hue, red 25%, blue)
+    /// let mixed = Color::mix(
+    ///     red, blue,
+    ///     InterpolationSpace::Oklch,
+    ///     (Some(25.0), None),
+    ///     HueInterpolation::Longer);
+    /// assert_eq!(mixed, Some(Color::new(Rgb::new(0, 130, 178))));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn mix<C, D>(
+        a: C,
+        b: D,
+        space: InterpolationSpace,
+        percentages: (Option<f32>, Option<f32>),
+        hue_interpolation: HueInterpolation)
+        -> Option<Self>
+        where
+            C: Into<Color> + Sized,
+            D: Into<Color> + Sized,
+    {
+        let valid = |p: Option<f32>| match p {
+            Some(p) => (0.0..=100.0).contains(&p),
+            None    => true,
+        };
+        if !valid(percentages.0) || !valid(percentages.1) { return None; }
+
+        let (p1, p2) = match percentages {
+            (None,     None)     => (50.0, 50.0),
+            (Some(p1), None)     => (p1, 100.0 - p1),
+            (None,     Some(p2)) => (100.0 - p2, p2),
+            (Some(p1), Some(p2)) => (p1, p2),
+        };
+        let sum = p1 + p2;
+        if sum == 0.0 { return None; }
+        let multiplier = if sum < 100.0 { sum / 100.0 } else { 1.0 };
+
+        let (rgb, alpha) = space.premultiplied_interpolate(
+            a.into(),
+            b.into(),
+            hue_interpolation,
+            p2 / sum);
+        let alpha = (255.0 * alpha * multiplier).round() as u8;
+        Some(Color::new(rgb).with_alpha(alpha))
+    }
+
+    /// Returns the distance between the given colors in [`Rgb`] color space.
+    ///
+    /// [`Rgb`]: rgb/struct.Rgb.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
     ///
-    /// assert_eq!(ratios, [0.49803922f32, 1.0f32, 0.2509804f32]);
+    /// assert_eq!(Color::rgb_distance(color_a, color_b), 161.52399);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1175,13 +3601,17 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn rgb_ratios(&self) -> [f32; 3] {
-        self.rgb.ratios()
+    pub fn rgb_distance<C, D>(start: C, end: D) -> f32 
+        where
+            C: Into<Rgb> + Sized,
+            D: Into<Rgb> + Sized,
+    {
+        Rgb::distance(start.into(), end.into())
     }
 
-    /// Returns an array containing the [`[C, M, Y, K]`] component ratios.
+    /// Returns the distance between the given colors in [`Cmyk`] color space.
     ///
-    /// [`[C, M, Y, K]`]: cmyk/struct.Cmyk.html
+    /// [`Cmyk`]: cmyk/struct.Cmyk.html
     ///
     /// # Example
     ///
@@ -1190,11 +3620,41 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
     ///
-    /// let ratios = color.cmyk_ratios();
+    /// assert_eq!(Color::cmyk_distance(color_a, color_b), 186.12361);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn cmyk_distance<C, D>(start: C, end: D) -> f32 
+        where
+            C: Into<Cmyk> + Sized,
+            D: Into<Cmyk> + Sized,
+    {
+        Cmyk::distance(start.into(), end.into())
+    }
+
+    /// Returns the distance between the given colors in [`Hsl`] color space.
     ///
-    /// assert_eq!(ratios, [0.5019608f32, 0.0f32, 0.7490196f32, 0.0f32]);
+    /// [`Hsl`]: hsl/struct.Hsl.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// assert_eq!(Color::hsl_distance(color_a, color_b), 0.71319157);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1203,13 +3663,17 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn cmyk_ratios(&self) -> [f32; 4] {
-        Cmyk::from(self.rgb).ratios()
+    pub fn hsl_distance<C, D>(start: C, end: D) -> f32 
+        where
+            C: Into<Hsl> + Sized,
+            D: Into<Hsl> + Sized,
+    {
+        Hsl::distance(start.into(), end.into())
     }
 
-    /// Returns the [`Rgb`] hex code of the color.
+    /// Returns the distance between the given colors in [`Hsv`] color space.
     ///
-    /// [`Rgb`]: rgb/struct.Rgb.html
+    /// [`Hsv`]: hsv/struct.Hsv.html
     ///
     /// # Example
     ///
@@ -1218,9 +3682,10 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
     ///
-    /// assert_eq!(color.rgb_hex(), 0x7FFF40);
+    /// assert_eq!(Color::hsv_distance(color_a, color_b), 1.1794227);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1229,13 +3694,17 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn rgb_hex(&self) -> u32 {
-        self.rgb.hex()
+    pub fn hsv_distance<C, D>(start: C, end: D) -> f32 
+        where
+            C: Into<Hsv> + Sized,
+            D: Into<Hsv> + Sized,
+    {
+        Hsv::distance(start.into(), end.into())
     }
 
-    /// Returns the [`Cmyk`] hex code of the color.
+    /// Returns the distance between the given colors in [`Xyz`] color space.
     ///
-    /// [`Cmyk`]: cmyk/struct.Cmyk.html
+    /// [`Xyz`]: hsv/struct.Xyz.html
     ///
     /// # Example
     ///
@@ -1244,9 +3713,10 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
     ///
-    /// assert_eq!(color.cmyk_hex(), 0x8000BF00);
+    /// assert_eq!(Color::xyz_distance(color_a, color_b), 0.6456722);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1255,15 +3725,17 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn cmyk_hex(&self) -> u32 {
-        Cmyk::from(self.rgb).hex()
+    pub fn xyz_distance<C, D>(start: C, end: D) -> f32 
+        where
+            C: Into<Xyz> + Sized,
+            D: Into<Xyz> + Sized,
+    {
+        Xyz::distance(start.into(), end.into())
     }
 
-    /// Performs an [`Rgb`] component-wise linear interpolation between given 
-    /// colors, returning the color located at the ratio given by `amount`,
-    /// which is clamped between 1 and 0.
+    /// Returns the distance between the given colors in [`LinearRgb`] color space.
     ///
-    /// [`Rgb`]: rgb/struct.Rgb.html
+    /// [`LinearRgb`]: linear_rgb/struct.LinearRgb.html
     ///
     /// # Example
     ///
@@ -1275,9 +3747,38 @@ impl Color {
     /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
     /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
     ///
-    /// let lerp_color = Color::rgb_linear_interpolate(color_a, color_b, 0.65);
+    /// assert_eq!(Color::linear_rgb_distance(color_a, color_b), 0.75394505);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn linear_rgb_distance<C, D>(start: C, end: D) -> f32 
+        where
+            C: Into<LinearRgb> + Sized,
+            D: Into<LinearRgb> + Sized,
+    {
+        LinearRgb::distance(start.into(), end.into())
+    }
+
+    /// Returns the distance between the given colors in [`Lab`] color space.
     ///
-    /// assert_eq!(lerp_color, Rgb {r: 54, g: 182, b: 86}.into());
+    /// [`Lab`]: lab/struct.Lab.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// assert_eq!(Color::lab_distance(color_a, color_b), 73.9301);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1286,20 +3787,17 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn rgb_linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+    pub fn lab_distance<C, D>(start: C, end: D) -> f32 
         where
-            C: Into<Rgb> + Sized,
-            D: Into<Rgb> + Sized,
+            C: Into<Lab> + Sized,
+            D: Into<Lab> + Sized,
     {
-        Rgb::linear_interpolate(start.into(), end.into(), amount).into()
+        Lab::distance(start.into(), end.into())
     }
 
-    /// Performs an [`Rgb`] component-wise cubic interpolation between given
-    /// colors, returning the color located at the ratio given by `amount`,
-    /// which is clamped between 1 and 0. The interpolation function will be
-    /// consistent with the slopes given by `start_slope` and `end_slope`.
+    /// Returns the distance between the given colors in [`Lch`] color space.
     ///
-    /// [`Rgb`]: hsv/struct.Rgb.html
+    /// [`Lch`]: lch/struct.Lch.html
     ///
     /// # Example
     ///
@@ -1308,14 +3806,41 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color_a = Color::new(Rgb::new(24, 68, 91));
-    /// let color_b = Color::new(Rgb::new(84, 228, 155));
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
     ///
-    /// let cerp_color = Color::rgb_cubic_interpolate(
-    ///     color_a, color_b, 0.0, 0.0, 0.39);
+    /// assert_eq!(Color::lch_distance(color_a, color_b), 73.93009);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lch_distance<C, D>(start: C, end: D) -> f32 
+        where
+            C: Into<Lch> + Sized,
+            D: Into<Lch> + Sized,
+    {
+        Lch::distance(start.into(), end.into())
+    }
+
+    /// Returns the distance between the given colors in [`Oklab`] color space.
     ///
-    /// assert_eq!(cerp_color, 
-    ///     Color::new(Rgb::new(44, 122, 112)));
+    /// [`Oklab`]: oklab/struct.Oklab.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    ///
+    /// assert_eq!(Color::oklab_distance(color_a, color_b), 0.34500477);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1324,29 +3849,17 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn rgb_cubic_interpolate<C, D>(
-        start: C,
-        end: D,
-        start_slope: f32,
-        end_slope: f32,
-        amount: f32) -> Self 
+    pub fn oklab_distance<C, D>(start: C, end: D) -> f32 
         where
-            C: Into<Self> + Sized,
-            D: Into<Self> + Sized,
+            C: Into<Oklab> + Sized,
+            D: Into<Oklab> + Sized,
     {
-        Rgb::cubic_interpolate(
-            start.into(),
-            end.into(),
-            start_slope,
-            end_slope,
-            amount).into()
+        Oklab::distance(start.into(), end.into())
     }
 
-    /// Performs an [`Cmyk`] component-wise linear interpolation between given 
-    /// colors, returning the color located at the ratio given by `amount`,
-    /// which is clamped between 1 and 0.
+    /// Returns the distance between the given colors in [`Oklch`] color space.
     ///
-    /// [`Cmyk`]: cmyk/struct.Cmyk.html
+    /// [`Oklch`]: oklch/struct.Oklch.html
     ///
     /// # Example
     ///
@@ -1358,9 +3871,39 @@ impl Color {
     /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
     /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
     ///
-    /// let lerp_color = Color::cmyk_linear_interpolate(color_a, color_b, 0.65);
+    /// assert_eq!(Color::oklch_distance(color_a, color_b), 0.34500477);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn oklch_distance<C, D>(start: C, end: D) -> f32 
+        where
+            C: Into<Oklch> + Sized,
+            D: Into<Oklch> + Sized,
+    {
+        Oklch::distance(start.into(), end.into())
+    }
+
+    /// Returns the CIE76 color difference between the given colors. See
+    /// [`delta_e::delta_e_76`] for details.
     ///
-    /// assert_eq!(lerp_color, Rgb {r: 44, g: 183, b: 98}.into());
+    /// [`delta_e::delta_e_76`]: delta_e/fn.delta_e_76.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 120, g: 250, b: 70});
+    ///
+    /// assert_eq!(Color::delta_e_76(color_a, color_b), 4.074353);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1369,36 +3912,69 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn cmyk_linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+    pub fn delta_e_76<C, D>(reference: C, sample: D) -> f32
         where
-            C: Into<Cmyk> + Sized,
-            D: Into<Cmyk> + Sized,
+            C: Into<Lab> + Sized,
+            D: Into<Lab> + Sized,
     {
-        Cmyk::linear_interpolate(start.into(), end.into(), amount).into()
+        delta_e::delta_e_76(reference, sample)
     }
 
-    /// Performs an [`Cmyk`] component-wise cubic interpolation between given
-    /// colors, returning the color located at the ratio given by `amount`,
-    /// which is clamped between 1 and 0. The interpolation function will be
-    /// consistent with the slopes given by `start_slope` and `end_slope`.
+    /// Returns the CIE94 color difference between the given colors, using the
+    /// weighting factors for the given application. See
+    /// [`delta_e::delta_e_94`] for details.
     ///
-    /// [`Cmyk`]: hsv/struct.Cmyk.html
+    /// [`delta_e::delta_e_94`]: delta_e/fn.delta_e_94.html
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
     /// # use color::{ Color, Rgb };
+    /// # use color::delta_e::{ Cie94Application };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
-    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    /// let color_b = Color::new(Rgb {r: 120, g: 250, b: 70});
     ///
-    /// let cerp_color = Color::rgb_cubic_interpolate(
-    ///     color_a, color_b, 0.0, 0.0, 0.39);
+    /// assert_eq!(Color::delta_e_94(color_a, color_b,
+    ///     Cie94Application::GraphicArts), 1.9934961);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn delta_e_94<C, D>(
+        reference: C,
+        sample: D,
+        application: Cie94Application)
+        -> f32
+        where
+            C: Into<Lab> + Sized,
+            D: Into<Lab> + Sized,
+    {
+        delta_e::delta_e_94(reference, sample, application)
+    }
+
+    /// Returns the CIEDE2000 color difference between the given colors. See
+    /// [`delta_e::delta_e_2000`] for details.
     ///
-    /// assert_eq!(cerp_color, 
-    ///     Color::new(Rgb::new(89, 217, 75)));
+    /// [`delta_e::delta_e_2000`]: delta_e/fn.delta_e_2000.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let color_b = Color::new(Rgb {r: 120, g: 250, b: 70});
+    ///
+    /// assert_eq!(Color::delta_e_2000(color_a, color_b), 1.4275366);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1407,29 +3983,19 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn cmyk_cubic_interpolate<C, D>(
-        start: C,
-        end: D,
-        start_slope: f32,
-        end_slope: f32,
-        amount: f32) -> Self 
+    pub fn delta_e_2000<C, D>(reference: C, sample: D) -> f32
         where
-            C: Into<Self> + Sized,
-            D: Into<Self> + Sized,
+            C: Into<Lab> + Sized,
+            D: Into<Lab> + Sized,
     {
-        Cmyk::cubic_interpolate(
-            start.into(),
-            end.into(),
-            start_slope,
-            end_slope,
-            amount).into()
+        delta_e::delta_e_2000(reference, sample)
     }
 
-    /// Performs an [`Hsl`] component-wise linear interpolation between given 
-    /// colors, returning the color located at the ratio given by `amount`,
-    /// which is clamped between 1 and 0.
+    /// Returns the CMC l:c color difference between the given colors, using
+    /// the given lightness and chroma weights. See [`delta_e::delta_e_cmc`]
+    /// for details.
     ///
-    /// [`Hsl`]: hsl/struct.Hsl.html
+    /// [`delta_e::delta_e_cmc`]: delta_e/fn.delta_e_cmc.html
     ///
     /// # Example
     ///
@@ -1439,11 +4005,9 @@ impl Color {
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
-    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    /// let color_b = Color::new(Rgb {r: 120, g: 250, b: 70});
     ///
-    /// let lerp_color = Color::hsl_linear_interpolate(color_a, color_b, 0.65);
-    ///
-    /// assert_eq!(lerp_color, Rgb {r: 28, g: 186, b: 76}.into());
+    /// assert_eq!(Color::delta_e_cmc(color_a, color_b, 2.0, 1.0), 1.3764302);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1452,20 +4016,23 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsl_linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+    pub fn delta_e_cmc<C, D>(
+        reference: C,
+        sample: D,
+        lightness: f32,
+        chroma: f32)
+        -> f32
         where
-            C: Into<Hsl> + Sized,
-            D: Into<Hsl> + Sized,
+            C: Into<Lab> + Sized,
+            D: Into<Lab> + Sized,
     {
-        Hsl::linear_interpolate(start.into(), end.into(), amount).into()
+        delta_e::delta_e_cmc(reference, sample, lightness, chroma)
     }
 
-    /// Performs an [`Hsl`] component-wise cubic interpolation between given
-    /// colors, returning the color located at the ratio given by `amount`,
-    /// which is clamped between 1 and 0. The interpolation function will be
-    /// consistent with the slopes given by `start_slope` and `end_slope`.
+    /// Returns the [WCAG 2.1 relative luminance] of the color, from 0.0 for
+    /// black to 1.0 for white.
     ///
-    /// [`Hsl`]: hsv/struct.Hsl.html
+    /// [WCAG 2.1 relative luminance]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
     ///
     /// # Example
     ///
@@ -1474,14 +4041,9 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
-    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
-    ///
-    /// let cerp_color = Color::hsl_cubic_interpolate(
-    ///     color_a, color_b, 0.0, 0.0, 0.39);
+    /// let color = Color::new(Rgb {r: 127, g: 255, b: 64});
     ///
-    /// assert_eq!(cerp_color, 
-    ///     Color::new(Rgb::new(50, 214, 50)));
+    /// assert_eq!(color.relative_luminance(), 0.7640219);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1490,29 +4052,15 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsl_cubic_interpolate<C, D>(
-        start: C,
-        end: D,
-        start_slope: f32,
-        end_slope: f32,
-        amount: f32) -> Self 
-        where
-            C: Into<Self> + Sized,
-            D: Into<Self> + Sized,
-    {
-        Hsl::cubic_interpolate(
-            start.into(),
-            end.into(),
-            start_slope,
-            end_slope,
-            amount).into()
+    pub fn relative_luminance(&self) -> f32 {
+        contrast::relative_luminance(self.rgb)
     }
 
-    /// Performs an [`Hsv`] component-wise linear interpolation between given 
-    /// colors, returning the color located at the ratio given by `amount`,
-    /// which is clamped between 1 and 0.
+    /// Returns the [WCAG 2.1 contrast ratio] between the given colors, from
+    /// 1.0 for identical colors to 21.0 for black and white. The order of the
+    /// colors does not matter, and alpha is ignored.
     ///
-    /// [`Hsv`]: hsv/struct.Hsv.html
+    /// [WCAG 2.1 contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
     ///
     /// # Example
     ///
@@ -1524,9 +4072,7 @@ impl Color {
     /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
     /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
     ///
-    /// let lerp_color = Color::hsv_linear_interpolate(color_a, color_b, 0.65);
-    ///
-    /// assert_eq!(lerp_color, Rgb {r: 28, g: 182, b: 75}.into());
+    /// assert_eq!(Color::contrast_ratio(color_a, color_b), 3.1370149);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1535,36 +4081,34 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsv_linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+    pub fn contrast_ratio<C, D>(a: C, b: D) -> f32
         where
-            C: Into<Hsv> + Sized,
-            D: Into<Hsv> + Sized,
+            C: Into<LinearRgb> + Sized,
+            D: Into<LinearRgb> + Sized,
     {
-        Hsv::linear_interpolate(start.into(), end.into(), amount).into()
+        contrast::contrast_ratio(a, b)
     }
 
-    /// Performs an [`Hsv`] component-wise cubic interpolation between given
-    /// colors, returning the color located at the ratio given by `amount`,
-    /// which is clamped between 1 and 0. The interpolation function will be
-    /// consistent with the slopes given by `start_slope` and `end_slope`.
+    /// Returns the [WCAG 2.1] conformance level met by the contrast between
+    /// the given text and background colors, for text of the given size.
     ///
-    /// [`Hsv`]: hsv/struct.Hsv.html
+    /// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#contrast-minimum
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
     /// # use color::{ Color, Rgb };
+    /// # use color::contrast::{ TextSize, WcagLevel };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
-    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
-    ///
-    /// let cerp_color = Color::hsv_cubic_interpolate(
-    ///     color_a, color_b, 0.0, 0.0, 0.39);
+    /// let text = Color::new(Rgb {r: 118, g: 118, b: 118});
+    /// let background = Color::new(Rgb {r: 255, g: 255, b: 255});
     ///
-    /// assert_eq!(cerp_color, 
-    ///     Color::new(Rgb::new(43, 217, 44)));
+    /// assert_eq!(Color::wcag_level(text, background, TextSize::Normal),
+    ///     WcagLevel::Aa);
+    /// assert_eq!(Color::wcag_level(text, background, TextSize::Large),
+    ///     WcagLevel::Aaa);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1573,30 +4117,21 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsv_cubic_interpolate<C, D>(
-        start: C,
-        end: D,
-        start_slope: f32,
-        end_slope: f32,
-        amount: f32) -> Self 
+    pub fn wcag_level<C, D>(text: C, background: D, size: TextSize)
+        -> WcagLevel
         where
-            C: Into<Self> + Sized,
-            D: Into<Self> + Sized,
+            C: Into<LinearRgb> + Sized,
+            D: Into<LinearRgb> + Sized,
     {
-        Hsv::cubic_interpolate(
-            start.into(),
-            end.into(),
-            start_slope,
-            end_slope,
-            amount).into()
+        WcagLevel::for_ratio(contrast::contrast_ratio(text, background), size)
     }
 
-
-    /// Performs an [`Xyz`] component-wise linear interpolation between given 
-    /// colors, returning the color located at the ratio given by `amount`,
-    /// which is clamped between 1 and 0.
+    /// Returns the [APCA] lightness contrast (Lc) of the given text color on
+    /// the given background color. See [`contrast::apca_contrast`] for
+    /// details.
     ///
-    /// [`Xyz`]: hsv/struct.Xyz.html
+    /// [APCA]: https://github.com/Myndex/apca-w3
+    /// [`contrast::apca_contrast`]: contrast/fn.apca_contrast.html
     ///
     /// # Example
     ///
@@ -1605,12 +4140,11 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
-    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
-    ///
-    /// let lerp_color = Color::xyz_linear_interpolate(color_a, color_b, 0.65);
+    /// let text = Color::new(Rgb {r: 0x11, g: 0x22, b: 0x33});
+    /// let background = Color::new(Rgb {r: 0xDD, g: 0xEE, b: 0xFF});
     ///
-    /// assert_eq!(lerp_color, Rgb {r: 54, g: 182, b: 86}.into());
+    /// assert_eq!(Color::apca_contrast(text, background), 91.668304);
+    /// assert_eq!(Color::apca_contrast(background, text), -93.0677);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1619,20 +4153,25 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn xyz_linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+    pub fn apca_contrast<C, D>(text: C, background: D) -> f32
         where
-            C: Into<Xyz> + Sized,
-            D: Into<Xyz> + Sized,
+            C: Into<Rgb> + Sized,
+            D: Into<Rgb> + Sized,
     {
-        Xyz::linear_interpolate(start.into(), end.into(), amount).into()
+        contrast::apca_contrast(text, background)
     }
 
-    /// Performs an [`Xyz`] component-wise cubic interpolation between given
-    /// colors, returning the color located at the ratio given by `amount`,
-    /// which is clamped between 1 and 0. The interpolation function will be
-    /// consistent with the slopes given by `start_slope` and `end_slope`.
+    /// Returns the color nearest to this one which has at least the given
+    /// [WCAG 2.1 contrast ratio] against the `background` color, or `None` if
+    /// no such color exists.
     ///
-    /// [`Xyz`]: hsv/struct.Xyz.html
+    /// The search keeps the [`Oklch`] hue and chroma of the color, adjusting
+    /// only its lightness, so the result stays recognizably the same color.
+    /// Chroma is reduced only where the adjusted lightness would otherwise be
+    /// outside of the sRGB gamut. The alpha component is preserved.
+    ///
+    /// [WCAG 2.1 contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    /// [`Oklch`]: struct.Oklch.html
     ///
     /// # Example
     ///
@@ -1641,14 +4180,14 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
-    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    /// let brand = Color::new(Rgb {r: 0x33, g: 0x99, b: 0xFF});
+    /// let background = Color::new(Rgb {r: 255, g: 255, b: 255});
     ///
-    /// let cerp_color = Color::xyz_cubic_interpolate(
-    ///     color_a, color_b, 0.0, 0.0, 0.39);
+    /// let text = brand.adjust_for_contrast(background, 4.5).unwrap();
     ///
-    /// assert_eq!(cerp_color, 
-    ///     Color::new(Rgb::new(89, 217, 75)));
+    /// assert_eq!(text, Color::new(Rgb {r: 0, g: 119, b: 216}));
+    /// assert!(Color::contrast_ratio(text, background) >= 4.5);
+    /// assert_eq!(brand.adjust_for_contrast(background, 22.0), None);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1657,27 +4196,62 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn xyz_cubic_interpolate<C, D>(
-        start: C,
-        end: D,
-        start_slope: f32,
-        end_slope: f32,
-        amount: f32) -> Self 
-        where
-            C: Into<Self> + Sized,
-            D: Into<Self> + Sized,
+    pub fn adjust_for_contrast<C>(&self, background: C, target_ratio: f32)
+        -> Option<Self>
+        where C: Into<LinearRgb>
     {
-        Xyz::cubic_interpolate(
-            start.into(),
-            end.into(),
-            start_slope,
-            end_slope,
-            amount).into()
+        let span = span!(Level::DEBUG, "Color::adjust_for_contrast");
+        let _enter = span.enter();
+
+        let background = background.into();
+        let meets = |color: Color| {
+            contrast::contrast_ratio(color, background) >= target_ratio
+        };
+        if meets(*self) {
+            return Some(*self);
+        }
+
+        let oklch = Oklch::from(self.rgb);
+        let with_lightness = |l: f32| Color {
+            rgb: Rgb::from(Oklch {l, ..oklch}.srgb_gamut_mapped()),
+            alpha: self.alpha,
+        };
+        // Binary search between the current lightness, which fails, and the
+        // lightness limit, which must pass for a result to exist.
+        let search = |mut near: f32, mut far: f32| {
+            if !meets(with_lightness(far)) {
+                return None;
+            }
+            for _ in 0..24 {
+                let mid = (near + far) / 2.0;
+                if meets(with_lightness(mid)) {
+                    far = mid;
+                } else {
+                    near = mid;
+                }
+            }
+            Some(with_lightness(far))
+        };
+
+        match (search(oklch.l, 1.0), search(oklch.l, 0.0)) {
+            (Some(lighter), Some(darker)) => {
+                let lighter_distance = Color::oklab_distance(*self, lighter);
+                let darker_distance = Color::oklab_distance(*self, darker);
+                Some(if lighter_distance <= darker_distance {
+                    lighter
+                } else {
+                    darker
+                })
+            },
+            (lighter, darker) => lighter.or(darker),
+        }
     }
 
-    /// Returns the distance between the given colors in [`Rgb`] color space.
+    /// Returns the candidate color with the highest [WCAG 2.1 contrast ratio]
+    /// against the `background` color, or `None` if there are no candidates.
+    /// If several candidates have the same contrast, the first is returned.
     ///
-    /// [`Rgb`]: rgb/struct.Rgb.html
+    /// [WCAG 2.1 contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
     ///
     /// # Example
     ///
@@ -1686,10 +4260,14 @@ impl Color {
     /// # use color::{ Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
-    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
-    ///
-    /// assert_eq!(Color::rgb_distance(color_a, color_b), 161.52399);
+    /// let background = Color::new(Rgb {r: 0x33, g: 0x66, b: 0x99});
+    /// let candidates = [
+    ///     Color::new(Rgb {r: 0x11, g: 0x11, b: 0x11}),
+    ///     Color::new(Rgb {r: 0xFF, g: 0xFF, b: 0xFF}),
+    /// ];
+    ///
+    /// assert_eq!(Color::best_text_color(background, &candidates),
+    ///     Some(candidates[1]));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1698,29 +4276,48 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn rgb_distance<C, D>(start: C, end: D) -> f32 
-        where
-            C: Into<Rgb> + Sized,
-            D: Into<Rgb> + Sized,
+    pub fn best_text_color<C>(background: C, candidates: &[Color])
+        -> Option<Self>
+        where C: Into<LinearRgb>
     {
-        Rgb::distance(start.into(), end.into())
+        let background = background.into();
+        let mut best: Option<(f32, Color)> = None;
+        for &candidate in candidates {
+            let ratio = contrast::contrast_ratio(candidate, background);
+            let better = match best {
+                Some((best_ratio, _)) => ratio > best_ratio,
+                None                  => true,
+            };
+            if better {
+                best = Some((ratio, candidate));
+            }
+        }
+        best.map(|(_, color)| color)
     }
 
-    /// Returns the distance between the given colors in [`Cmyk`] color space.
+    /// Returns the color as it would appear to a viewer with the given kind of
+    /// color vision deficiency. The `severity` is clamped between 0 and 1,
+    /// where 1 simulates dichromacy and smaller values simulate anomalous
+    /// trichromacy. See [`cvd::simulate`] for details. The alpha component is
+    /// preserved.
     ///
-    /// [`Cmyk`]: cmyk/struct.Cmyk.html
+    /// [`cvd::simulate`]: cvd/fn.simulate.html
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
     /// # use color::{ Color, Rgb };
+    /// # use color::cvd::CvdKind;
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
-    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    /// let red = Color::new(Rgb {r: 255, g: 0, b: 0}).with_alpha(200);
+    /// let green = Color::new(Rgb {r: 0, g: 128, b: 0});
     ///
-    /// assert_eq!(Color::cmyk_distance(color_a, color_b), 186.12361);
+    /// assert_eq!(red.simulate_cvd(CvdKind::Protan, 1.0),
+    ///     Color::new(Rgb {r: 109, g: 95, b: 0}).with_alpha(200));
+    /// assert_eq!(green.simulate_cvd(CvdKind::Deutan, 0.6),
+    ///     Color::new(Rgb {r: 107, g: 112, b: 19}));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1729,29 +4326,31 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn cmyk_distance<C, D>(start: C, end: D) -> f32 
-        where
-            C: Into<Cmyk> + Sized,
-            D: Into<Cmyk> + Sized,
-    {
-        Cmyk::distance(start.into(), end.into())
+    pub fn simulate_cvd(&self, kind: CvdKind, severity: f32) -> Self {
+        Color {
+            rgb: Rgb::from(cvd::simulate(self.rgb, kind, severity)),
+            alpha: self.alpha,
+        }
     }
 
-    /// Returns the distance between the given colors in [`Hsl`] color space.
+    /// Returns the color adjusted to be easier to distinguish for a viewer
+    /// with the given kind of color vision deficiency. See [`cvd::daltonize`]
+    /// for details. The alpha component is preserved.
     ///
-    /// [`Hsl`]: hsl/struct.Hsl.html
+    /// [`cvd::daltonize`]: cvd/fn.daltonize.html
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
     /// # use color::{ Color, Rgb };
+    /// # use color::cvd::CvdKind;
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
-    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    /// let orange = Color::new(Rgb {r: 0xE0, g: 0x80, b: 0x30}).with_alpha(200);
     ///
-    /// assert_eq!(Color::hsl_distance(color_a, color_b), 0.71319157);
+    /// assert_eq!(orange.daltonize(CvdKind::Deutan),
+    ///     Color::new(Rgb {r: 224, g: 145, b: 133}).with_alpha(200));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1760,29 +4359,32 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsl_distance<C, D>(start: C, end: D) -> f32 
-        where
-            C: Into<Hsl> + Sized,
-            D: Into<Hsl> + Sized,
-    {
-        Hsl::distance(start.into(), end.into())
+    pub fn daltonize(&self, kind: CvdKind) -> Self {
+        Color {
+            rgb: Rgb::from(cvd::daltonize(self.rgb, kind)),
+            alpha: self.alpha,
+        }
     }
 
-    /// Returns the distance between the given colors in [`Hsv`] color space.
+    /// Composites the `over` color onto the `under` color using the given
+    /// Porter-Duff [`CompositeOperator`]. The colors are composited in linear
+    /// light with straight alpha.
     ///
-    /// [`Hsv`]: hsv/struct.Hsv.html
+    /// [`CompositeOperator`]: enum.CompositeOperator.html
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use color::{ Color, Rgb };
+    /// # use color::{ Color, CompositeOperator, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
-    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    /// let over = Color::new(Rgb {r: 255, g: 0, b: 0}).with_alpha(128);
+    /// let under = Color::new(Rgb {r: 0, g: 0, b: 255});
     ///
-    /// assert_eq!(Color::hsv_distance(color_a, color_b), 1.1794227);
+    /// let color = Color::composite(over, under, CompositeOperator::SourceOver);
+    ///
+    /// assert_eq!(color, Color::new(Rgb {r: 188, g: 0, b: 187}));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1791,29 +4393,34 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn hsv_distance<C, D>(start: C, end: D) -> f32 
+    pub fn composite<C, D>(over: C, under: D, op: CompositeOperator) -> Self
         where
-            C: Into<Hsv> + Sized,
-            D: Into<Hsv> + Sized,
+            C: Into<Color> + Sized,
+            D: Into<Color> + Sized,
     {
-        Hsv::distance(start.into(), end.into())
+        let source: Alpha<LinearRgb> = over.into().into();
+        let destination: Alpha<LinearRgb> = under.into().into();
+        op.composite(source, destination).into()
     }
 
-    /// Returns the distance between the given colors in [`Xyz`] color space.
+    /// Blends the `source` color onto the `backdrop` color using the given
+    /// [`BlendMode`], then composites the result over the backdrop.
     ///
-    /// [`Xyz`]: hsv/struct.Xyz.html
+    /// [`BlendMode`]: enum.BlendMode.html
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use color::{ Color, Rgb };
+    /// # use color::{ BlendMode, Color, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color_a = Color::new(Rgb {r: 127, g: 255, b: 64});
-    /// let color_b = Color::new(Rgb {r: 15, g: 144, b: 99});
+    /// let backdrop = Color::new(Rgb {r: 127, g: 255, b: 64});
+    /// let source = Color::new(Rgb {r: 15, g: 144, b: 99});
     ///
-    /// assert_eq!(Color::xyz_distance(color_a, color_b), 0.5080837);
+    /// let color = Color::blend(backdrop, source, BlendMode::Screen);
+    ///
+    /// assert_eq!(color, Color::new(Rgb {r: 135, g: 255, b: 138}));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -1822,35 +4429,68 @@ impl Color {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn xyz_distance<C, D>(start: C, end: D) -> f32 
+    pub fn blend<C, D>(backdrop: C, source: D, mode: BlendMode) -> Self
         where
-            C: Into<Xyz> + Sized,
-            D: Into<Xyz> + Sized,
+            C: Into<Color> + Sized,
+            D: Into<Color> + Sized,
     {
-        Xyz::distance(start.into(), end.into())
+        let backdrop: Alpha<Rgb> = backdrop.into().into();
+        let source: Alpha<Rgb> = source.into().into();
+        mode.blend(backdrop, source).into()
+    }
+}
+
+
+impl Default for Color {
+    fn default() -> Self {
+        Color::new(Rgb::default())
     }
 }
 
+/// Returns the alpha value of a fully opaque color.
+#[cfg(feature = "serde")]
+fn opaque_alpha() -> u8 {
+    u8::MAX
+}
+
 
 impl fmt::UpperHex for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        write!(f, "{:X}", self.rgb)
+        if self.alpha == u8::MAX {
+            write!(f, "{:X}", self.rgb)
+        } else {
+            write!(f, "{:X}{:02X}", self.rgb, self.alpha)
+        }
     }
 }
 
 impl fmt::LowerHex for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        write!(f, "{:x}", self.rgb)
+        if self.alpha == u8::MAX {
+            write!(f, "{:x}", self.rgb)
+        } else {
+            write!(f, "{:x}{:02x}", self.rgb, self.alpha)
+        }
     }
 }
 
 
+impl<C> From<Alpha<C>> for Color where C: Into<Rgb> {
+    fn from(alpha: Alpha<C>) -> Color {
+        let span = span!(Level::DEBUG, "Color::from<Alpha>");
+        let _enter = span.enter();
+        
+        let octet = alpha.alpha();
+        Color {rgb: alpha.color.into(), alpha: octet}
+    }
+}
+
 impl From<Cmyk> for Color {
     fn from(cmyk: Cmyk) -> Color {
         let span = span!(Level::DEBUG, "Color::from<Cmyk>");
         let _enter = span.enter();
         
-        Color {rgb: Rgb::from(cmyk)}
+        Color {rgb: Rgb::from(cmyk), alpha: u8::MAX}
     }
 }
 
@@ -1859,7 +4499,7 @@ impl From<Hsl> for Color {
         let span = span!(Level::DEBUG, "Color::from<Hsl>");
         let _enter = span.enter();
         
-        Color {rgb: Rgb::from(hsl)}
+        Color {rgb: Rgb::from(hsl), alpha: u8::MAX}
     }
 }
 
@@ -1868,7 +4508,7 @@ impl From<Rgb> for Color {
         let span = span!(Level::DEBUG, "Color::from<Rgb>");
         let _enter = span.enter();
         
-        Color {rgb: rgb}
+        Color {rgb, alpha: u8::MAX}
     }
 }
 
@@ -1877,7 +4517,52 @@ impl From<Hsv> for Color {
         let span = span!(Level::DEBUG, "Color::from<Hsv>");
         let _enter = span.enter();
         
-        Color {rgb: Rgb::from(hsv)}
+        Color {rgb: Rgb::from(hsv), alpha: u8::MAX}
+    }
+}
+
+impl From<Lab> for Color {
+    fn from(lab: Lab) -> Color {
+        let span = span!(Level::DEBUG, "Color::from<Lab>");
+        let _enter = span.enter();
+        
+        Color {rgb: Rgb::from(lab), alpha: u8::MAX}
+    }
+}
+
+impl From<Lch> for Color {
+    fn from(lch: Lch) -> Color {
+        let span = span!(Level::DEBUG, "Color::from<Lch>");
+        let _enter = span.enter();
+        
+        Color {rgb: Rgb::from(lch), alpha: u8::MAX}
+    }
+}
+
+impl From<LinearRgb> for Color {
+    fn from(linear_rgb: LinearRgb) -> Color {
+        let span = span!(Level::DEBUG, "Color::from<LinearRgb>");
+        let _enter = span.enter();
+        
+        Color {rgb: Rgb::from(linear_rgb), alpha: u8::MAX}
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(oklab: Oklab) -> Color {
+        let span = span!(Level::DEBUG, "Color::from<Oklab>");
+        let _enter = span.enter();
+        
+        Color {rgb: Rgb::from(oklab), alpha: u8::MAX}
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(oklch: Oklch) -> Color {
+        let span = span!(Level::DEBUG, "Color::from<Oklch>");
+        let _enter = span.enter();
+        
+        Color {rgb: Rgb::from(oklch), alpha: u8::MAX}
     }
 }
 
@@ -1886,7 +4571,7 @@ impl From<Xyz> for Color {
         let span = span!(Level::DEBUG, "Color::from<Xyz>");
         let _enter = span.enter();
         
-        Color {rgb: Rgb::from(xyz)}
+        Color {rgb: Rgb::from(xyz), alpha: u8::MAX}
     }
 }
 
@@ -1906,7 +4591,8 @@ impl From<Color> for [f32; 4] {
         let span = span!(Level::DEBUG, "[f32; 4]::from<Color>");
         let _enter = span.enter();
         
-        Rgb::from(color).into()
+        let [r, g, b] = color.rgb.ratios();
+        [r, g, b, color.alpha_ratio()]
     }
 }
 
@@ -1920,6 +4606,16 @@ impl From<Color> for Rgb {
     }
 }
 
+/// Converts the color to an Alpha.
+impl<C> From<Color> for Alpha<C> where C: From<Rgb> {
+    fn from(color: Color) -> Self {
+        let span = span!(Level::DEBUG, "Alpha::from<Color>");
+        let _enter = span.enter();
+        
+        Alpha::new(C::from(color.rgb), color.alpha_ratio())
+    }
+}
+
 /// Converts the color to a Cmyk.
 impl From<Color> for Cmyk {
     fn from(color: Color) -> Self {
@@ -1949,6 +4645,56 @@ impl From<Color> for Hsv {
     }
 }
 
+/// Converts the color to a Lab.
+impl From<Color> for Lab {
+    fn from(color: Color) -> Self {
+        let span = span!(Level::DEBUG, "Lab::from<Color>");
+        let _enter = span.enter();
+        
+        color.rgb.into()
+    }
+}
+
+/// Converts the color to a Lch.
+impl From<Color> for Lch {
+    fn from(color: Color) -> Self {
+        let span = span!(Level::DEBUG, "Lch::from<Color>");
+        let _enter = span.enter();
+        
+        color.rgb.into()
+    }
+}
+
+/// Converts the color to a LinearRgb.
+impl From<Color> for LinearRgb {
+    fn from(color: Color) -> Self {
+        let span = span!(Level::DEBUG, "LinearRgb::from<Color>");
+        let _enter = span.enter();
+        
+        color.rgb.into()
+    }
+}
+
+/// Converts the color to an Oklab.
+impl From<Color> for Oklab {
+    fn from(color: Color) -> Self {
+        let span = span!(Level::DEBUG, "Oklab::from<Color>");
+        let _enter = span.enter();
+        
+        color.rgb.into()
+    }
+}
+
+/// Converts the color to an Oklch.
+impl From<Color> for Oklch {
+    fn from(color: Color) -> Self {
+        let span = span!(Level::DEBUG, "Oklch::from<Color>");
+        let _enter = span.enter();
+        
+        color.rgb.into()
+    }
+}
+
 /// Converts the color to a Xyz.
 impl From<Color> for Xyz {
     fn from(color: Color) -> Self {
@@ -10,8 +10,14 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 // Internal modules.
+pub(in crate) mod alpha;
 pub(in crate) mod cmyk;
 pub(in crate) mod hsl;
 pub(in crate) mod hsv;
+pub(in crate) mod lab;
+pub(in crate) mod lch;
+pub(in crate) mod linear_rgb;
+pub(in crate) mod oklab;
+pub(in crate) mod oklch;
 pub(in crate) mod rgb;
 pub(in crate) mod xyz;
@@ -0,0 +1,511 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Defines an alpha channel wrapper for the color spaces.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Hsl;
+use crate::Hsv;
+use crate::LinearRgb;
+use crate::ParseColorError;
+use crate::Rgb;
+use crate::utility::clamped;
+use crate::utility::lerp_f32;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+// Standard library imports.
+use std::fmt;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Type aliases
+////////////////////////////////////////////////////////////////////////////////
+/// An [`Rgb`] color with an alpha component.
+///
+/// [`Rgb`]: struct.Rgb.html
+pub type Rgba = Alpha<Rgb>;
+
+/// An [`Hsl`] color with an alpha component.
+///
+/// [`Hsl`]: struct.Hsl.html
+pub type Hsla = Alpha<Hsl>;
+
+/// An [`Hsv`] color with an alpha component.
+///
+/// [`Hsv`]: struct.Hsv.html
+pub type Hsva = Alpha<Hsv>;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Alpha
+////////////////////////////////////////////////////////////////////////////////
+/// A color with an alpha (opacity) component. The alpha is stored as a ratio,
+/// where 0 is fully transparent and 1 is fully opaque.
+///
+/// As with [`Color`], the [`alpha`] and [`set_alpha`] accessors use an octet
+/// from 0 to 255, and the [`alpha_ratio`] and [`set_alpha_ratio`] accessors
+/// use the stored ratio.
+///
+/// [`Color`]: struct.Color.html
+/// [`alpha`]: #method.alpha
+/// [`set_alpha`]: #method.set_alpha
+/// [`alpha_ratio`]: #method.alpha_ratio
+/// [`set_alpha_ratio`]: #method.set_alpha_ratio
+#[derive(Debug, PartialOrd, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Alpha<C> {
+    /// The color component.
+    pub color: C,
+    /// The alpha component as a ratio.
+    pub alpha: f32,
+}
+
+
+impl<C> Alpha<C> {
+    /// Constructs a new `Alpha` color with the given alpha ratio.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Alpha::new(Rgb::new(127, 255, 64), 0.5);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn new(color: C, alpha: f32) -> Self {
+        let mut a = Alpha {color, alpha: 1.0};
+        a.set_alpha_ratio(alpha);
+        a
+    }
+
+    /// Constructs a new fully opaque `Alpha` color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Alpha::opaque(Rgb::new(127, 255, 64));
+    ///
+    /// assert_eq!(color.alpha(), 255);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn opaque(color: C) -> Self {
+        Alpha {color, alpha: 1.0}
+    }
+
+    /// Returns a reference to the color component.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Alpha::new(Rgb::new(127, 255, 64), 0.5);
+    ///
+    /// assert_eq!(color.color(), &Rgb::new(127, 255, 64));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn color(&self) -> &C {
+        &self.color
+    }
+
+    /// Returns the alpha component as an octet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Alpha::new(Rgb::new(127, 255, 64), 0.5);
+    ///
+    /// assert_eq!(color.alpha(), 128);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn alpha(&self) -> u8 {
+        (u8::MAX as f32 * self.alpha + 0.5) as u8
+    }
+
+    /// Returns the alpha component as a ratio.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Alpha::new(Rgb::new(127, 255, 64), 0.5);
+    ///
+    /// assert_eq!(color.alpha_ratio(), 0.5);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn alpha_ratio(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Sets the color component.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Alpha::new(Rgb::new(127, 255, 64), 0.5);
+    ///
+    /// color.set_color(Rgb::new(15, 144, 99));
+    ///
+    /// assert_eq!(color.color(), &Rgb::new(15, 144, 99));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_color(&mut self, color: C) {
+        self.color = color;
+    }
+
+    /// Sets the alpha component as an octet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Alpha::new(Rgb::new(127, 255, 64), 0.5);
+    ///
+    /// color.set_alpha(51);
+    ///
+    /// assert_eq!(color.alpha_ratio(), 0.2);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_alpha(&mut self, alpha: u8) {
+        self.alpha = alpha as f32 / u8::MAX as f32;
+    }
+
+    /// Sets the alpha component as a ratio.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Alpha::new(Rgb::new(127, 255, 64), 0.5);
+    ///
+    /// color.set_alpha_ratio(0.25);
+    ///
+    /// assert_eq!(color.alpha_ratio(), 0.25);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_alpha_ratio(&mut self, alpha: f32) {
+        self.alpha = clamped(alpha, 0.0, 1.0);
+    }
+
+    /// Converts the color component into another color space, preserving the
+    /// alpha component.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, Hsl, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Alpha::new(Rgb::new(255, 0, 0), 0.5);
+    ///
+    /// let hsla: Alpha<Hsl> = color.convert();
+    ///
+    /// assert_eq!(hsla, Alpha::new(Hsl::new(0.0, 1.0, 0.5), 0.5));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn convert<D>(self) -> Alpha<D> where D: From<C> {
+        Alpha {color: D::from(self.color), alpha: self.alpha}
+    }
+
+    /// Performs a linear interpolation between given colors, returning the
+    /// color located at the ratio given by `amount`, which is clamped between
+    /// 1 and 0. The color components are interpolated using `interpolate`,
+    /// and the alpha components are interpolated linearly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Alpha::new(Rgb::new(127, 255, 64), 1.0);
+    /// let color_b = Alpha::new(Rgb::new(15, 144, 99), 0.0);
+    ///
+    /// let lerp_color = Alpha::linear_interpolate(
+    ///     color_a, color_b, 0.65, Rgb::linear_interpolate::<Rgb, Rgb>);
+    ///
+    /// assert_eq!(lerp_color, Alpha::new(Rgb::new(54, 182, 86), 0.35000002));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn linear_interpolate<F>(
+        start: Self,
+        end: Self,
+        amount: f32,
+        interpolate: F) -> Self
+        where F: FnOnce(C, C, f32) -> C,
+    {
+        Alpha {
+            color: interpolate(start.color, end.color, amount),
+            alpha: lerp_f32(start.alpha, end.alpha, amount),
+        }
+    }
+}
+
+
+impl<C> Default for Alpha<C> where C: Default {
+    fn default() -> Self {
+        Alpha::opaque(C::default())
+    }
+}
+
+
+impl<C> fmt::Display for Alpha<C> where C: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{:?}", self)
+    }
+}
+
+
+impl Alpha<Rgb> {
+    /// Constructs a new `Rgba` color by parsing a hex code.
+    ///
+    /// Three, four, six, and eight digit variations are acceptable. If no
+    /// alpha digits are given, the color will be fully opaque.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Rgb, Rgba };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Rgba::from_hex_code("#a1b2c380").unwrap();
+    /// let color_short = Rgba::from_hex_code("#abc8").unwrap();
+    /// let color_opaque = Rgba::from_hex_code("#a1b2c3").unwrap();
+    ///
+    /// assert_eq!(color.color(), &Rgb::new(0xA1, 0xB2, 0xC3));
+    /// assert_eq!(color.alpha(), 0x80);
+    /// assert_eq!(color_short.alpha(), 0x88);
+    /// assert_eq!(color_opaque.alpha(), 0xFF);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn from_hex_code(hex: &str) -> Result<Self, ParseColorError> {
+        let (rgb, alpha) = Rgb::from_hex_code_with_alpha(hex)?;
+        Ok(Alpha {
+            color: rgb,
+            alpha: alpha.map_or(1.0, |a| a as f32 / u8::MAX as f32),
+        })
+    }
+
+    /// Returns the `Rgba` hex code of the color, with the alpha component in
+    /// the lowest byte.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Rgb, Rgba };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Rgba::new(Rgb::new(127, 255, 64), 0.5);
+    ///
+    /// assert_eq!(color.hex(), 0x7FFF4080);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hex(&self) -> u32 {
+        self.color.hex() << 8 | self.alpha() as u32
+    }
+}
+
+
+impl Alpha<LinearRgb> {
+    /// Returns the color with its color components multiplied by its alpha
+    /// component.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, LinearRgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Alpha::new(LinearRgb::new(0.8, 0.4, 0.2), 0.5);
+    ///
+    /// assert_eq!(color.premultiplied(),
+    ///     Alpha::new(LinearRgb::new(0.4, 0.2, 0.1), 0.5));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn premultiplied(&self) -> Self {
+        let c = self.color;
+        Alpha {
+            color: LinearRgb {
+                r: c.r * self.alpha,
+                g: c.g * self.alpha,
+                b: c.b * self.alpha,
+            },
+            alpha: self.alpha,
+        }
+    }
+
+    /// Returns the color with its color components divided by its alpha
+    /// component. This is the inverse of [`premultiplied`]. Fully transparent
+    /// colors become transparent black.
+    ///
+    /// [`premultiplied`]: #method.premultiplied
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, LinearRgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Alpha::new(LinearRgb::new(0.4, 0.2, 0.1), 0.5);
+    ///
+    /// assert_eq!(color.unpremultiplied(),
+    ///     Alpha::new(LinearRgb::new(0.8, 0.4, 0.2), 0.5));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn unpremultiplied(&self) -> Self {
+        if self.alpha <= 0.0 {
+            return Alpha {color: LinearRgb::default(), alpha: 0.0};
+        }
+        let c = self.color;
+        Alpha {
+            color: LinearRgb {
+                r: clamped(c.r / self.alpha, 0.0, 1.0),
+                g: clamped(c.g / self.alpha, 0.0, 1.0),
+                b: clamped(c.b / self.alpha, 0.0, 1.0),
+            },
+            alpha: self.alpha,
+        }
+    }
+}
+
+
+impl fmt::UpperHex for Alpha<Rgb> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{:X}{:02X}", self.color, self.alpha())
+    }
+}
+
+
+impl fmt::LowerHex for Alpha<Rgb> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{:x}{:02x}", self.color, self.alpha())
+    }
+}
@@ -12,12 +12,19 @@
 ////////////////////////////////////////////////////////////////////////////////
 // Local imports.
 use crate::Hsl;
+use crate::Hsv;
+use crate::Lab;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::Oklab;
+use crate::Oklch;
 use crate::Rgb;
 use crate::utility::cerp_u8;
 use crate::utility::clamped;
 use crate::utility::distance;
 use crate::utility::lerp_u8;
 use crate::utility::nearly_equal;
+use crate::Xyz;
 
 // External library imports.
 #[cfg(feature = "serde")]
@@ -624,3 +631,66 @@ impl From<Hsl> for Cmyk {
         Cmyk::from(Rgb::from(hsl))
     }
 }
+
+impl From<Hsv> for Cmyk {
+    fn from(hsv: Hsv) -> Self {
+        let span = span!(Level::DEBUG, "Cmyk::from<Hsv>");
+        let _enter = span.enter();
+        
+        Cmyk::from(Rgb::from(hsv))
+    }
+}
+
+impl From<Lab> for Cmyk {
+    fn from(lab: Lab) -> Self {
+        let span = span!(Level::DEBUG, "Cmyk::from<Lab>");
+        let _enter = span.enter();
+
+        Cmyk::from(Rgb::from(lab))
+    }
+}
+
+impl From<Lch> for Cmyk {
+    fn from(lch: Lch) -> Self {
+        let span = span!(Level::DEBUG, "Cmyk::from<Lch>");
+        let _enter = span.enter();
+
+        Cmyk::from(Rgb::from(lch))
+    }
+}
+
+impl From<LinearRgb> for Cmyk {
+    fn from(linear_rgb: LinearRgb) -> Self {
+        let span = span!(Level::DEBUG, "Cmyk::from<LinearRgb>");
+        let _enter = span.enter();
+        
+        Cmyk::from(Rgb::from(linear_rgb))
+    }
+}
+
+impl From<Xyz> for Cmyk {
+    fn from(xyz: Xyz) -> Self {
+        let span = span!(Level::DEBUG, "Cmyk::from<Xyz>");
+        let _enter = span.enter();
+        
+        Cmyk::from(Rgb::from(xyz))
+    }
+}
+
+impl From<Oklab> for Cmyk {
+    fn from(oklab: Oklab) -> Self {
+        let span = span!(Level::DEBUG, "Cmyk::from<Oklab>");
+        let _enter = span.enter();
+
+        Cmyk::from(Rgb::from(oklab))
+    }
+}
+
+impl From<Oklch> for Cmyk {
+    fn from(oklch: Oklch) -> Self {
+        let span = span!(Level::DEBUG, "Cmyk::from<Oklch>");
+        let _enter = span.enter();
+
+        Cmyk::from(Rgb::from(oklch))
+    }
+}
@@ -14,11 +14,20 @@
 // Local imports.
 use crate::Cmyk;
 use crate::Hsv;
+use crate::HueInterpolation;
+use crate::Lab;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::Oklab;
+use crate::Oklch;
 use crate::Rgb;
 use crate::utility::cerp_f32;
+use crate::utility::cerp_hue;
 use crate::utility::clamped;
 use crate::utility::lerp_f32;
+use crate::utility::lerp_hue;
 use crate::utility::nearly_equal;
+use crate::utility::normalize_hue;
 use crate::Xyz;
 
 // External library imports.
@@ -173,7 +182,7 @@ impl Hsl {
     /// ```
     pub fn set_hue(&mut self, hue: f32) {
         assert!(hue.is_finite());
-        self.h = hue % 360.0;
+        self.h = normalize_hue(hue);
     }
 
     /// Sets the saturation component of the color as a ratio.
@@ -254,21 +263,23 @@ impl Hsl {
 
     /// Performs a component-wise linear interpolation between given colors,
     /// returning the color located at the ratio given by `amount`, which is
-    /// clamped between 1 and 0.
+    /// clamped between 1 and 0. The hue is interpolated around the hue circle
+    /// in the direction given by `hue_interpolation`.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use color::Hsl;
+    /// # use color::{ Hsl, HueInterpolation };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let color_a = Hsl::new(34.0, 0.63, 0.35);
     /// let color_b = Hsl::new(322.0, 0.14, 0.95);
     ///
-    /// let lerp_color = Hsl::linear_interpolate(color_a, color_b, 0.65);
+    /// let lerp_color = Hsl::linear_interpolate(
+    ///     color_a, color_b, 0.65, HueInterpolation::Shorter);
     ///
-    /// assert_eq!(lerp_color, Hsl::new(221.2, 0.3115, 0.74));
+    /// assert_eq!(lerp_color, Hsl::new(347.2, 0.3115, 0.74));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -277,15 +288,20 @@ impl Hsl {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+    pub fn linear_interpolate<C, D>(
+        start: C,
+        end: D,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
         where
             C: Into<Self> + Sized,
             D: Into<Self> + Sized,
     {
         let s = start.into();
         let e = end.into();
+        let (sh, eh) = Hsl::interpolation_hues(&s, &e);
         Hsl {
-            h: lerp_f32(s.h, e.h, amount),
+            h: lerp_hue(sh, eh, amount, hue_interpolation),
             s: lerp_f32(s.s, e.s, amount),
             l: lerp_f32(s.l, e.l, amount),
         }
@@ -294,20 +310,22 @@ impl Hsl {
     /// Performs a component-wise cubic interpolation between given colors,
     /// returning the color located at the ratio given by `amount`, which is
     /// clamped between 1 and 0. The interpolation function will be consistent
-    /// with the slopes given by `start_slope` and `end_slope`.
+    /// with the slopes given by `start_slope` and `end_slope`. The hue is
+    /// interpolated around the hue circle in the direction given by
+    /// `hue_interpolation`.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use color::Hsl;
+    /// # use color::{ Hsl, HueInterpolation };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let color_a = Hsl::new(0.24, 0.68, 0.91);
     /// let color_b = Hsl::new(0.84, 0.228, 0.455);
     ///
     /// let lerp_color = Hsl::cubic_interpolate(
-    ///     color_a, color_b, 0.0, 0.0, 0.19);
+    ///     color_a, color_b, 0.0, 0.0, 0.19, HueInterpolation::Shorter);
     ///
     /// assert_eq!(lerp_color, Hsl::new(0.29674917, 0.63724893, 0.8669652));
     /// # //-------------------------------------------------------------------
@@ -323,15 +341,18 @@ impl Hsl {
         end: D,
         start_slope: f32,
         end_slope: f32,
-        amount: f32) -> Self 
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
         where
             C: Into<Self> + Sized,
             D: Into<Self> + Sized,
     {
         let s = start.into();
         let e = end.into();
+        let (sh, eh) = Hsl::interpolation_hues(&s, &e);
         Hsl {
-            h: cerp_f32(s.h, e.h, start_slope, end_slope, amount),
+            h: cerp_hue(sh, eh, start_slope, end_slope, amount,
+                hue_interpolation),
             s: cerp_f32(s.s, e.s, start_slope, end_slope, amount),
             l: cerp_f32(s.l, e.l, start_slope, end_slope, amount),
         }
@@ -377,6 +398,17 @@ impl Hsl {
 
         (s*s + x*x + y*y).sqrt() / 6.0f32.sqrt()
     }
+
+    /// Returns the hues to interpolate between for the given colors. The hue
+    /// of a gray is undefined, so it takes on the hue of the other color to
+    /// avoid introducing a hue shift.
+    fn interpolation_hues(s: &Hsl, e: &Hsl) -> (f32, f32) {
+        match (nearly_equal(s.s, 0.0), nearly_equal(e.s, 0.0)) {
+            (true, false) => (e.h, e.h),
+            (false, true) => (s.h, s.h),
+            _             => (s.h, e.h),
+        }
+    }
 }
 
 
@@ -421,6 +453,51 @@ impl From<Hsv> for Hsl {
     }
 }
 
+impl From<Lab> for Hsl {
+    fn from(lab: Lab) -> Self {
+        let span = span!(Level::DEBUG, "Hsl::from<Lab>");
+        let _enter = span.enter();
+        
+        Hsl::from(Rgb::from(lab))
+    }
+}
+
+impl From<Lch> for Hsl {
+    fn from(lch: Lch) -> Self {
+        let span = span!(Level::DEBUG, "Hsl::from<Lch>");
+        let _enter = span.enter();
+        
+        Hsl::from(Rgb::from(lch))
+    }
+}
+
+impl From<LinearRgb> for Hsl {
+    fn from(linear_rgb: LinearRgb) -> Self {
+        let span = span!(Level::DEBUG, "Hsl::from<LinearRgb>");
+        let _enter = span.enter();
+        
+        Hsl::from(Rgb::from(linear_rgb))
+    }
+}
+
+impl From<Oklab> for Hsl {
+    fn from(oklab: Oklab) -> Self {
+        let span = span!(Level::DEBUG, "Hsl::from<Oklab>");
+        let _enter = span.enter();
+        
+        Hsl::from(Rgb::from(oklab))
+    }
+}
+
+impl From<Oklch> for Hsl {
+    fn from(oklch: Oklch) -> Self {
+        let span = span!(Level::DEBUG, "Hsl::from<Oklch>");
+        let _enter = span.enter();
+        
+        Hsl::from(Rgb::from(oklch))
+    }
+}
+
 impl From<Rgb> for Hsl {
     fn from(rgb: Rgb) -> Self {
         let span = span!(Level::DEBUG, "Hsl::from<Rgb>");
@@ -456,7 +533,7 @@ impl From<Rgb> for Hsl {
             };
 
             // Compute hue.
-            let mut h = 60.0 * match max_index {
+            let h = 60.0 * match max_index {
                 0 => (ratios[1] - ratios[2]) / delta,
                 1 => (ratios[2] - ratios[0]) / delta + 2.0,
                 2 => (ratios[0] - ratios[1]) / delta + 4.0,
@@ -14,11 +14,20 @@
 // Local imports.
 use crate::Cmyk;
 use crate::Hsl;
+use crate::HueInterpolation;
+use crate::Lab;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::Oklab;
+use crate::Oklch;
 use crate::Rgb;
 use crate::utility::cerp_f32;
+use crate::utility::cerp_hue;
 use crate::utility::clamped;
 use crate::utility::lerp_f32;
+use crate::utility::lerp_hue;
 use crate::utility::nearly_equal;
+use crate::utility::normalize_hue;
 use crate::Xyz;
 
 // External library imports.
@@ -172,7 +181,7 @@ impl Hsv {
     /// ```
     pub fn set_hue(&mut self, hue: f32) {
         assert!(hue.is_finite());
-        self.h = hue % 360.0;
+        self.h = normalize_hue(hue);
     }
 
     /// Sets the saturation component of the color as a ratio.
@@ -253,21 +262,23 @@ impl Hsv {
 
     /// Performs a component-wise linear interpolation between given colors,
     /// returning the color located at the ratio given by `amount`, which is
-    /// clamped between 1 and 0.
+    /// clamped between 1 and 0. The hue is interpolated around the hue circle
+    /// in the direction given by `hue_interpolation`.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use color::Hsv;
+    /// # use color::{ Hsv, HueInterpolation };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let color_a = Hsv::new(34.0, 0.63, 0.35);
     /// let color_b = Hsv::new(322.0, 0.14, 0.95);
     ///
-    /// let lerp_color = Hsv::linear_interpolate(color_a, color_b, 0.65);
+    /// let lerp_color = Hsv::linear_interpolate(
+    ///     color_a, color_b, 0.65, HueInterpolation::Shorter);
     ///
-    /// assert_eq!(lerp_color, Hsv::new(221.2, 0.3115, 0.74));
+    /// assert_eq!(lerp_color, Hsv::new(347.2, 0.3115, 0.74));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -276,15 +287,20 @@ impl Hsv {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+    pub fn linear_interpolate<C, D>(
+        start: C,
+        end: D,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
         where
             C: Into<Self> + Sized,
             D: Into<Self> + Sized,
     {
         let s = start.into();
         let e = end.into();
+        let (sh, eh) = Hsv::interpolation_hues(&s, &e);
         Hsv {
-            h: lerp_f32(s.h, e.h, amount),
+            h: lerp_hue(sh, eh, amount, hue_interpolation),
             s: lerp_f32(s.s, e.s, amount),
             v: lerp_f32(s.v, e.v, amount),
         }
@@ -293,20 +309,22 @@ impl Hsv {
     /// Performs a component-wise cubic interpolation between given colors,
     /// returning the color located at the ratio given by `amount`, which is
     /// clamped between 1 and 0. The interpolation function will be consistent
-    /// with the slopes given by `start_slope` and `end_slope`.
+    /// with the slopes given by `start_slope` and `end_slope`. The hue is
+    /// interpolated around the hue circle in the direction given by
+    /// `hue_interpolation`.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use color::Hsv;
+    /// # use color::{ Hsv, HueInterpolation };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let color_a = Hsv::new(0.24, 0.68, 0.91);
     /// let color_b = Hsv::new(0.84, 0.228, 0.455);
     ///
     /// let lerp_color = Hsv::cubic_interpolate(
-    ///     color_a, color_b, 0.0, 0.0, 0.19);
+    ///     color_a, color_b, 0.0, 0.0, 0.19, HueInterpolation::Shorter);
     ///
     /// assert_eq!(lerp_color, Hsv::new(0.29674917, 0.63724893, 0.8669652));
     /// # //-------------------------------------------------------------------
@@ -322,15 +340,18 @@ impl Hsv {
         end: D,
         start_slope: f32,
         end_slope: f32,
-        amount: f32) -> Self 
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
         where
             C: Into<Self> + Sized,
             D: Into<Self> + Sized,
     {
         let s = start.into();
         let e = end.into();
+        let (sh, eh) = Hsv::interpolation_hues(&s, &e);
         Hsv {
-            h: cerp_f32(s.h, e.h, start_slope, end_slope, amount),
+            h: cerp_hue(sh, eh, start_slope, end_slope, amount,
+                hue_interpolation),
             s: cerp_f32(s.s, e.s, start_slope, end_slope, amount),
             v: cerp_f32(s.v, e.v, start_slope, end_slope, amount),
         }
@@ -378,6 +399,17 @@ impl Hsv {
 
         (s*s + x*x + y*y).sqrt() / 6.0f32.sqrt()
     }
+
+    /// Returns the hues to interpolate between for the given colors. The hue
+    /// of a gray is undefined, so it takes on the hue of the other color to
+    /// avoid introducing a hue shift.
+    fn interpolation_hues(s: &Hsv, e: &Hsv) -> (f32, f32) {
+        match (nearly_equal(s.s, 0.0), nearly_equal(e.s, 0.0)) {
+            (true, false) => (e.h, e.h),
+            (false, true) => (s.h, s.h),
+            _             => (s.h, e.h),
+        }
+    }
 }
 
 
@@ -422,6 +454,51 @@ impl From<Hsl> for Hsv {
     }
 }
 
+impl From<Lab> for Hsv {
+    fn from(lab: Lab) -> Self {
+        let span = span!(Level::DEBUG, "Hsv::from<Lab>");
+        let _enter = span.enter();
+        
+        Hsv::from(Rgb::from(lab))
+    }
+}
+
+impl From<Lch> for Hsv {
+    fn from(lch: Lch) -> Self {
+        let span = span!(Level::DEBUG, "Hsv::from<Lch>");
+        let _enter = span.enter();
+        
+        Hsv::from(Rgb::from(lch))
+    }
+}
+
+impl From<LinearRgb> for Hsv {
+    fn from(linear_rgb: LinearRgb) -> Self {
+        let span = span!(Level::DEBUG, "Hsv::from<LinearRgb>");
+        let _enter = span.enter();
+        
+        Hsv::from(Rgb::from(linear_rgb))
+    }
+}
+
+impl From<Oklab> for Hsv {
+    fn from(oklab: Oklab) -> Self {
+        let span = span!(Level::DEBUG, "Hsv::from<Oklab>");
+        let _enter = span.enter();
+        
+        Hsv::from(Rgb::from(oklab))
+    }
+}
+
+impl From<Oklch> for Hsv {
+    fn from(oklch: Oklch) -> Self {
+        let span = span!(Level::DEBUG, "Hsv::from<Oklch>");
+        let _enter = span.enter();
+        
+        Hsv::from(Rgb::from(oklch))
+    }
+}
+
 impl From<Rgb> for Hsv {
     fn from(rgb: Rgb) -> Self {
         let span = span!(Level::DEBUG, "Hsv::from<Rgb>");
@@ -455,7 +532,7 @@ impl From<Rgb> for Hsv {
             };
 
             // Compute hue.
-            let mut h = 60.0 * match max_index {
+            let h = 60.0 * match max_index {
                 0 => ((ratios[1] - ratios[2]) / delta) % 6.0,
                 1 => (ratios[2] - ratios[0]) / delta + 2.0,
                 2 => (ratios[0] - ratios[1]) / delta + 4.0,
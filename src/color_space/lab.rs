@@ -0,0 +1,520 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Defines a 96-bit CIE L*a*b* color space.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Cmyk;
+use crate::color_space::xyz::D65_WHITE;
+use crate::Hsl;
+use crate::Hsv;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::Oklab;
+use crate::Oklch;
+use crate::Rgb;
+use crate::utility::cerp_f32;
+use crate::utility::clamped;
+use crate::utility::lerp_f32;
+use crate::Xyz;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use tracing::Level;
+use tracing::span;
+
+// Standard library imports.
+use std::convert::From;
+use std::fmt;
+use std::f32;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Constants
+////////////////////////////////////////////////////////////////////////////////
+/// The CIE epsilon constant, `(6/29)^3`.
+pub(in crate) const EPSILON: f32 = 216.0 / 24389.0;
+
+/// The CIE kappa constant, `(29/3)^3`.
+pub(in crate) const KAPPA: f32 = 24389.0 / 27.0;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Lab
+////////////////////////////////////////////////////////////////////////////////
+/// The encoded CIE L*a*b* color, relative to the D65 reference white.
+#[derive(Debug, PartialOrd, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lab {
+    /// The lightness component.
+    pub(in crate) l: f32,
+    /// The green-red component.
+    pub(in crate) a: f32,
+    /// The blue-yellow component.
+    pub(in crate) b: f32,
+}
+
+
+impl Lab {
+    /// Constructs a new `Lab` color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Lab::new(54.0, -22.5, 38.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn new(lightness: f32, a: f32, b: f32) -> Self {
+        let mut lab = Lab {l: 0.0, a: 0.0, b: 0.0};
+        lab.set_lightness(lightness);
+        lab.set_a(a);
+        lab.set_b(b);
+        lab
+    }
+
+    /// Returns the lightness component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Lab::new(54.0, -22.5, 38.0);
+    ///
+    /// assert_eq!(color.lightness(), 54.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    /// Returns the a (green-red) component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Lab::new(54.0, -22.5, 38.0);
+    ///
+    /// assert_eq!(color.a(), -22.5);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+
+    /// Returns the b (blue-yellow) component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Lab::new(54.0, -22.5, 38.0);
+    ///
+    /// assert_eq!(color.b(), 38.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+
+    /// Sets the lightness component of the color. The value is clamped between
+    /// 0 and 100.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Lab::new(54.0, -22.5, 38.0);
+    /// color.set_lightness(72.0);
+    ///
+    /// assert_eq!(color.lightness(), 72.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_lightness(&mut self, lightness: f32) {
+        self.l = clamped(lightness, 0.0, 100.0);
+    }
+
+    /// Sets the a (green-red) component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Lab::new(54.0, -22.5, 38.0);
+    /// color.set_a(12.0);
+    ///
+    /// assert_eq!(color.a(), 12.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_a(&mut self, a: f32) {
+        assert!(a.is_finite());
+        self.a = a;
+    }
+
+    /// Sets the b (blue-yellow) component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Lab::new(54.0, -22.5, 38.0);
+    /// color.set_b(-6.0);
+    ///
+    /// assert_eq!(color.b(), -6.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_b(&mut self, b: f32) {
+        assert!(b.is_finite());
+        self.b = b;
+    }
+
+    /// Returns an array containing the `[L, a, b]` components.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Lab::new(54.0, -22.5, 38.0);
+    ///
+    /// let components = color.components();
+    ///
+    /// assert_eq!(components, [54.0, -22.5, 38.0]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn components(&self) -> [f32; 3] {
+        [self.l, self.a, self.b]
+    }
+
+    /// Performs a component-wise linear interpolation between given colors,
+    /// returning the color located at the ratio given by `amount`, which is
+    /// clamped between 1 and 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Lab::new(54.0, -22.5, 38.0);
+    /// let color_b = Lab::new(32.0, 60.0, -12.0);
+    ///
+    /// let lerp_color = Lab::linear_interpolate(color_a, color_b, 0.65);
+    ///
+    /// assert_eq!(lerp_color, Lab::new(39.7, 31.124996, 5.500002));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+        Lab {
+            l: lerp_f32(s.l, e.l, amount),
+            a: lerp_f32(s.a, e.a, amount),
+            b: lerp_f32(s.b, e.b, amount),
+        }
+    }
+
+    /// Performs a component-wise cubic interpolation between given colors,
+    /// returning the color located at the ratio given by `amount`, which is
+    /// clamped between 1 and 0. The interpolation function will be consistent
+    /// with the slopes given by `start_slope` and `end_slope`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Lab::new(54.0, -22.5, 38.0);
+    /// let color_b = Lab::new(32.0, 60.0, -12.0);
+    ///
+    /// let cerp_color = Lab::cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.19);
+    ///
+    /// assert_eq!(cerp_color, Lab::new(51.919193, -14.696984, 33.270897));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+        Lab {
+            l: cerp_f32(s.l, e.l, start_slope, end_slope, amount),
+            a: cerp_f32(s.a, e.a, start_slope, end_slope, amount),
+            b: cerp_f32(s.b, e.b, start_slope, end_slope, amount),
+        }
+    }
+
+    /// Returns the distance between the given colors in `Lab` color space.
+    ///
+    /// This is the CIE76 color difference, where a distance of about 2.3
+    /// corresponds to a just noticeable difference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Lab::new(54.0, -22.5, 38.0);
+    /// let color_b = Lab::new(32.0, 60.0, -12.0);
+    ///
+    /// assert_eq!(Lab::distance(color_a, color_b), 98.945694);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn distance<C, D>(start: C, end: D) -> f32
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+
+        let l = s.l - e.l;
+        let a = s.a - e.a;
+        let b = s.b - e.b;
+
+        (l*l + a*a + b*b).sqrt()
+    }
+}
+
+
+impl fmt::Display for Lab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{:?}", self)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Lab conversions
+////////////////////////////////////////////////////////////////////////////////
+impl From<[f32; 3]> for Lab {
+    fn from(components: [f32; 3]) -> Self {
+        let span = span!(Level::DEBUG, "Lab::from<[f32; 3]>");
+        let _enter = span.enter();
+
+        Lab::new(
+            components[0],
+            components[1],
+            components[2],
+        )
+    }
+}
+
+impl From<Cmyk> for Lab {
+    fn from(cmyk: Cmyk) -> Self {
+        let span = span!(Level::DEBUG, "Lab::from<Cmyk>");
+        let _enter = span.enter();
+
+        Lab::from(Xyz::from(cmyk))
+    }
+}
+
+impl From<Hsl> for Lab {
+    fn from(hsl: Hsl) -> Self {
+        let span = span!(Level::DEBUG, "Lab::from<Hsl>");
+        let _enter = span.enter();
+
+        Lab::from(Xyz::from(hsl))
+    }
+}
+
+impl From<Hsv> for Lab {
+    fn from(hsv: Hsv) -> Self {
+        let span = span!(Level::DEBUG, "Lab::from<Hsv>");
+        let _enter = span.enter();
+
+        Lab::from(Xyz::from(hsv))
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(lch: Lch) -> Self {
+        let span = span!(Level::DEBUG, "Lab::from<Lch>");
+        let _enter = span.enter();
+
+        let (l, c, h) = (lch.lightness(), lch.chroma(), lch.hue());
+        let (sin, cos) = h.to_radians().sin_cos();
+
+        Lab {l, a: c * cos, b: c * sin}
+    }
+}
+
+impl From<LinearRgb> for Lab {
+    fn from(linear_rgb: LinearRgb) -> Self {
+        let span = span!(Level::DEBUG, "Lab::from<LinearRgb>");
+        let _enter = span.enter();
+        
+        Lab::from(Xyz::from(linear_rgb))
+    }
+}
+
+impl From<Oklab> for Lab {
+    fn from(oklab: Oklab) -> Self {
+        let span = span!(Level::DEBUG, "Lab::from<Oklab>");
+        let _enter = span.enter();
+        
+        Lab::from(Xyz::from(oklab))
+    }
+}
+
+impl From<Oklch> for Lab {
+    fn from(oklch: Oklch) -> Self {
+        let span = span!(Level::DEBUG, "Lab::from<Oklch>");
+        let _enter = span.enter();
+        
+        Lab::from(Xyz::from(oklch))
+    }
+}
+
+impl From<Rgb> for Lab {
+    fn from(rgb: Rgb) -> Self {
+        let span = span!(Level::DEBUG, "Lab::from<Rgb>");
+        let _enter = span.enter();
+
+        Lab::from(Xyz::from(rgb))
+    }
+}
+
+impl From<Xyz> for Lab {
+    fn from(xyz: Xyz) -> Self {
+        let span = span!(Level::DEBUG, "Lab::from<Xyz>");
+        let _enter = span.enter();
+
+        // Compresses a white-relative component into the cube-root domain.
+        fn f(t: f32) -> f32 {
+            if t > EPSILON {
+                t.cbrt()
+            } else {
+                (KAPPA * t + 16.0) / 116.0
+            }
+        }
+
+        let fx = f(xyz.x / D65_WHITE[0]);
+        let fy = f(xyz.y / D65_WHITE[1]);
+        let fz = f(xyz.z / D65_WHITE[2]);
+
+        Lab {
+            l: clamped(116.0 * fy - 16.0, 0.0, 100.0),
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
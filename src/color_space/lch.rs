@@ -0,0 +1,530 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Defines a 96-bit CIE LCh(ab) color space.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Cmyk;
+use crate::Hsl;
+use crate::Hsv;
+use crate::HueInterpolation;
+use crate::Lab;
+use crate::LinearRgb;
+use crate::Oklab;
+use crate::Oklch;
+use crate::Rgb;
+use crate::utility::cerp_f32;
+use crate::utility::cerp_hue;
+use crate::utility::clamped;
+use crate::utility::lerp_f32;
+use crate::utility::lerp_hue;
+use crate::utility::normalize_hue;
+use crate::Xyz;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use tracing::Level;
+use tracing::span;
+
+// Standard library imports.
+use std::convert::From;
+use std::fmt;
+use std::f32;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Constants
+////////////////////////////////////////////////////////////////////////////////
+/// Chroma below which a color is considered achromatic and its hue is
+/// undefined.
+const ACHROMATIC_THRESHOLD: f32 = 0.0001;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Lch
+////////////////////////////////////////////////////////////////////////////////
+/// The encoded CIE LCh(ab) color. This is the cylindrical form of [`Lab`].
+///
+/// [`Lab`]: struct.Lab.html
+#[derive(Debug, PartialOrd, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lch {
+    /// The lightness component.
+    pub(in crate) l: f32,
+    /// The chroma component.
+    pub(in crate) c: f32,
+    /// The hue component.
+    pub(in crate) h: f32,
+}
+
+
+impl Lch {
+    /// Constructs a new `Lch` color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Lch::new(54.0, 44.0, 120.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn new(lightness: f32, chroma: f32, hue: f32) -> Self {
+        let mut lch = Lch {l: 0.0, c: 0.0, h: 0.0};
+        lch.set_lightness(lightness);
+        lch.set_chroma(chroma);
+        lch.set_hue(hue);
+        lch
+    }
+
+    /// Returns the lightness component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Lch::new(54.0, 44.0, 120.0);
+    ///
+    /// assert_eq!(color.lightness(), 54.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    /// Returns the chroma component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Lch::new(54.0, 44.0, 120.0);
+    ///
+    /// assert_eq!(color.chroma(), 44.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn chroma(&self) -> f32 {
+        self.c
+    }
+
+    /// Returns the hue component of the color in degrees.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Lch::new(54.0, 44.0, 120.0);
+    ///
+    /// assert_eq!(color.hue(), 120.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    /// Sets the lightness component of the color. The value is clamped between
+    /// 0 and 100.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Lch::new(54.0, 44.0, 120.0);
+    /// color.set_lightness(72.0);
+    ///
+    /// assert_eq!(color.lightness(), 72.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_lightness(&mut self, lightness: f32) {
+        self.l = clamped(lightness, 0.0, 100.0);
+    }
+
+    /// Sets the chroma component of the color. Negative values are clamped to
+    /// 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Lch::new(54.0, 44.0, 120.0);
+    /// color.set_chroma(12.0);
+    ///
+    /// assert_eq!(color.chroma(), 12.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_chroma(&mut self, chroma: f32) {
+        assert!(chroma.is_finite());
+        self.c = if chroma < 0.0 { 0.0 } else { chroma };
+    }
+
+    /// Sets the hue component of the color in degrees.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Lch::new(54.0, 44.0, 120.0);
+    /// color.set_hue(-90.0);
+    ///
+    /// assert_eq!(color.hue(), 270.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_hue(&mut self, hue: f32) {
+        assert!(hue.is_finite());
+        self.h = normalize_hue(hue);
+    }
+
+    /// Returns an array containing the `[L, C, h]` components.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Lch::new(54.0, 44.0, 120.0);
+    ///
+    /// let components = color.components();
+    ///
+    /// assert_eq!(components, [54.0, 44.0, 120.0]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn components(&self) -> [f32; 3] {
+        [self.l, self.c, self.h]
+    }
+
+    /// Performs a component-wise linear interpolation between given colors,
+    /// returning the color located at the ratio given by `amount`, which is
+    /// clamped between 1 and 0. The hue is interpolated around the hue circle
+    /// in the direction given by `hue_interpolation`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ HueInterpolation, Lch };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Lch::new(54.0, 44.0, 340.0);
+    /// let color_b = Lch::new(32.0, 60.0, 40.0);
+    ///
+    /// let lerp_color = Lch::linear_interpolate(
+    ///     color_a, color_b, 0.5, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(lerp_color, Lch::new(43.0, 52.0, 10.0));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn linear_interpolate<C, D>(
+        start: C,
+        end: D,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+        let (sh, eh) = Lch::interpolation_hues(&s, &e);
+        Lch {
+            l: lerp_f32(s.l, e.l, amount),
+            c: lerp_f32(s.c, e.c, amount),
+            h: lerp_hue(sh, eh, amount, hue_interpolation),
+        }
+    }
+
+    /// Performs a component-wise cubic interpolation between given colors,
+    /// returning the color located at the ratio given by `amount`, which is
+    /// clamped between 1 and 0. The interpolation function will be consistent
+    /// with the slopes given by `start_slope` and `end_slope`. The hue is
+    /// interpolated around the hue circle in the direction given by
+    /// `hue_interpolation`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ HueInterpolation, Lch };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Lch::new(54.0, 44.0, 340.0);
+    /// let color_b = Lch::new(32.0, 60.0, 40.0);
+    ///
+    /// let cerp_color = Lch::cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.19, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(cerp_color, Lch::new(51.919193, 45.51331, 345.6749));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+        let (sh, eh) = Lch::interpolation_hues(&s, &e);
+        Lch {
+            l: cerp_f32(s.l, e.l, start_slope, end_slope, amount),
+            c: cerp_f32(s.c, e.c, start_slope, end_slope, amount),
+            h: cerp_hue(sh, eh, start_slope, end_slope, amount,
+                hue_interpolation),
+        }
+    }
+
+    /// Returns the distance between the given colors in `Lch` color space.
+    ///
+    /// This is measured in the equivalent [`Lab`] coordinates, so it is the
+    /// same as the CIE76 color difference.
+    ///
+    /// [`Lab`]: struct.Lab.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Lch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Lch::new(54.0, 44.0, 340.0);
+    /// let color_b = Lch::new(32.0, 60.0, 40.0);
+    ///
+    /// assert_eq!(Lch::distance(color_a, color_b), 58.137775);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn distance<C, D>(start: C, end: D) -> f32
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        Lab::distance(Lab::from(start.into()), Lab::from(end.into()))
+    }
+
+    /// Returns the hues to interpolate between for the given colors. The hue
+    /// of an achromatic color is undefined, so it takes on the hue of the
+    /// other color to avoid introducing a hue shift.
+    fn interpolation_hues(s: &Lch, e: &Lch) -> (f32, f32) {
+        match (s.c < ACHROMATIC_THRESHOLD, e.c < ACHROMATIC_THRESHOLD) {
+            (true, false) => (e.h, e.h),
+            (false, true) => (s.h, s.h),
+            _             => (s.h, e.h),
+        }
+    }
+}
+
+
+impl fmt::Display for Lch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{:?}", self)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Lch conversions
+////////////////////////////////////////////////////////////////////////////////
+impl From<[f32; 3]> for Lch {
+    fn from(components: [f32; 3]) -> Self {
+        let span = span!(Level::DEBUG, "Lch::from<[f32; 3]>");
+        let _enter = span.enter();
+
+        Lch::new(
+            components[0],
+            components[1],
+            components[2],
+        )
+    }
+}
+
+impl From<Cmyk> for Lch {
+    fn from(cmyk: Cmyk) -> Self {
+        let span = span!(Level::DEBUG, "Lch::from<Cmyk>");
+        let _enter = span.enter();
+
+        Lch::from(Lab::from(cmyk))
+    }
+}
+
+impl From<Hsl> for Lch {
+    fn from(hsl: Hsl) -> Self {
+        let span = span!(Level::DEBUG, "Lch::from<Hsl>");
+        let _enter = span.enter();
+
+        Lch::from(Lab::from(hsl))
+    }
+}
+
+impl From<Hsv> for Lch {
+    fn from(hsv: Hsv) -> Self {
+        let span = span!(Level::DEBUG, "Lch::from<Hsv>");
+        let _enter = span.enter();
+
+        Lch::from(Lab::from(hsv))
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(lab: Lab) -> Self {
+        let span = span!(Level::DEBUG, "Lch::from<Lab>");
+        let _enter = span.enter();
+
+        let (l, a, b) = (lab.lightness(), lab.a(), lab.b());
+        let c = (a * a + b * b).sqrt();
+
+        if c < ACHROMATIC_THRESHOLD {
+            // No need to compute hue for grayscale colors.
+            Lch {l, c: 0.0, h: 0.0}
+        } else {
+            Lch {l, c, h: normalize_hue(b.atan2(a).to_degrees())}
+        }
+    }
+}
+
+impl From<LinearRgb> for Lch {
+    fn from(linear_rgb: LinearRgb) -> Self {
+        let span = span!(Level::DEBUG, "Lch::from<LinearRgb>");
+        let _enter = span.enter();
+        
+        Lch::from(Lab::from(linear_rgb))
+    }
+}
+
+impl From<Oklab> for Lch {
+    fn from(oklab: Oklab) -> Self {
+        let span = span!(Level::DEBUG, "Lch::from<Oklab>");
+        let _enter = span.enter();
+        
+        Lch::from(Lab::from(oklab))
+    }
+}
+
+impl From<Oklch> for Lch {
+    fn from(oklch: Oklch) -> Self {
+        let span = span!(Level::DEBUG, "Lch::from<Oklch>");
+        let _enter = span.enter();
+        
+        Lch::from(Lab::from(oklch))
+    }
+}
+
+impl From<Rgb> for Lch {
+    fn from(rgb: Rgb) -> Self {
+        let span = span!(Level::DEBUG, "Lch::from<Rgb>");
+        let _enter = span.enter();
+
+        Lch::from(Lab::from(rgb))
+    }
+}
+
+impl From<Xyz> for Lch {
+    fn from(xyz: Xyz) -> Self {
+        let span = span!(Level::DEBUG, "Lch::from<Xyz>");
+        let _enter = span.enter();
+
+        Lch::from(Lab::from(xyz))
+    }
+}
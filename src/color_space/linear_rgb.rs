@@ -0,0 +1,528 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Defines a 96-bit linear-light RGB color space.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Cmyk;
+use crate::Hsl;
+use crate::Hsv;
+use crate::Lab;
+use crate::Lch;
+use crate::Oklab;
+use crate::Oklch;
+use crate::Rgb;
+use crate::utility::cerp_f32;
+use crate::utility::clamped;
+use crate::utility::lerp_f32;
+use crate::utility::srgb_to_linear;
+use crate::Xyz;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use tracing::Level;
+use tracing::span;
+
+
+// Standard library imports.
+use std::convert::From;
+use std::fmt;
+use std::f32;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// LinearRgb
+////////////////////////////////////////////////////////////////////////////////
+/// The linear-light sRGB color. Unlike [`Rgb`], the components are
+/// proportional to physical light intensity, making it suitable for blending
+/// and averaging.
+///
+/// [`Rgb`]: struct.Rgb.html
+#[derive(Debug, PartialOrd, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearRgb {
+    /// The red component.
+    pub r: f32,
+    /// The green component.
+    pub g: f32,
+    /// The blue component.
+    pub b: f32,
+}
+
+
+impl LinearRgb {
+    /// Constructs a new `LinearRgb` color. The components are clamped between
+    /// 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::LinearRgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = LinearRgb::new(0.24, 0.68, 0.91);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn new(r: f32, g: f32, b: f32) -> Self {
+        let mut linear_rgb = LinearRgb {r: 0.0, g: 0.0, b: 0.0};
+        linear_rgb.set_r(r);
+        linear_rgb.set_g(g);
+        linear_rgb.set_b(b);
+        linear_rgb
+    }
+
+    /// Returns the red component.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::LinearRgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = LinearRgb::new(0.24, 0.68, 0.91);
+    /// 
+    /// assert_eq!(color.r(), 0.24);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn r(&self) -> f32 {
+        self.r
+    }
+
+    /// Returns the green component.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::LinearRgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = LinearRgb::new(0.24, 0.68, 0.91);
+    /// 
+    /// assert_eq!(color.g(), 0.68);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn g(&self) -> f32 {
+        self.g
+    }
+
+    /// Returns the blue component.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::LinearRgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = LinearRgb::new(0.24, 0.68, 0.91);
+    /// 
+    /// assert_eq!(color.b(), 0.91);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+
+    /// Sets the red component as a ratio.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::LinearRgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = LinearRgb::new(0.24, 0.68, 0.91);
+    /// 
+    /// color.set_r(0.55);
+    /// 
+    /// assert_eq!(color.r(), 0.55);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_r(&mut self, r: f32) {
+        self.r = clamped(r, 0.0, 1.0);
+    }
+
+    /// Sets the green component as a ratio.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::LinearRgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = LinearRgb::new(0.24, 0.68, 0.91);
+    /// 
+    /// color.set_g(0.55);
+    /// 
+    /// assert_eq!(color.g(), 0.55);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_g(&mut self, g: f32) {
+        self.g = clamped(g, 0.0, 1.0);
+    }
+
+    /// Sets the blue component as a ratio.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::LinearRgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = LinearRgb::new(0.24, 0.68, 0.91);
+    /// 
+    /// color.set_b(0.55);
+    /// 
+    /// assert_eq!(color.b(), 0.55);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_b(&mut self, b: f32) {
+        self.b = clamped(b, 0.0, 1.0);
+    }
+
+    /// Returns an array containing the `[R, G, B]` components.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::LinearRgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = LinearRgb::new(0.24, 0.68, 0.91);
+    ///
+    /// let components = color.components();
+    ///
+    /// assert_eq!(components, [0.24, 0.68, 0.91]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn components(&self) -> [f32; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    /// Performs a component-wise linear interpolation between given colors,
+    /// returning the color located at the ratio given by `amount`, which is
+    /// clamped between 1 and 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::LinearRgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = LinearRgb::new(0.24, 0.68, 0.91);
+    /// let color_b = LinearRgb::new(0.84, 0.228, 0.455);
+    ///
+    /// let lerp_color = LinearRgb::linear_interpolate(color_a, color_b, 0.19);
+    ///
+    /// assert_eq!(lerp_color, LinearRgb::new(0.35399997, 0.59412, 0.82355));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+        LinearRgb {
+            r: lerp_f32(s.r, e.r, amount),
+            g: lerp_f32(s.g, e.g, amount),
+            b: lerp_f32(s.b, e.b, amount),
+        }
+    }
+
+    /// Performs a component-wise cubic interpolation between given colors,
+    /// returning the color located at the ratio given by `amount`, which is
+    /// clamped between 1 and 0. The interpolation function will be consistent
+    /// with the slopes given by `start_slope` and `end_slope`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::LinearRgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = LinearRgb::new(0.24, 0.68, 0.91);
+    /// let color_b = LinearRgb::new(0.84, 0.228, 0.455);
+    ///
+    /// let lerp_color = LinearRgb::cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.19);
+    ///
+    /// assert_eq!(lerp_color, LinearRgb::new(0.29674917, 0.63724893, 0.8669652));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32) -> Self 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+        LinearRgb::new(
+            cerp_f32(s.r, e.r, start_slope, end_slope, amount),
+            cerp_f32(s.g, e.g, start_slope, end_slope, amount),
+            cerp_f32(s.b, e.b, start_slope, end_slope, amount))
+    }
+
+    /// Returns the distance between the given colors in `LinearRgb` color space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::LinearRgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = LinearRgb::new(0.24, 0.68, 0.91);
+    /// let color_b = LinearRgb::new(0.84, 0.228, 0.455);
+    ///
+    /// assert_eq!(LinearRgb::distance(color_a, color_b), 0.8782534);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn distance<C, D>(start: C, end: D) -> f32 
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+        
+        let r = s.r - e.r;
+        let g = s.g - e.g;
+        let b = s.b - e.b;
+
+        (r*r + g*g + b*b).sqrt()
+    }
+}
+
+
+impl fmt::Display for LinearRgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{:?}", self)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// LinearRgb conversions
+////////////////////////////////////////////////////////////////////////////////
+impl From<[f32; 3]> for LinearRgb {
+    fn from(components: [f32; 3]) -> Self {
+        let span = span!(Level::DEBUG, "LinearRgb::from<[f32; 3]>");
+        let _enter = span.enter();
+        
+        LinearRgb::new(
+            components[0],
+            components[1],
+            components[2],
+        )
+    }
+}
+
+
+impl From<Cmyk> for LinearRgb {
+    fn from(cmyk: Cmyk) -> Self {
+        let span = span!(Level::DEBUG, "LinearRgb::from<Cmyk>");
+        let _enter = span.enter();
+        
+        LinearRgb::from(Rgb::from(cmyk))
+    }
+}
+
+impl From<Hsl> for LinearRgb {
+    fn from(hsl: Hsl) -> Self {
+        let span = span!(Level::DEBUG, "LinearRgb::from<Hsl>");
+        let _enter = span.enter();
+        
+        LinearRgb::from(Rgb::from(hsl))
+    }
+}
+
+impl From<Hsv> for LinearRgb {
+    fn from(hsv: Hsv) -> Self {
+        let span = span!(Level::DEBUG, "LinearRgb::from<Hsv>");
+        let _enter = span.enter();
+        
+        LinearRgb::from(Rgb::from(hsv))
+    }
+}
+
+impl From<Lab> for LinearRgb {
+    fn from(lab: Lab) -> Self {
+        let span = span!(Level::DEBUG, "LinearRgb::from<Lab>");
+        let _enter = span.enter();
+        
+        LinearRgb::from(Xyz::from(lab))
+    }
+}
+
+impl From<Lch> for LinearRgb {
+    fn from(lch: Lch) -> Self {
+        let span = span!(Level::DEBUG, "LinearRgb::from<Lch>");
+        let _enter = span.enter();
+        
+        LinearRgb::from(Xyz::from(lch))
+    }
+}
+
+impl From<Oklab> for LinearRgb {
+    fn from(oklab: Oklab) -> Self {
+        let span = span!(Level::DEBUG, "LinearRgb::from<Oklab>");
+        let _enter = span.enter();
+
+        let [r, g, b] = oklab_to_linear_srgb(oklab);
+        LinearRgb::new(r, g, b)
+    }
+}
+
+impl From<Oklch> for LinearRgb {
+    fn from(oklch: Oklch) -> Self {
+        let span = span!(Level::DEBUG, "LinearRgb::from<Oklch>");
+        let _enter = span.enter();
+        
+        LinearRgb::from(Oklab::from(oklch))
+    }
+}
+
+impl From<Rgb> for LinearRgb {
+    fn from(rgb: Rgb) -> Self {
+        let span = span!(Level::DEBUG, "LinearRgb::from<Rgb>");
+        let _enter = span.enter();
+        
+        let [r, g, b] = rgb.ratios();
+
+        LinearRgb {
+            r: srgb_to_linear(r),
+            g: srgb_to_linear(g),
+            b: srgb_to_linear(b),
+        }
+    }
+}
+
+impl From<Xyz> for LinearRgb {
+    fn from(xyz: Xyz) -> Self {
+        let span = span!(Level::DEBUG, "LinearRgb::from<Xyz>");
+        let _enter = span.enter();
+        
+        let (x, y, z) = (xyz.x(), xyz.y(), xyz.z()); 
+
+        LinearRgb::new(
+            x *  3.2404542 + y * -1.5371385 + z * -0.4985314,
+            x * -0.969266  + y *  1.8760108 + z *  0.041556,
+            x *  0.0556434 + y * -0.2040259 + z *  1.0572252)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// oklab_to_linear_srgb
+////////////////////////////////////////////////////////////////////////////////
+/// Converts an `Oklab` color into linear sRGB component ratios without
+/// clamping them, so that out-of-gamut colors can be detected.
+pub(in crate) fn oklab_to_linear_srgb(oklab: Oklab) -> [f32; 3] {
+    let (l, a, b) = (oklab.lightness(), oklab.a(), oklab.b());
+
+    // Convert to cone responses.
+    let li = l + a *  0.39633778 + b *  0.21580376;
+    let mi = l + a * -0.105561346 + b * -0.06385417;
+    let si = l + a * -0.08948418 + b * -1.2914855;
+    let (li, mi, si) = (li * li * li, mi * mi * mi, si * si * si);
+
+    // Convert cone responses to linear sRGB.
+    [
+        li *  4.0767417    + mi * -3.3077116 + si *  0.23096993,
+        li * -1.268438     + mi *  2.6097574 + si * -0.34131938,
+        li * -0.0041960863 + mi * -0.7034186 + si *  1.7076147,
+    ]
+}
@@ -0,0 +1,503 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Defines a 96-bit Oklab color space.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Cmyk;
+use crate::Hsl;
+use crate::Hsv;
+use crate::Lab;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::Oklch;
+use crate::Rgb;
+use crate::utility::cerp_f32;
+use crate::utility::clamped;
+use crate::utility::lerp_f32;
+use crate::Xyz;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use tracing::Level;
+use tracing::span;
+
+// Standard library imports.
+use std::convert::From;
+use std::fmt;
+use std::f32;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Oklab
+////////////////////////////////////////////////////////////////////////////////
+/// The encoded Oklab color.
+#[derive(Debug, PartialOrd, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Oklab {
+    /// The lightness component.
+    pub(in crate) l: f32,
+    /// The green-red component.
+    pub(in crate) a: f32,
+    /// The blue-yellow component.
+    pub(in crate) b: f32,
+}
+
+
+impl Oklab {
+    /// Constructs a new `Oklab` color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Oklab::new(0.54, -0.06, 0.11);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn new(lightness: f32, a: f32, b: f32) -> Self {
+        let mut lab = Oklab {l: 0.0, a: 0.0, b: 0.0};
+        lab.set_lightness(lightness);
+        lab.set_a(a);
+        lab.set_b(b);
+        lab
+    }
+
+    /// Returns the lightness component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Oklab::new(0.54, -0.06, 0.11);
+    ///
+    /// assert_eq!(color.lightness(), 0.54);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    /// Returns the a (green-red) component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Oklab::new(0.54, -0.06, 0.11);
+    ///
+    /// assert_eq!(color.a(), -0.06);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+
+    /// Returns the b (blue-yellow) component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Oklab::new(0.54, -0.06, 0.11);
+    ///
+    /// assert_eq!(color.b(), 0.11);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+
+    /// Sets the lightness component of the color. The value is clamped between
+    /// 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Oklab::new(0.54, -0.06, 0.11);
+    /// color.set_lightness(0.72);
+    ///
+    /// assert_eq!(color.lightness(), 0.72);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_lightness(&mut self, lightness: f32) {
+        self.l = clamped(lightness, 0.0, 1.0);
+    }
+
+    /// Sets the a (green-red) component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Oklab::new(0.54, -0.06, 0.11);
+    /// color.set_a(0.12);
+    ///
+    /// assert_eq!(color.a(), 0.12);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_a(&mut self, a: f32) {
+        assert!(a.is_finite());
+        self.a = a;
+    }
+
+    /// Sets the b (blue-yellow) component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Oklab::new(0.54, -0.06, 0.11);
+    /// color.set_b(-0.06);
+    ///
+    /// assert_eq!(color.b(), -0.06);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_b(&mut self, b: f32) {
+        assert!(b.is_finite());
+        self.b = b;
+    }
+
+    /// Returns an array containing the `[L, a, b]` components.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Oklab::new(0.54, -0.06, 0.11);
+    ///
+    /// let components = color.components();
+    ///
+    /// assert_eq!(components, [0.54, -0.06, 0.11]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn components(&self) -> [f32; 3] {
+        [self.l, self.a, self.b]
+    }
+
+    /// Performs a component-wise linear interpolation between given colors,
+    /// returning the color located at the ratio given by `amount`, which is
+    /// clamped between 1 and 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Oklab::new(0.54, -0.06, 0.11);
+    /// let color_b = Oklab::new(0.32, 0.18, -0.04);
+    ///
+    /// let lerp_color = Oklab::linear_interpolate(color_a, color_b, 0.65);
+    ///
+    /// assert_eq!(lerp_color, Oklab::new(0.397, 0.096, 0.012500007));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn linear_interpolate<C, D>(start: C, end: D, amount: f32) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+        Oklab {
+            l: lerp_f32(s.l, e.l, amount),
+            a: lerp_f32(s.a, e.a, amount),
+            b: lerp_f32(s.b, e.b, amount),
+        }
+    }
+
+    /// Performs a component-wise cubic interpolation between given colors,
+    /// returning the color located at the ratio given by `amount`, which is
+    /// clamped between 1 and 0. The interpolation function will be consistent
+    /// with the slopes given by `start_slope` and `end_slope`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Oklab::new(0.54, -0.06, 0.11);
+    /// let color_b = Oklab::new(0.32, 0.18, -0.04);
+    ///
+    /// let cerp_color = Oklab::cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.19);
+    ///
+    /// assert_eq!(cerp_color, Oklab::new(0.519192, -0.03730032, 0.095812686));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+        Oklab {
+            l: cerp_f32(s.l, e.l, start_slope, end_slope, amount),
+            a: cerp_f32(s.a, e.a, start_slope, end_slope, amount),
+            b: cerp_f32(s.b, e.b, start_slope, end_slope, amount),
+        }
+    }
+
+    /// Returns the distance between the given colors in `Oklab` color space.
+    ///
+    /// This is the deltaEOK color difference, where a distance of about 0.02
+    /// corresponds to a just noticeable difference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklab;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Oklab::new(0.54, -0.06, 0.11);
+    /// let color_b = Oklab::new(0.32, 0.18, -0.04);
+    ///
+    /// assert_eq!(Oklab::distance(color_a, color_b), 0.35846898);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn distance<C, D>(start: C, end: D) -> f32
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+
+        let l = s.l - e.l;
+        let a = s.a - e.a;
+        let b = s.b - e.b;
+
+        (l*l + a*a + b*b).sqrt()
+    }
+}
+
+
+impl fmt::Display for Oklab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{:?}", self)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Oklab conversions
+////////////////////////////////////////////////////////////////////////////////
+impl From<[f32; 3]> for Oklab {
+    fn from(components: [f32; 3]) -> Self {
+        let span = span!(Level::DEBUG, "Oklab::from<[f32; 3]>");
+        let _enter = span.enter();
+
+        Oklab::new(
+            components[0],
+            components[1],
+            components[2],
+        )
+    }
+}
+
+impl From<Cmyk> for Oklab {
+    fn from(cmyk: Cmyk) -> Self {
+        let span = span!(Level::DEBUG, "Oklab::from<Cmyk>");
+        let _enter = span.enter();
+
+        Oklab::from(Rgb::from(cmyk))
+    }
+}
+
+impl From<Hsl> for Oklab {
+    fn from(hsl: Hsl) -> Self {
+        let span = span!(Level::DEBUG, "Oklab::from<Hsl>");
+        let _enter = span.enter();
+
+        Oklab::from(Rgb::from(hsl))
+    }
+}
+
+impl From<Hsv> for Oklab {
+    fn from(hsv: Hsv) -> Self {
+        let span = span!(Level::DEBUG, "Oklab::from<Hsv>");
+        let _enter = span.enter();
+
+        Oklab::from(Rgb::from(hsv))
+    }
+}
+
+impl From<Lab> for Oklab {
+    fn from(lab: Lab) -> Self {
+        let span = span!(Level::DEBUG, "Oklab::from<Lab>");
+        let _enter = span.enter();
+        
+        Oklab::from(LinearRgb::from(lab))
+    }
+}
+
+impl From<Lch> for Oklab {
+    fn from(lch: Lch) -> Self {
+        let span = span!(Level::DEBUG, "Oklab::from<Lch>");
+        let _enter = span.enter();
+        
+        Oklab::from(LinearRgb::from(lch))
+    }
+}
+
+impl From<LinearRgb> for Oklab {
+    fn from(linear_rgb: LinearRgb) -> Self {
+        let span = span!(Level::DEBUG, "Oklab::from<LinearRgb>");
+        let _enter = span.enter();
+
+        let (r, g, b) = (linear_rgb.r(), linear_rgb.g(), linear_rgb.b());
+
+        // Convert linear sRGB to cone responses.
+        let l = (r * 0.41222147 + g * 0.53633254 + b * 0.051445993).cbrt();
+        let m = (r * 0.2119035  + g * 0.6806995  + b * 0.10739696).cbrt();
+        let s = (r * 0.08830246 + g * 0.28171884 + b * 0.6299787).cbrt();
+
+        Oklab {
+            l: l * 0.21045426 + m *  0.7936178  + s * -0.004072047,
+            a: l * 1.9779985  + m * -2.4285922  + s *  0.4505937,
+            b: l * 0.025904037 + m * 0.78277177 + s * -0.80867577,
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(oklch: Oklch) -> Self {
+        let span = span!(Level::DEBUG, "Oklab::from<Oklch>");
+        let _enter = span.enter();
+
+        let (l, c, h) = (oklch.lightness(), oklch.chroma(), oklch.hue());
+        let (sin, cos) = h.to_radians().sin_cos();
+
+        Oklab {l, a: c * cos, b: c * sin}
+    }
+}
+
+impl From<Rgb> for Oklab {
+    fn from(rgb: Rgb) -> Self {
+        let span = span!(Level::DEBUG, "Oklab::from<Rgb>");
+        let _enter = span.enter();
+        
+        Oklab::from(LinearRgb::from(rgb))
+    }
+}
+
+impl From<Xyz> for Oklab {
+    fn from(xyz: Xyz) -> Self {
+        let span = span!(Level::DEBUG, "Oklab::from<Xyz>");
+        let _enter = span.enter();
+        
+        Oklab::from(LinearRgb::from(xyz))
+    }
+}
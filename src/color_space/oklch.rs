@@ -0,0 +1,596 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Defines a 96-bit Oklch color space.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Cmyk;
+use crate::color_space::linear_rgb::oklab_to_linear_srgb;
+use crate::Hsl;
+use crate::Hsv;
+use crate::HueInterpolation;
+use crate::Lab;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::Oklab;
+use crate::Rgb;
+use crate::utility::cerp_f32;
+use crate::utility::cerp_hue;
+use crate::utility::clamped;
+use crate::utility::lerp_f32;
+use crate::utility::lerp_hue;
+use crate::utility::normalize_hue;
+use crate::Xyz;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use tracing::Level;
+use tracing::span;
+
+// Standard library imports.
+use std::convert::From;
+use std::fmt;
+use std::f32;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Constants
+////////////////////////////////////////////////////////////////////////////////
+/// Chroma below which a color is considered achromatic and its hue is
+/// undefined.
+const ACHROMATIC_THRESHOLD: f32 = 0.0001;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Oklch
+////////////////////////////////////////////////////////////////////////////////
+/// The encoded Oklch color. This is the cylindrical form of [`Oklab`].
+///
+/// [`Oklab`]: struct.Oklab.html
+#[derive(Debug, PartialOrd, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Oklch {
+    /// The lightness component.
+    pub(in crate) l: f32,
+    /// The chroma component.
+    pub(in crate) c: f32,
+    /// The hue component.
+    pub(in crate) h: f32,
+}
+
+
+impl Oklch {
+    /// Constructs a new `Oklch` color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Oklch::new(0.54, 0.12, 120.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn new(lightness: f32, chroma: f32, hue: f32) -> Self {
+        let mut lch = Oklch {l: 0.0, c: 0.0, h: 0.0};
+        lch.set_lightness(lightness);
+        lch.set_chroma(chroma);
+        lch.set_hue(hue);
+        lch
+    }
+
+    /// Returns the lightness component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Oklch::new(0.54, 0.12, 120.0);
+    ///
+    /// assert_eq!(color.lightness(), 0.54);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    /// Returns the chroma component of the color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Oklch::new(0.54, 0.12, 120.0);
+    ///
+    /// assert_eq!(color.chroma(), 0.12);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn chroma(&self) -> f32 {
+        self.c
+    }
+
+    /// Returns the hue component of the color in degrees.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Oklch::new(0.54, 0.12, 120.0);
+    ///
+    /// assert_eq!(color.hue(), 120.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    /// Sets the lightness component of the color. The value is clamped between
+    /// 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Oklch::new(0.54, 0.12, 120.0);
+    /// color.set_lightness(0.72);
+    ///
+    /// assert_eq!(color.lightness(), 0.72);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_lightness(&mut self, lightness: f32) {
+        self.l = clamped(lightness, 0.0, 1.0);
+    }
+
+    /// Sets the chroma component of the color. Negative values are clamped to
+    /// 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Oklch::new(0.54, 0.12, 120.0);
+    /// color.set_chroma(0.05);
+    ///
+    /// assert_eq!(color.chroma(), 0.05);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_chroma(&mut self, chroma: f32) {
+        assert!(chroma.is_finite());
+        self.c = if chroma < 0.0 { 0.0 } else { chroma };
+    }
+
+    /// Sets the hue component of the color in degrees.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut color = Oklch::new(0.54, 0.12, 120.0);
+    /// color.set_hue(-90.0);
+    ///
+    /// assert_eq!(color.hue(), 270.0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn set_hue(&mut self, hue: f32) {
+        assert!(hue.is_finite());
+        self.h = normalize_hue(hue);
+    }
+
+    /// Returns an array containing the `[L, C, h]` components.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Oklch::new(0.54, 0.12, 120.0);
+    ///
+    /// let components = color.components();
+    ///
+    /// assert_eq!(components, [0.54, 0.12, 120.0]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn components(&self) -> [f32; 3] {
+        [self.l, self.c, self.h]
+    }
+
+    /// Returns true if the color can be represented in the sRGB color space
+    /// without clipping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert!(Oklch::new(0.5, 0.1, 250.0).is_in_srgb_gamut());
+    /// assert!(!Oklch::new(0.95, 0.3, 250.0).is_in_srgb_gamut());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn is_in_srgb_gamut(&self) -> bool {
+        const EPSILON: f32 = 0.0001;
+        oklab_to_linear_srgb(Oklab::from(*self)).iter()
+            .all(|&v| (-EPSILON..=1.0 + EPSILON).contains(&v))
+    }
+
+    /// Returns the color with its chroma reduced until it can be represented
+    /// in the sRGB color space, preserving its lightness and hue.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color = Oklch::new(0.95, 0.3, 250.0).srgb_gamut_mapped();
+    ///
+    /// assert!(color.is_in_srgb_gamut());
+    /// assert_eq!(color.lightness(), 0.95);
+    /// assert_eq!(color.chroma(), 0.02477274);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn srgb_gamut_mapped(&self) -> Self {
+        if self.is_in_srgb_gamut() {
+            return *self;
+        }
+        let (mut low, mut high) = (0.0, self.c);
+        for _ in 0..20 {
+            let mid = (low + high) / 2.0;
+            if (Oklch {c: mid, ..*self}).is_in_srgb_gamut() {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Oklch {c: low, ..*self}
+    }
+
+    /// Performs a component-wise linear interpolation between given colors,
+    /// returning the color located at the ratio given by `amount`, which is
+    /// clamped between 1 and 0. The hue is interpolated around the hue circle
+    /// in the direction given by `hue_interpolation`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ HueInterpolation, Oklch };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Oklch::new(0.54, 0.12, 340.0);
+    /// let color_b = Oklch::new(0.32, 0.18, 40.0);
+    ///
+    /// let lerp_color = Oklch::linear_interpolate(
+    ///     color_a, color_b, 0.5, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(lerp_color, Oklch::new(0.43, 0.15, 10.0));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn linear_interpolate<C, D>(
+        start: C,
+        end: D,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+        let (sh, eh) = Oklch::interpolation_hues(&s, &e);
+        Oklch {
+            l: lerp_f32(s.l, e.l, amount),
+            c: lerp_f32(s.c, e.c, amount),
+            h: lerp_hue(sh, eh, amount, hue_interpolation),
+        }
+    }
+
+    /// Performs a component-wise cubic interpolation between given colors,
+    /// returning the color located at the ratio given by `amount`, which is
+    /// clamped between 1 and 0. The interpolation function will be consistent
+    /// with the slopes given by `start_slope` and `end_slope`. The hue is
+    /// interpolated around the hue circle in the direction given by
+    /// `hue_interpolation`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ HueInterpolation, Oklch };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Oklch::new(0.54, 0.12, 340.0);
+    /// let color_b = Oklch::new(0.32, 0.18, 40.0);
+    ///
+    /// let cerp_color = Oklch::cubic_interpolate(
+    ///     color_a, color_b, 0.0, 0.0, 0.19, HueInterpolation::Shorter);
+    ///
+    /// assert_eq!(cerp_color, Oklch::new(0.519192, 0.12567492, 345.6749));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn cubic_interpolate<C, D>(
+        start: C,
+        end: D,
+        start_slope: f32,
+        end_slope: f32,
+        amount: f32,
+        hue_interpolation: HueInterpolation) -> Self
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        let s = start.into();
+        let e = end.into();
+        let (sh, eh) = Oklch::interpolation_hues(&s, &e);
+        Oklch {
+            l: cerp_f32(s.l, e.l, start_slope, end_slope, amount),
+            c: cerp_f32(s.c, e.c, start_slope, end_slope, amount),
+            h: cerp_hue(sh, eh, start_slope, end_slope, amount,
+                hue_interpolation),
+        }
+    }
+
+    /// Returns the distance between the given colors in `Oklch` color space.
+    ///
+    /// This is measured in the equivalent [`Oklab`] coordinates, so it is the
+    /// same as the deltaEOK color difference.
+    ///
+    /// [`Oklab`]: struct.Oklab.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Oklch;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let color_a = Oklch::new(0.54, 0.12, 340.0);
+    /// let color_b = Oklch::new(0.32, 0.18, 40.0);
+    ///
+    /// assert_eq!(Oklch::distance(color_a, color_b), 0.27129325);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn distance<C, D>(start: C, end: D) -> f32
+        where
+            C: Into<Self> + Sized,
+            D: Into<Self> + Sized,
+    {
+        Oklab::distance(Oklab::from(start.into()), Oklab::from(end.into()))
+    }
+
+    /// Returns the hues to interpolate between for the given colors. The hue
+    /// of an achromatic color is undefined, so it takes on the hue of the
+    /// other color to avoid introducing a hue shift.
+    fn interpolation_hues(s: &Oklch, e: &Oklch) -> (f32, f32) {
+        match (s.c < ACHROMATIC_THRESHOLD, e.c < ACHROMATIC_THRESHOLD) {
+            (true, false) => (e.h, e.h),
+            (false, true) => (s.h, s.h),
+            _             => (s.h, e.h),
+        }
+    }
+}
+
+
+impl fmt::Display for Oklch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{:?}", self)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Oklch conversions
+////////////////////////////////////////////////////////////////////////////////
+impl From<[f32; 3]> for Oklch {
+    fn from(components: [f32; 3]) -> Self {
+        let span = span!(Level::DEBUG, "Oklch::from<[f32; 3]>");
+        let _enter = span.enter();
+
+        Oklch::new(
+            components[0],
+            components[1],
+            components[2],
+        )
+    }
+}
+
+impl From<Cmyk> for Oklch {
+    fn from(cmyk: Cmyk) -> Self {
+        let span = span!(Level::DEBUG, "Oklch::from<Cmyk>");
+        let _enter = span.enter();
+
+        Oklch::from(Oklab::from(cmyk))
+    }
+}
+
+impl From<Hsl> for Oklch {
+    fn from(hsl: Hsl) -> Self {
+        let span = span!(Level::DEBUG, "Oklch::from<Hsl>");
+        let _enter = span.enter();
+
+        Oklch::from(Oklab::from(hsl))
+    }
+}
+
+impl From<Hsv> for Oklch {
+    fn from(hsv: Hsv) -> Self {
+        let span = span!(Level::DEBUG, "Oklch::from<Hsv>");
+        let _enter = span.enter();
+
+        Oklch::from(Oklab::from(hsv))
+    }
+}
+
+impl From<Lab> for Oklch {
+    fn from(lab: Lab) -> Self {
+        let span = span!(Level::DEBUG, "Oklch::from<Lab>");
+        let _enter = span.enter();
+        
+        Oklch::from(Oklab::from(lab))
+    }
+}
+
+impl From<Lch> for Oklch {
+    fn from(lch: Lch) -> Self {
+        let span = span!(Level::DEBUG, "Oklch::from<Lch>");
+        let _enter = span.enter();
+        
+        Oklch::from(Oklab::from(lch))
+    }
+}
+
+impl From<LinearRgb> for Oklch {
+    fn from(linear_rgb: LinearRgb) -> Self {
+        let span = span!(Level::DEBUG, "Oklch::from<LinearRgb>");
+        let _enter = span.enter();
+        
+        Oklch::from(Oklab::from(linear_rgb))
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(oklab: Oklab) -> Self {
+        let span = span!(Level::DEBUG, "Oklch::from<Oklab>");
+        let _enter = span.enter();
+
+        let (l, a, b) = (oklab.lightness(), oklab.a(), oklab.b());
+        let c = (a * a + b * b).sqrt();
+
+        if c < ACHROMATIC_THRESHOLD {
+            // No need to compute hue for grayscale colors.
+            Oklch {l, c: 0.0, h: 0.0}
+        } else {
+            Oklch {l, c, h: normalize_hue(b.atan2(a).to_degrees())}
+        }
+    }
+}
+
+impl From<Rgb> for Oklch {
+    fn from(rgb: Rgb) -> Self {
+        let span = span!(Level::DEBUG, "Oklch::from<Rgb>");
+        let _enter = span.enter();
+
+        Oklch::from(Oklab::from(rgb))
+    }
+}
+
+impl From<Xyz> for Oklch {
+    fn from(xyz: Xyz) -> Self {
+        let span = span!(Level::DEBUG, "Oklch::from<Xyz>");
+        let _enter = span.enter();
+
+        Oklch::from(Oklab::from(xyz))
+    }
+}
@@ -15,10 +15,18 @@
 use crate::Cmyk;
 use crate::Hsl;
 use crate::Hsv;
+use crate::Lab;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::Oklab;
+use crate::Oklch;
+use crate::ParseColorError;
+use crate::ParseColorErrorReason;
 use crate::utility::cerp_u8;
 use crate::utility::clamped;
 use crate::utility::distance;
 use crate::utility::lerp_u8;
+use crate::utility::linear_to_srgb;
 use crate::Xyz;
 
 // External library imports.
@@ -38,18 +46,6 @@ use std::f32;
 use std::u8;
 
 
-////////////////////////////////////////////////////////////////////////////////
-// HexCodeParseError
-////////////////////////////////////////////////////////////////////////////////
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-/// An error which can be returned while parsing an RGB hex code.
-/// 
-/// This error is returned by [`Rgb::from_hex_code`] if the parse fails.
-///
-/// [`Rgb::from_hex_code`]: struct.Rgb.html#method.from_hex_code
-pub struct RgbHexCodeParseError;
-
-
 ////////////////////////////////////////////////////////////////////////////////
 // Rgb
 ////////////////////////////////////////////////////////////////////////////////
@@ -91,18 +87,30 @@ impl Rgb {
 
     /// Constructs a new `Rgb` color by parsing a hex code.
     ///
-    /// Both three and six digit variations are acceptable, and the longest will
-    /// be used.
+    /// Both three and six digit variations are acceptable. Four and eight
+    /// digit codes are valid CSS, but their last digits are an alpha
+    /// component, which `Rgb` has no way to store. Rather than silently
+    /// discarding it, those codes are rejected with
+    /// [`ParseColorErrorReason::AlphaNotSupported`]; use
+    /// [`Rgba::from_hex_code`] or [`Color::from_hex_code`] to parse them.
+    ///
+    /// [`ParseColorErrorReason::AlphaNotSupported`]: enum.ParseColorErrorReason.html#variant.AlphaNotSupported
+    /// [`Rgba::from_hex_code`]: struct.Alpha.html#method.from_hex_code
+    /// [`Color::from_hex_code`]: struct.Color.html#method.from_hex_code
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use color::Rgb;
+    /// # use color::{ ParseColorErrorReason, Rgb };
     /// # fn example() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let color = Rgb::from_hex_code("#a1b2c3");
-    /// let color_short = Rgb::from_hex_code("#abc");
+    /// let color = Rgb::from_hex_code("#a1b2c3")?;
+    /// let color_short = Rgb::from_hex_code("#abc")?;
+    ///
+    /// assert_eq!(color_short, Rgb::new(0xAA, 0xBB, 0xCC));
+    /// assert_eq!(Rgb::from_hex_code("#abcd").unwrap_err().reason(),
+    ///     ParseColorErrorReason::AlphaNotSupported);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -111,44 +119,54 @@ impl Rgb {
     /// #     example().unwrap();
     /// # }
     /// ```
-    pub fn from_hex_code(hex: &str) -> Result<Rgb, RgbHexCodeParseError> {
-        if !hex.starts_with("#") || hex.len() < 4 {
-            return Err(RgbHexCodeParseError);
+    pub fn from_hex_code(hex: &str) -> Result<Rgb, ParseColorError> {
+        match Rgb::from_hex_code_with_alpha(hex)? {
+            (rgb, None) => Ok(rgb),
+            (_, Some(_)) => Err(ParseColorError::new(
+                0,
+                hex,
+                ParseColorErrorReason::AlphaNotSupported)),
         }
-
-        Rgb::from_hex_code_6(&hex[1..])
-            .or_else(|_| Rgb::from_hex_code_3(&hex[1..]))
     }
 
-    fn from_hex_code_6(hex: &str) -> Result<Rgb, RgbHexCodeParseError> {
-        if hex.len() != 6 {
-            return Err(RgbHexCodeParseError);
+    /// Parses a three, four, six, or eight digit hex code, returning the color
+    /// and the alpha component, if one was given.
+    pub(in crate) fn from_hex_code_with_alpha(hex: &str)
+        -> Result<(Rgb, Option<u8>), ParseColorError>
+    {
+        if !hex.starts_with('#') {
+            return Err(ParseColorError::new(
+                0,
+                hex,
+                ParseColorErrorReason::MissingHash));
         }
-
-        match u32::from_str_radix(&hex[0..6], 16) {
-            Ok(v) => Ok(Rgb::from(v)),
-            Err(_) => Err(RgbHexCodeParseError),
+        let digits = &hex[1..];
+        if let Some((i, c)) = digits.char_indices()
+            .find(|(_, c)| !c.is_ascii_hexdigit())
+        {
+            return Err(ParseColorError::new(
+                i + 1,
+                &digits[i..i + c.len_utf8()],
+                ParseColorErrorReason::InvalidDigit));
         }
-    }
-
-    fn from_hex_code_3(hex: &str) -> Result<Rgb, RgbHexCodeParseError> {
-        if hex.len() != 3 {
-            return Err(RgbHexCodeParseError);
+        let wrong_length = ParseColorError::new(
+            0,
+            hex,
+            ParseColorErrorReason::WrongLength);
+        if digits.len() > 8 {
+            return Err(wrong_length);
         }
-
-        match u32::from_str_radix(&hex[0..3], 16) {
-            Ok(v) => {
-                // Expand three digits into six.
-                let mut expanded = 0;
-                expanded |= v & 0x00F;
-                expanded |= (v & 0x00F) << 4;
-                expanded |= (v & 0x0F0) << 4;
-                expanded |= (v & 0x0F0) << 8;
-                expanded |= (v & 0xF00) << 8;
-                expanded |= (v & 0xF00) << 12;
-                Ok(Rgb::from(expanded))
-            }
-            Err(_) => Err(RgbHexCodeParseError),
+        let v = u32::from_str_radix(digits, 16)
+            .map_err(|_| wrong_length.clone())?;
+
+        match digits.len() {
+            3 => Ok((Rgb::from(expand_hex_digits(v)), None)),
+            4 => Ok((
+                Rgb::from(expand_hex_digits(v >> 4)),
+                Some((expand_hex_digits(v & 0xF) & 0xFF) as u8))),
+            6 => Ok((Rgb::from(v), None)),
+            8 => Ok((Rgb::from(v >> 8), Some((v & 0xFF) as u8))),
+            _ => Err(wrong_length),
         }
     }
 
@@ -530,6 +548,17 @@ impl fmt::LowerHex for Rgb {
 }
 
 
+/// Expands each of the hex digits in `v` into two identical digits.
+fn expand_hex_digits(v: u32) -> u32 {
+    let mut expanded = 0;
+    for i in 0..3 {
+        let digit = (v >> (i * 4)) & 0xF;
+        expanded |= (digit | digit << 4) << (i * 8);
+    }
+    expanded
+}
+
+
 ////////////////////////////////////////////////////////////////////////////////
 // Rgb conversions
 ////////////////////////////////////////////////////////////////////////////////
@@ -721,28 +750,69 @@ impl From<Hsv> for Rgb {
     }
 }
 
-impl From<Xyz> for Rgb {
-    fn from(xyz: Xyz) -> Self {
-        let span = span!(Level::DEBUG, "Rgb::from<Xyz>");
+impl From<Lab> for Rgb {
+    fn from(lab: Lab) -> Self {
+        let span = span!(Level::DEBUG, "Rgb::from<Lab>");
         let _enter = span.enter();
-        
-        let (x, y, z) = (xyz.x(), xyz.y(), xyz.z()); 
 
-        event!(Level::TRACE, "Xyz {{ x={}, y={}, z={} }}", x, y, z);
+        Rgb::from(Xyz::from(lab))
+    }
+}
 
-        let ri = x *  3.2404542 + y * -1.5371385 + z * -0.4985314;
-        let gi = x * -0.9692660 + y *  1.8760108 + z *  0.0415560;
-        let bi = x *  0.0556434 + y * -0.2040259 + z *  1.0572252;
+impl From<Lch> for Rgb {
+    fn from(lch: Lch) -> Self {
+        let span = span!(Level::DEBUG, "Rgb::from<Lch>");
+        let _enter = span.enter();
 
-        event!(Level::TRACE, "ri={}, gi={}, bi={}", ri, gi, bi);
 
+        Rgb::from(Lab::from(lch))
+    }
+}
+
+impl From<LinearRgb> for Rgb {
+    fn from(linear_rgb: LinearRgb) -> Self {
+        let span = span!(Level::DEBUG, "Rgb::from<LinearRgb>");
+        let _enter = span.enter();
+
+        let (r, g, b) = (linear_rgb.r(), linear_rgb.g(), linear_rgb.b());
+
+        event!(Level::TRACE, "LinearRgb {{ r={}, g={}, b={} }}", r, g, b);
+
+        // Re-encode with the sRGB transfer function.
         let rgb = Rgb {
-            r: (ri * u8::MAX as f32) as u8,
-            g: (gi * u8::MAX as f32) as u8,
-            b: (bi * u8::MAX as f32) as u8,
+            r: (u8::MAX as f32 * clamped(linear_to_srgb(r), 0.0, 1.0) + 0.5) as u8,
+            g: (u8::MAX as f32 * clamped(linear_to_srgb(g), 0.0, 1.0) + 0.5) as u8,
+            b: (u8::MAX as f32 * clamped(linear_to_srgb(b), 0.0, 1.0) + 0.5) as u8,
         };
 
         event!(Level::TRACE, "Rgb={:?}", rgb);
         rgb
     }
 }
+
+impl From<Oklab> for Rgb {
+    fn from(oklab: Oklab) -> Self {
+        let span = span!(Level::DEBUG, "Rgb::from<Oklab>");
+        let _enter = span.enter();
+        
+        Rgb::from(LinearRgb::from(oklab))
+    }
+}
+
+impl From<Oklch> for Rgb {
+    fn from(oklch: Oklch) -> Self {
+        let span = span!(Level::DEBUG, "Rgb::from<Oklch>");
+        let _enter = span.enter();
+
+        Rgb::from(Oklab::from(oklch))
+    }
+}
+
+impl From<Xyz> for Rgb {
+    fn from(xyz: Xyz) -> Self {
+        let span = span!(Level::DEBUG, "Rgb::from<Xyz>");
+        let _enter = span.enter();
+        
+        Rgb::from(LinearRgb::from(xyz))
+    }
+}
@@ -13,11 +13,17 @@
 
 // Local imports.
 use crate::Cmyk;
+use crate::color_space::lab::EPSILON;
+use crate::color_space::lab::KAPPA;
 use crate::Hsl;
 use crate::Hsv;
+use crate::Lab;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::Oklab;
+use crate::Oklch;
 use crate::Rgb;
 use crate::utility::cerp_f32;
-use crate::utility::clamped;
 use crate::utility::lerp_f32;
 
 // External library imports.
@@ -35,6 +41,12 @@ use std::fmt;
 use std::f32;
 
 
+////////////////////////////////////////////////////////////////////////////////
+// Constants
+////////////////////////////////////////////////////////////////////////////////
+/// The CIE standard illuminant D65 reference white, normalized to `Y = 1`.
+pub(in crate) const D65_WHITE: [f32; 3] = [0.95047, 1.0, 1.08883];
+
 
 ////////////////////////////////////////////////////////////////////////////////
 // Xyz
@@ -53,7 +65,9 @@ pub struct Xyz {
 
 
 impl Xyz {
-    /// Constructs a new `Xyz` color.
+    /// Constructs a new `Xyz` color. The components are relative to a white
+    /// with `Y = 1`, so D65 white is `Xyz::new(0.95047, 1.0, 1.08883)`.
+    /// Negative components are clamped to 0.
     ///
     /// # Example
     ///
@@ -151,7 +165,7 @@ impl Xyz {
         self.z
     }
 
-    /// Sets the x component as a ratio.
+    /// Sets the x component. Negative values are clamped to 0.
     ///
     /// # Example
     ///
@@ -174,10 +188,10 @@ impl Xyz {
     /// # }
     /// ```
     pub fn set_x(&mut self, x: f32) {
-        self.x = clamped(x, 0.0, 1.0);
+        self.x = x.max(0.0);
     }
 
-    /// Sets the y component as a ratio.
+    /// Sets the y component. Negative values are clamped to 0.
     ///
     /// # Example
     ///
@@ -198,11 +212,12 @@ impl Xyz {
     /// # fn main() {
     /// #     example().unwrap();
     /// # }
+    /// ```
     pub fn set_y(&mut self, y: f32) {
-        self.y = clamped(y, 0.0, 1.0);
+        self.y = y.max(0.0);
     }
 
-    /// Sets the z component as a ratio.
+    /// Sets the z component. Negative values are clamped to 0.
     ///
     /// # Example
     ///
@@ -223,8 +238,9 @@ impl Xyz {
     /// # fn main() {
     /// #     example().unwrap();
     /// # }
+    /// ```
     pub fn set_z(&mut self, z: f32) {
-        self.z = clamped(z, 0.0, 1.0);
+        self.z = z.max(0.0);
     }
 
     /// Returns an array containing the `[X, Y, Z]` components.
@@ -391,11 +407,11 @@ impl From<[f32; 3]> for Xyz {
         let span = span!(Level::DEBUG, "Xyz::from<[f32; 3]>");
         let _enter = span.enter();
         
-        Xyz {
-            x: components[0],
-            y: components[1],
-            z: components[2],
-        }
+        Xyz::new(
+            components[0],
+            components[1],
+            components[2],
+        )
     }
 }
 
@@ -427,18 +443,89 @@ impl From<Hsv> for Xyz {
     }
 }
 
-impl From<Rgb> for Xyz {
-    fn from(rgb: Rgb) -> Self {
-        let span = span!(Level::DEBUG, "Xyz::from<Rgb>");
+impl From<Lab> for Xyz {
+    fn from(lab: Lab) -> Self {
+        let span = span!(Level::DEBUG, "Xyz::from<Lab>");
+        let _enter = span.enter();
+
+        // Expands a cube-root domain component into a white-relative ratio.
+        fn f_inv(t: f32) -> f32 {
+            let t3 = t * t * t;
+            if t3 > EPSILON {
+                t3
+            } else {
+                (116.0 * t - 16.0) / KAPPA
+            }
+        }
+
+        let (l, a, b) = (lab.lightness(), lab.a(), lab.b());
+
+        let fy = (l + 16.0) / 116.0;
+        let fx = fy + a / 500.0;
+        let fz = fy - b / 200.0;
+
+        let yr = if l > KAPPA * EPSILON {
+            fy * fy * fy
+        } else {
+            l / KAPPA
+        };
+
+        Xyz {
+            x: f_inv(fx) * D65_WHITE[0],
+            y: yr * D65_WHITE[1],
+            z: f_inv(fz) * D65_WHITE[2],
+        }
+    }
+}
+
+impl From<Lch> for Xyz {
+    fn from(lch: Lch) -> Self {
+        let span = span!(Level::DEBUG, "Xyz::from<Lch>");
         let _enter = span.enter();
         
-        let m = rgb.ratios(); 
+        Xyz::from(Lab::from(lch))
+    }
+}
+
+impl From<LinearRgb> for Xyz {
+    fn from(linear_rgb: LinearRgb) -> Self {
+        let span = span!(Level::DEBUG, "Xyz::from<LinearRgb>");
+        let _enter = span.enter();
+        
+        let m = linear_rgb.components();
 
         Xyz {
             x: m[0] * 0.4124564 + m[1] * 0.3575761 + m[2] * 0.1804375,
-            y: m[0] * 0.2126729 + m[1] * 0.7151522 + m[2] * 0.0721750,
-            z: m[0] * 0.0193339 + m[1] * 0.1191920 + m[2] * 0.9503041,
+            y: m[0] * 0.2126729 + m[1] * 0.7151522 + m[2] * 0.072175,
+            z: m[0] * 0.0193339 + m[1] * 0.119192 + m[2] * 0.9503041,
         }
     }
 }
 
+impl From<Oklab> for Xyz {
+    fn from(oklab: Oklab) -> Self {
+        let span = span!(Level::DEBUG, "Xyz::from<Oklab>");
+        let _enter = span.enter();
+        
+        Xyz::from(LinearRgb::from(oklab))
+    }
+}
+
+impl From<Oklch> for Xyz {
+    fn from(oklch: Oklch) -> Self {
+        let span = span!(Level::DEBUG, "Xyz::from<Oklch>");
+        let _enter = span.enter();
+        
+        Xyz::from(LinearRgb::from(oklch))
+    }
+}
+
+impl From<Rgb> for Xyz {
+    fn from(rgb: Rgb) -> Self {
+        let span = span!(Level::DEBUG, "Xyz::from<Rgb>");
+        let _enter = span.enter();
+        
+        Xyz::from(LinearRgb::from(rgb))
+    }
+}
+
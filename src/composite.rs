@@ -0,0 +1,174 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Porter-Duff compositing operators.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Alpha;
+use crate::LinearRgb;
+use crate::utility::clamped;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use tracing::Level;
+use tracing::span;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CompositeOperator
+////////////////////////////////////////////////////////////////////////////////
+/// A Porter-Duff compositing operator, describing how a source color is
+/// combined with the destination color beneath it.
+///
+/// All compositing is done in linear light, using either straight or
+/// premultiplied alpha.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CompositeOperator {
+    /// The source is placed over the destination.
+    #[default]
+    SourceOver,
+    /// The destination is placed over the source.
+    DestinationOver,
+    /// The source is kept where it overlaps the destination.
+    SourceIn,
+    /// The destination is kept where it overlaps the source.
+    DestinationIn,
+    /// The source is kept where it does not overlap the destination.
+    SourceOut,
+    /// The destination is kept where it does not overlap the source.
+    DestinationOut,
+    /// The source is placed over the destination where they overlap, and the
+    /// destination is kept elsewhere.
+    SourceAtop,
+    /// The destination is placed over the source where they overlap, and the
+    /// source is kept elsewhere.
+    DestinationAtop,
+    /// The source and destination are kept where they do not overlap.
+    Xor,
+    /// The source and destination are added together.
+    Plus,
+}
+
+
+impl CompositeOperator {
+    /// Returns the fractions of the source and destination which contribute
+    /// to the result, given their alpha components.
+    fn fractions(&self, source_alpha: f32, destination_alpha: f32)
+        -> (f32, f32)
+    {
+        use CompositeOperator::*;
+        let (sa, da) = (source_alpha, destination_alpha);
+        match self {
+            SourceOver      => (1.0,      1.0 - sa),
+            DestinationOver => (1.0 - da, 1.0),
+            SourceIn        => (da,       0.0),
+            DestinationIn   => (0.0,      sa),
+            SourceOut       => (1.0 - da, 0.0),
+            DestinationOut  => (0.0,      1.0 - sa),
+            SourceAtop      => (da,       1.0 - sa),
+            DestinationAtop => (1.0 - da, sa),
+            Xor             => (1.0 - da, 1.0 - sa),
+            Plus            => (1.0,      1.0),
+        }
+    }
+
+    /// Composites the `source` color with the `destination` color, where both
+    /// colors have premultiplied alpha. The result also has premultiplied
+    /// alpha.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, CompositeOperator, LinearRgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let source = Alpha::new(LinearRgb::new(0.5, 0.0, 0.0), 0.5);
+    /// let destination = Alpha::new(LinearRgb::new(0.0, 0.0, 1.0), 1.0);
+    ///
+    /// let result = CompositeOperator::SourceOver
+    ///     .composite_premultiplied(source, destination);
+    ///
+    /// assert_eq!(result, Alpha::new(LinearRgb::new(0.5, 0.0, 0.5), 1.0));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn composite_premultiplied(
+        &self,
+        source: Alpha<LinearRgb>,
+        destination: Alpha<LinearRgb>)
+        -> Alpha<LinearRgb>
+    {
+        let span = span!(Level::DEBUG, "CompositeOperator::composite_premultiplied");
+        let _enter = span.enter();
+
+        let (fs, fd) = self.fractions(source.alpha, destination.alpha);
+        let (s, d) = (source.color, destination.color);
+
+        Alpha {
+            color: LinearRgb {
+                r: clamped(s.r * fs + d.r * fd, 0.0, 1.0),
+                g: clamped(s.g * fs + d.g * fd, 0.0, 1.0),
+                b: clamped(s.b * fs + d.b * fd, 0.0, 1.0),
+            },
+            alpha: clamped(source.alpha * fs + destination.alpha * fd, 0.0, 1.0),
+        }
+    }
+
+    /// Composites the `source` color with the `destination` color, where both
+    /// colors have straight (unassociated) alpha. The result also has straight
+    /// alpha.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Alpha, CompositeOperator, LinearRgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let source = Alpha::new(LinearRgb::new(1.0, 0.0, 0.0), 0.5);
+    /// let destination = Alpha::new(LinearRgb::new(0.0, 0.0, 1.0), 1.0);
+    ///
+    /// let result = CompositeOperator::SourceOver.composite(source, destination);
+    ///
+    /// assert_eq!(result, Alpha::new(LinearRgb::new(0.5, 0.0, 0.5), 1.0));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn composite(
+        &self,
+        source: Alpha<LinearRgb>,
+        destination: Alpha<LinearRgb>)
+        -> Alpha<LinearRgb>
+    {
+        let span = span!(Level::DEBUG, "CompositeOperator::composite");
+        let _enter = span.enter();
+
+        self.composite_premultiplied(
+                source.premultiplied(),
+                destination.premultiplied())
+            .unpremultiplied()
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Contrast metrics for checking the legibility of text.
+//!
+//! Two metrics are provided: the [WCAG 2.1] contrast ratio, and the [APCA]
+//! lightness contrast proposed for WCAG 3. APCA models the perception of text
+//! more accurately, especially for dark color pairs.
+//!
+//! [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+//! [APCA]: https://github.com/Myndex/apca-w3
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::LinearRgb;
+use crate::Rgb;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// TextSize
+////////////////////////////////////////////////////////////////////////////////
+/// The size category of text, which determines the contrast required by the
+/// [WCAG 2.1] success criteria.
+///
+/// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextSize {
+    /// Text smaller than large text.
+    #[default]
+    Normal,
+    /// Text at least 18 point, or at least 14 point and bold.
+    Large,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// WcagLevel
+////////////////////////////////////////////////////////////////////////////////
+/// A [WCAG 2.1] conformance level for the contrast between text and its
+/// background.
+///
+/// Levels are ordered, so `level >= WcagLevel::Aa` checks for at least AA
+/// conformance.
+///
+/// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WcagLevel {
+    /// The contrast does not meet level AA.
+    Fail,
+    /// The contrast meets level AA, but not level AAA.
+    Aa,
+    /// The contrast meets level AAA.
+    Aaa,
+}
+
+impl WcagLevel {
+    /// Returns the conformance level met by the given contrast ratio for text
+    /// of the given size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::contrast::{ TextSize, WcagLevel };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(WcagLevel::for_ratio(5.0, TextSize::Normal), WcagLevel::Aa);
+    /// assert_eq!(WcagLevel::for_ratio(5.0, TextSize::Large), WcagLevel::Aaa);
+    /// assert_eq!(WcagLevel::for_ratio(2.9, TextSize::Large), WcagLevel::Fail);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn for_ratio(ratio: f32, size: TextSize) -> Self {
+        if ratio >= WcagLevel::Aaa.minimum_ratio(size) {
+            WcagLevel::Aaa
+        } else if ratio >= WcagLevel::Aa.minimum_ratio(size) {
+            WcagLevel::Aa
+        } else {
+            WcagLevel::Fail
+        }
+    }
+
+    /// Returns the minimum contrast ratio required to meet the conformance
+    /// level for text of the given size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::contrast::{ TextSize, WcagLevel };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(WcagLevel::Aa.minimum_ratio(TextSize::Normal), 4.5);
+    /// assert_eq!(WcagLevel::Aaa.minimum_ratio(TextSize::Large), 4.5);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn minimum_ratio(&self, size: TextSize) -> f32 {
+        match (self, size) {
+            (WcagLevel::Fail, _)                => 1.0,
+            (WcagLevel::Aa,   TextSize::Normal) => 4.5,
+            (WcagLevel::Aa,   TextSize::Large)  => 3.0,
+            (WcagLevel::Aaa,  TextSize::Normal) => 7.0,
+            (WcagLevel::Aaa,  TextSize::Large)  => 4.5,
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// relative_luminance
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the [WCAG 2.1 relative luminance] of the given color, from 0.0 for
+/// black to 1.0 for white.
+///
+/// [WCAG 2.1 relative luminance]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::Rgb;
+/// # use color::contrast::relative_luminance;
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// assert_eq!(relative_luminance(Rgb::new(255, 255, 255)), 1.0);
+/// assert_eq!(relative_luminance(Rgb::new(255, 0, 0)), 0.2126);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+pub fn relative_luminance<C>(color: C) -> f32 where C: Into<LinearRgb> {
+    let linear = color.into();
+    0.2126 * linear.r + 0.7152 * linear.g + 0.0722 * linear.b
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// contrast_ratio
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the [WCAG 2.1 contrast ratio] between the given colors, from 1.0 for
+/// identical colors to 21.0 for black and white. The order of the colors does
+/// not matter.
+///
+/// [WCAG 2.1 contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::Rgb;
+/// # use color::contrast::contrast_ratio;
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let black = Rgb::new(0, 0, 0);
+/// let white = Rgb::new(255, 255, 255);
+///
+/// assert_eq!(contrast_ratio(black, white), 20.999998);
+/// assert_eq!(contrast_ratio(white, Rgb::new(118, 118, 118)), 4.542225);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+pub fn contrast_ratio<C, D>(a: C, b: D) -> f32
+    where
+        C: Into<LinearRgb> + Sized,
+        D: Into<LinearRgb> + Sized,
+{
+    let la = relative_luminance(a);
+    let lb = relative_luminance(b);
+    let (lighter, darker) = if la > lb { (la, lb) } else { (lb, la) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// apca_contrast
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the [APCA] lightness contrast (Lc) of the given text color on the
+/// given background color, using the 0.0.98G constants.
+///
+/// The result is roughly in the range -108 to 106. It is positive for dark
+/// text on a light background and negative for light text on a dark
+/// background, and the order of the colors matters. Contrasts too low to be
+/// meaningful are clamped to 0. As a rough guide, an absolute Lc of 75 is the
+/// minimum for body text, 60 for other content text, and 45 for large text.
+///
+/// [APCA]: https://github.com/Myndex/apca-w3
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::Rgb;
+/// # use color::contrast::apca_contrast;
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let gray = Rgb::new(0x88, 0x88, 0x88);
+/// let white = Rgb::new(0xFF, 0xFF, 0xFF);
+///
+/// assert_eq!(apca_contrast(gray, white), 63.056477);
+/// assert_eq!(apca_contrast(white, gray), -68.54146);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+pub fn apca_contrast<C, D>(text: C, background: D) -> f32
+    where
+        C: Into<Rgb> + Sized,
+        D: Into<Rgb> + Sized,
+{
+    let text_y = apca_soft_clamp(apca_luminance(text.into()));
+    let background_y = apca_soft_clamp(apca_luminance(background.into()));
+
+    if (background_y - text_y).abs() < 0.0005 {
+        return 0.0;
+    }
+
+    let lc = if background_y > text_y {
+        // Dark text on a light background.
+        let sapc = (background_y.powf(0.56) - text_y.powf(0.57)) * 1.14;
+        if sapc < 0.1 { 0.0 } else { sapc - 0.027 }
+    } else {
+        // Light text on a dark background.
+        let sapc = (background_y.powf(0.65) - text_y.powf(0.62)) * 1.14;
+        if sapc > -0.1 { 0.0 } else { sapc + 0.027 }
+    };
+    lc * 100.0
+}
+
+/// Returns the APCA screen luminance of the given color, which uses a simple
+/// 2.4 exponent rather than the piecewise sRGB transfer function.
+fn apca_luminance(rgb: Rgb) -> f32 {
+    let [r, g, b] = rgb.ratios();
+    0.2126729 * r.powf(2.4) + 0.7151522 * g.powf(2.4) + 0.072175 * b.powf(2.4)
+}
+
+/// Applies the APCA soft clamp to luminances near black, accounting for
+/// flare.
+fn apca_soft_clamp(y: f32) -> f32 {
+    const BLACK_THRESHOLD: f32 = 0.022;
+    if y > BLACK_THRESHOLD {
+        y
+    } else {
+        y + (BLACK_THRESHOLD - y).powf(1.414)
+    }
+}
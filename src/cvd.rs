@@ -0,0 +1,408 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Color vision deficiency simulation.
+//!
+//! Simulations use the model of [Machado, Oliveira, and Fernandes (2009)],
+//! which is applied in [`LinearRgb`] color space. The model covers both
+//! dichromacy (protanopia, deuteranopia, and tritanopia), where one type of
+//! cone is missing, and anomalous trichromacy (protanomaly, deuteranomaly, and
+//! tritanomaly), where one type of cone has a shifted sensitivity.
+//!
+//! The module also provides [`daltonize`] to make colors easier to
+//! distinguish for affected viewers, and [`palette_conflicts`] to check
+//! whether the colors of a palette remain distinguishable.
+//!
+//! [Machado, Oliveira, and Fernandes (2009)]: https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html
+//! [`LinearRgb`]: ../struct.LinearRgb.html
+//! [`daltonize`]: fn.daltonize.html
+//! [`palette_conflicts`]: fn.palette_conflicts.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Color;
+use crate::delta_e::delta_e_2000;
+use crate::Lab;
+use crate::LinearRgb;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use tracing::Level;
+use tracing::span;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CvdKind
+////////////////////////////////////////////////////////////////////////////////
+/// A kind of color vision deficiency, named for the affected cone type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CvdKind {
+    /// A deficiency of the long-wavelength (red) cones, causing protanomaly,
+    /// or protanopia at full severity.
+    Protan,
+    /// A deficiency of the medium-wavelength (green) cones, causing
+    /// deuteranomaly, or deuteranopia at full severity.
+    Deutan,
+    /// A deficiency of the short-wavelength (blue) cones, causing
+    /// tritanomaly, or tritanopia at full severity.
+    Tritan,
+}
+
+impl CvdKind {
+    /// All kinds of color vision deficiency.
+    pub const ALL: [CvdKind; 3] = [
+        CvdKind::Protan,
+        CvdKind::Deutan,
+        CvdKind::Tritan,
+    ];
+
+    /// Returns the simulation matrix for the given severity, interpolating
+    /// between the tabulated matrices.
+    fn matrix(&self, severity: f32) -> [[f32; 3]; 3] {
+        let table = match self {
+            CvdKind::Protan => &PROTAN,
+            CvdKind::Deutan => &DEUTAN,
+            CvdKind::Tritan => &TRITAN,
+        };
+
+        let position = severity.clamp(0.0, 1.0) * 10.0;
+        let index = (position.floor() as usize).min(9);
+        let amount = position - index as f32;
+
+        let (lower, upper) = (&table[index], &table[index + 1]);
+        let mut matrix = [[0.0; 3]; 3];
+        for (row, (l, u)) in matrix.iter_mut().zip(lower.iter().zip(upper)) {
+            for (value, (l, u)) in row.iter_mut().zip(l.iter().zip(u)) {
+                *value = l + (u - l) * amount;
+            }
+        }
+        matrix
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// simulate
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the given color as it would appear to a viewer with the given kind
+/// of color vision deficiency.
+///
+/// The `severity` is clamped between 0 and 1. A severity of 0 leaves the
+/// color unchanged, a severity of 1 simulates dichromacy, and values between
+/// simulate anomalous trichromacy of increasing severity. The result is
+/// clamped to the sRGB gamut.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::{ LinearRgb, Rgb };
+/// # use color::cvd::{ CvdKind, simulate };
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let red = Rgb::new(255, 0, 0);
+///
+/// assert_eq!(simulate(red, CvdKind::Protan, 1.0),
+///     LinearRgb::new(0.152286, 0.114503, 0.0));
+/// assert_eq!(simulate(red, CvdKind::Deutan, 0.5),
+///     LinearRgb::new(0.547494, 0.181692, 0.0));
+/// assert_eq!(simulate(red, CvdKind::Tritan, 0.0),
+///     LinearRgb::new(1.0, 0.0, 0.0));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+pub fn simulate<C>(color: C, kind: CvdKind, severity: f32) -> LinearRgb
+    where C: Into<LinearRgb>
+{
+    let span = span!(Level::DEBUG, "cvd::simulate");
+    let _enter = span.enter();
+
+    let LinearRgb { r, g, b } = color.into();
+    let [m0, m1, m2] = kind.matrix(severity);
+    LinearRgb::new(
+        m0[0] * r + m0[1] * g + m0[2] * b,
+        m1[0] * r + m1[1] * g + m1[2] * b,
+        m2[0] * r + m2[1] * g + m2[2] * b)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// daltonize
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the given color adjusted to be easier to distinguish for a viewer
+/// with the given kind of color vision deficiency.
+///
+/// This uses the method of Fidaner, Lin, and Ozguven: the difference between
+/// the color and its simulated dichromatic appearance is the information lost
+/// to the viewer, and it is redistributed onto the channels the viewer can
+/// still see. For protan and deutan deficiencies the difference is shifted
+/// into the green and blue channels, and for tritan deficiencies into the red
+/// and green channels. Colors which the viewer already sees correctly, such
+/// as grays, are unchanged. The result is clamped to the sRGB gamut.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::{ LinearRgb, Rgb };
+/// # use color::cvd::{ CvdKind, daltonize };
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let red = Rgb::new(255, 0, 0);
+/// let gray = LinearRgb::new(0.5, 0.5, 0.5);
+///
+/// assert_eq!(daltonize(red, CvdKind::Deutan),
+///     LinearRgb::new(1.0, 0.16278961, 0.4428746));
+/// assert_eq!(daltonize(gray, CvdKind::Tritan), gray);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+pub fn daltonize<C>(color: C, kind: CvdKind) -> LinearRgb
+    where C: Into<LinearRgb>
+{
+    let span = span!(Level::DEBUG, "cvd::daltonize");
+    let _enter = span.enter();
+
+    let color = color.into();
+    let simulated = simulate(color, kind, 1.0);
+    let (er, eg, eb) = (
+        color.r - simulated.r,
+        color.g - simulated.g,
+        color.b - simulated.b);
+
+    let (dr, dg, db) = match kind {
+        CvdKind::Protan |
+        CvdKind::Deutan => (0.0, 0.7 * er + eg, 0.7 * er + eb),
+        CvdKind::Tritan => (er + 0.7 * eb, eg + 0.7 * eb, 0.0),
+    };
+    LinearRgb::new(color.r + dr, color.g + dg, color.b + db)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CvdConflict
+////////////////////////////////////////////////////////////////////////////////
+/// A pair of palette colors which are difficult to distinguish for viewers
+/// with a kind of color vision deficiency. Returned by
+/// [`palette_conflicts`].
+///
+/// [`palette_conflicts`]: fn.palette_conflicts.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CvdConflict {
+    /// The kind of color vision deficiency.
+    pub kind: CvdKind,
+    /// The palette index of the first color of the pair.
+    pub first: usize,
+    /// The palette index of the second color of the pair, which is always
+    /// greater than `first`.
+    pub second: usize,
+    /// The CIEDE2000 difference between the simulated colors.
+    pub delta_e: f32,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// palette_conflicts
+////////////////////////////////////////////////////////////////////////////////
+/// Returns every pair of colors in the palette whose CIEDE2000 difference
+/// falls below the given threshold when simulated for each kind of color
+/// vision deficiency at full severity.
+///
+/// Conflicts are ordered by deficiency kind, then by palette index. The alpha
+/// components of the colors are ignored. A threshold around 10 is a
+/// reasonable minimum for colors in a categorical palette to be told apart at
+/// a glance.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::{ Color, Rgb };
+/// # use color::cvd::{ CvdKind, palette_conflicts };
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let palette = [
+///     Color::new(Rgb {r: 0x30, g: 0x60, b: 0xD0}),
+///     Color::new(Rgb {r: 0x90, g: 0x50, b: 0xD0}),
+///     Color::new(Rgb {r: 0xC0, g: 0x80, b: 0x00}),
+/// ];
+///
+/// let conflicts = palette_conflicts(&palette, 10.0);
+///
+/// assert_eq!(conflicts.len(), 2);
+/// assert_eq!(conflicts[0].kind, CvdKind::Protan);
+/// assert_eq!((conflicts[0].first, conflicts[0].second), (0, 1));
+/// assert_eq!(conflicts[1].kind, CvdKind::Deutan);
+/// assert_eq!((conflicts[1].first, conflicts[1].second), (0, 1));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+pub fn palette_conflicts(palette: &[Color], threshold: f32)
+    -> Vec<CvdConflict>
+{
+    let span = span!(Level::DEBUG, "cvd::palette_conflicts");
+    let _enter = span.enter();
+
+    let mut conflicts = Vec::new();
+    for &kind in &CvdKind::ALL {
+        let simulated: Vec<Lab> = palette
+            .iter()
+            .map(|&color| Lab::from(simulate(color, kind, 1.0)))
+            .collect();
+
+        for (first, &a) in simulated.iter().enumerate() {
+            for (second, &b) in simulated.iter().enumerate().skip(first + 1) {
+                let delta_e = delta_e_2000(a, b);
+                if delta_e < threshold {
+                    conflicts.push(CvdConflict { kind, first, second, delta_e });
+                }
+            }
+        }
+    }
+    conflicts
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Simulation matrices
+////////////////////////////////////////////////////////////////////////////////
+/// The Machado et al. simulation matrices for protan deficiencies, at
+/// severities from 0.0 to 1.0 in steps of 0.1.
+const PROTAN: [[[f32; 3]; 3]; 11] = [
+    [[ 1.000000,  0.000000,  0.000000],
+     [ 0.000000,  1.000000,  0.000000],
+     [ 0.000000,  0.000000,  1.000000]],
+    [[ 0.856167,  0.182038, -0.038205],
+     [ 0.029342,  0.955115,  0.015544],
+     [-0.002880, -0.001563,  1.004443]],
+    [[ 0.734766,  0.334872, -0.069637],
+     [ 0.051840,  0.919198,  0.028963],
+     [-0.004928, -0.004209,  1.009137]],
+    [[ 0.630323,  0.465641, -0.095964],
+     [ 0.069181,  0.890046,  0.040773],
+     [-0.006308, -0.007724,  1.014032]],
+    [[ 0.539009,  0.579343, -0.118352],
+     [ 0.082546,  0.866121,  0.051332],
+     [-0.007136, -0.011959,  1.019095]],
+    [[ 0.458064,  0.679578, -0.137642],
+     [ 0.092785,  0.846313,  0.060902],
+     [-0.007494, -0.016807,  1.024301]],
+    [[ 0.385450,  0.769005, -0.154455],
+     [ 0.100526,  0.829802,  0.069673],
+     [-0.007442, -0.022190,  1.029632]],
+    [[ 0.319627,  0.849633, -0.169261],
+     [ 0.106241,  0.815969,  0.077790],
+     [-0.007025, -0.028051,  1.035076]],
+    [[ 0.259411,  0.923008, -0.182420],
+     [ 0.110296,  0.804340,  0.085364],
+     [-0.006276, -0.034346,  1.040622]],
+    [[ 0.203876,  0.990338, -0.194214],
+     [ 0.112975,  0.794542,  0.092483],
+     [-0.005222, -0.041043,  1.046265]],
+    [[ 0.152286,  1.052583, -0.204868],
+     [ 0.114503,  0.786281,  0.099216],
+     [-0.003882, -0.048116,  1.051998]],
+];
+
+/// The Machado et al. simulation matrices for deutan deficiencies, at
+/// severities from 0.0 to 1.0 in steps of 0.1.
+const DEUTAN: [[[f32; 3]; 3]; 11] = [
+    [[ 1.000000,  0.000000,  0.000000],
+     [ 0.000000,  1.000000,  0.000000],
+     [ 0.000000,  0.000000,  1.000000]],
+    [[ 0.866435,  0.177704, -0.044139],
+     [ 0.049567,  0.939063,  0.011370],
+     [-0.003453,  0.007233,  0.996220]],
+    [[ 0.760729,  0.319078, -0.079807],
+     [ 0.090568,  0.889315,  0.020117],
+     [-0.006027,  0.013325,  0.992702]],
+    [[ 0.675425,  0.433850, -0.109275],
+     [ 0.125303,  0.847755,  0.026942],
+     [-0.007950,  0.018572,  0.989378]],
+    [[ 0.605511,  0.528560, -0.134071],
+     [ 0.155318,  0.812366,  0.032316],
+     [-0.009376,  0.023176,  0.986200]],
+    [[ 0.547494,  0.607765, -0.155259],
+     [ 0.181692,  0.781742,  0.036566],
+     [-0.010410,  0.027275,  0.983136]],
+    [[ 0.498864,  0.674741, -0.173604],
+     [ 0.205199,  0.754872,  0.039929],
+     [-0.011131,  0.030969,  0.980162]],
+    [[ 0.457771,  0.731899, -0.189670],
+     [ 0.226409,  0.731012,  0.042579],
+     [-0.011595,  0.034333,  0.977261]],
+    [[ 0.422823,  0.781057, -0.203881],
+     [ 0.245752,  0.709602,  0.044646],
+     [-0.011843,  0.037423,  0.974421]],
+    [[ 0.392952,  0.823610, -0.216562],
+     [ 0.263559,  0.690210,  0.046232],
+     [-0.011910,  0.040281,  0.971630]],
+    [[ 0.367322,  0.860646, -0.227968],
+     [ 0.280085,  0.672501,  0.047413],
+     [-0.011820,  0.042940,  0.968881]],
+];
+
+/// The Machado et al. simulation matrices for tritan deficiencies, at
+/// severities from 0.0 to 1.0 in steps of 0.1.
+const TRITAN: [[[f32; 3]; 3]; 11] = [
+    [[ 1.000000,  0.000000,  0.000000],
+     [ 0.000000,  1.000000,  0.000000],
+     [ 0.000000,  0.000000,  1.000000]],
+    [[ 0.926670,  0.092514, -0.019184],
+     [ 0.021191,  0.964503,  0.014306],
+     [ 0.008437,  0.054813,  0.936750]],
+    [[ 0.895720,  0.133330, -0.029050],
+     [ 0.029997,  0.945400,  0.024603],
+     [ 0.013027,  0.104707,  0.882266]],
+    [[ 0.905871,  0.127791, -0.033662],
+     [ 0.026856,  0.941251,  0.031893],
+     [ 0.013410,  0.148296,  0.838294]],
+    [[ 0.948035,  0.089490, -0.037526],
+     [ 0.014364,  0.946792,  0.038844],
+     [ 0.010853,  0.193991,  0.795156]],
+    [[ 1.017277,  0.027029, -0.044306],
+     [-0.006113,  0.958479,  0.047634],
+     [ 0.006379,  0.248708,  0.744913]],
+    [[ 1.104996, -0.046633, -0.058363],
+     [-0.032137,  0.971635,  0.060503],
+     [ 0.001336,  0.317922,  0.680742]],
+    [[ 1.193214, -0.109812, -0.083402],
+     [-0.058496,  0.979410,  0.079086],
+     [-0.002346,  0.403492,  0.598854]],
+    [[ 1.257728, -0.139648, -0.118081],
+     [-0.078003,  0.975409,  0.102594],
+     [-0.003316,  0.501214,  0.502102]],
+    [[ 1.278864, -0.125333, -0.153531],
+     [-0.084748,  0.957674,  0.127074],
+     [-0.000989,  0.601151,  0.399838]],
+    [[ 1.255528, -0.076749, -0.178779],
+     [-0.078411,  0.930809,  0.147602],
+     [ 0.004733,  0.691367,  0.303900]],
+];
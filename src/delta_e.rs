@@ -0,0 +1,334 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Perceptual color difference metrics.
+//!
+//! Each metric returns a ΔE value measured in [`Lab`] color space, where a
+//! difference of about 1.0 is the smallest a typical observer can notice.
+//!
+//! [`Lab`]: ../struct.Lab.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Lab;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+// Standard library imports.
+use std::f32;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Cie94Application
+////////////////////////////////////////////////////////////////////////////////
+/// The application area whose weighting factors are used by [`delta_e_94`].
+///
+/// [`delta_e_94`]: fn.delta_e_94.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Cie94Application {
+    /// Weighting factors for graphic arts.
+    #[default]
+    GraphicArts,
+    /// Weighting factors for textiles, which are more tolerant of lightness
+    /// differences.
+    Textiles,
+}
+
+impl Cie94Application {
+    /// Returns the `(kL, K1, K2)` weighting factors for the application.
+    fn weights(&self) -> (f32, f32, f32) {
+        match self {
+            Cie94Application::GraphicArts => (1.0, 0.045, 0.015),
+            Cie94Application::Textiles    => (2.0, 0.048, 0.014),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// delta_e_76
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the CIE76 color difference between the given colors, which is the
+/// Euclidean distance between them in [`Lab`] color space.
+///
+/// [`Lab`]: ../struct.Lab.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::Lab;
+/// # use color::delta_e::delta_e_76;
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = Lab::new(50.0, 2.6772, -79.7751);
+/// let b = Lab::new(50.0, 0.0, -82.7485);
+///
+/// assert_eq!(delta_e_76(a, b), 4.0010605);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+pub fn delta_e_76<C, D>(reference: C, sample: D) -> f32
+    where
+        C: Into<Lab> + Sized,
+        D: Into<Lab> + Sized,
+{
+    Lab::distance(reference, sample)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// delta_e_94
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the CIE94 color difference between the given colors, using the
+/// weighting factors for the given application.
+///
+/// CIE94 is not symmetric: the chroma of the `reference` color is used to
+/// weight the chroma and hue differences.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::Lab;
+/// # use color::delta_e::{ Cie94Application, delta_e_94 };
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = Lab::new(50.0, 2.6772, -79.7751);
+/// let b = Lab::new(50.0, 0.0, -82.7485);
+///
+/// assert_eq!(delta_e_94(a, b, Cie94Application::GraphicArts), 1.3950375);
+/// assert_eq!(delta_e_94(a, b, Cie94Application::Textiles), 1.4230447);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+pub fn delta_e_94<C, D>(
+    reference: C,
+    sample: D,
+    application: Cie94Application)
+    -> f32
+    where
+        C: Into<Lab> + Sized,
+        D: Into<Lab> + Sized,
+{
+    let (r, s) = (reference.into(), sample.into());
+    let (kl, k1, k2) = application.weights();
+
+    let c1 = r.a.hypot(r.b);
+    let c2 = s.a.hypot(s.b);
+    let dl = r.l - s.l;
+    let dc = c1 - c2;
+    let dh_sq = delta_hue_squared(r, s, dc);
+
+    let sc = 1.0 + k1 * c1;
+    let sh = 1.0 + k2 * c1;
+
+    ((dl / kl).powi(2) + (dc / sc).powi(2) + dh_sq / (sh * sh)).sqrt()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// delta_e_2000
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the CIEDE2000 color difference between the given colors, with unit
+/// parametric weighting factors.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::Lab;
+/// # use color::delta_e::delta_e_2000;
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = Lab::new(50.0, 2.6772, -79.7751);
+/// let b = Lab::new(50.0, 0.0, -82.7485);
+///
+/// assert_eq!(delta_e_2000(a, b), 2.0424585);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+pub fn delta_e_2000<C, D>(reference: C, sample: D) -> f32
+    where
+        C: Into<Lab> + Sized,
+        D: Into<Lab> + Sized,
+{
+    let (r, s) = (reference.into(), sample.into());
+
+    // Adjust the a* axis to improve the performance for neutral colors.
+    let c_mean = (r.a.hypot(r.b) + s.a.hypot(s.b)) / 2.0;
+    let c_mean_7 = c_mean.powi(7);
+    let g = 0.5 * (1.0 - (c_mean_7 / (c_mean_7 + 25.0f32.powi(7))).sqrt());
+    let a1 = r.a * (1.0 + g);
+    let a2 = s.a * (1.0 + g);
+
+    let c1 = a1.hypot(r.b);
+    let c2 = a2.hypot(s.b);
+    let h1 = hue_degrees(r.b, a1);
+    let h2 = hue_degrees(s.b, a2);
+
+    let dl = s.l - r.l;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 <= h1 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1 - 360.0
+    };
+    let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_mean = (r.l + s.l) / 2.0;
+    let c_mean = (c1 + c2) / 2.0;
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0
+        - 0.17 * (h_mean - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_mean).to_radians().cos()
+        + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+
+    let l_offset = (l_mean - 50.0).powi(2);
+    let sl = 1.0 + 0.015 * l_offset / (20.0 + l_offset).sqrt();
+    let sc = 1.0 + 0.045 * c_mean;
+    let sh = 1.0 + 0.015 * c_mean * t;
+
+    let c_mean_7 = c_mean.powi(7);
+    let rc = 2.0 * (c_mean_7 / (c_mean_7 + 25.0f32.powi(7))).sqrt();
+    let d_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+    let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+    let (l, c, h) = (dl / sl, dc / sc, dh / sh);
+    (l * l + c * c + h * h + rt * c * h).sqrt()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// delta_e_cmc
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the CMC l:c color difference between the given colors, using the
+/// given lightness and chroma weights. The common weights are 2:1 for
+/// acceptability and 1:1 for perceptibility.
+///
+/// CMC l:c is not symmetric: the `reference` color is used to weight the
+/// differences.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::Lab;
+/// # use color::delta_e::delta_e_cmc;
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = Lab::new(50.0, 2.6772, -79.7751);
+/// let b = Lab::new(55.0, 0.0, -82.7485);
+///
+/// assert_eq!(delta_e_cmc(a, b, 2.0, 1.0), 2.8809745);
+/// assert_eq!(delta_e_cmc(a, b, 1.0, 1.0), 4.912277);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+pub fn delta_e_cmc<C, D>(
+    reference: C,
+    sample: D,
+    lightness: f32,
+    chroma: f32)
+    -> f32
+    where
+        C: Into<Lab> + Sized,
+        D: Into<Lab> + Sized,
+{
+    let (r, s) = (reference.into(), sample.into());
+
+    let c1 = r.a.hypot(r.b);
+    let c2 = s.a.hypot(s.b);
+    let h1 = hue_degrees(r.b, r.a);
+    let dl = r.l - s.l;
+    let dc = c1 - c2;
+    let dh_sq = delta_hue_squared(r, s, dc);
+
+    let sl = if r.l < 16.0 {
+        0.511
+    } else {
+        0.040975 * r.l / (1.0 + 0.01765 * r.l)
+    };
+    let sc = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+    let c1_4 = c1.powi(4);
+    let f = (c1_4 / (c1_4 + 1900.0)).sqrt();
+    let t = if (164.0..=345.0).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+    };
+    let sh = sc * (f * t + 1.0 - f);
+
+    ((dl / (lightness * sl)).powi(2)
+        + (dc / (chroma * sc)).powi(2)
+        + dh_sq / (sh * sh))
+        .sqrt()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Helpers
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the hue angle of the given Lab coordinates in degrees, in the range
+/// `[0, 360)`.
+fn hue_degrees(b: f32, a: f32) -> f32 {
+    if a == 0.0 && b == 0.0 {
+        return 0.0;
+    }
+    let h = b.atan2(a).to_degrees();
+    if h < 0.0 { h + 360.0 } else { h }
+}
+
+/// Returns the squared hue difference between the given colors, given their
+/// chroma difference.
+fn delta_hue_squared(r: Lab, s: Lab, dc: f32) -> f32 {
+    let da = r.a - s.a;
+    let db = r.b - s.b;
+    (da * da + db * db - dc * dc).max(0.0)
+}
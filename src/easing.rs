@@ -0,0 +1,215 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Easing functions for interpolation amounts.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::utility::clamped;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+// Standard library imports.
+use std::f32::consts::FRAC_PI_2;
+use std::f32::consts::PI;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Easing
+////////////////////////////////////////////////////////////////////////////////
+/// A timing function which maps an interpolation amount between 0 and 1 to an
+/// eased amount, changing the rate at which an interpolation progresses.
+///
+/// Every easing maps 0 to 0 and 1 to 1. The cubic Bézier easings match the
+/// [CSS `cubic-bezier()`] timing function, and the `EASE` constants match the
+/// CSS keywords of the same names.
+///
+/// [CSS `cubic-bezier()`]: https://www.w3.org/TR/css-easing-1/#cubic-bezier-easing-functions
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Easing {
+    /// The amount is unchanged.
+    #[default]
+    Linear,
+    /// The amount follows the smoothstep curve, `3t² - 2t³`.
+    Smoothstep,
+    /// The amount starts slowly and accelerates quadratically.
+    QuadIn,
+    /// The amount starts quickly and decelerates quadratically.
+    QuadOut,
+    /// The amount accelerates quadratically, then decelerates.
+    QuadInOut,
+    /// The amount starts slowly and accelerates cubically.
+    CubicIn,
+    /// The amount starts quickly and decelerates cubically.
+    CubicOut,
+    /// The amount accelerates cubically, then decelerates.
+    CubicInOut,
+    /// The amount follows the first quarter of a sine wave, starting slowly.
+    SineIn,
+    /// The amount follows the first quarter of a sine wave, ending slowly.
+    SineOut,
+    /// The amount follows half of a sine wave, starting and ending slowly.
+    SineInOut,
+    /// The amount follows a cubic Bézier curve from `(0, 0)` to `(1, 1)`
+    /// with the control points `(x1, y1)` and `(x2, y2)`, given in that
+    /// order. The x coordinates are clamped between 0 and 1. The y
+    /// coordinates are not, so the eased amount may overshoot.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// The CSS `ease` timing function.
+    pub const EASE: Easing = Easing::CubicBezier(0.25, 0.1, 0.25, 1.0);
+    /// The CSS `ease-in` timing function.
+    pub const EASE_IN: Easing = Easing::CubicBezier(0.42, 0.0, 1.0, 1.0);
+    /// The CSS `ease-out` timing function.
+    pub const EASE_OUT: Easing = Easing::CubicBezier(0.0, 0.0, 0.58, 1.0);
+    /// The CSS `ease-in-out` timing function.
+    pub const EASE_IN_OUT: Easing = Easing::CubicBezier(0.42, 0.0, 0.58, 1.0);
+
+    /// Returns the eased amount for the given interpolation amount, which is
+    /// clamped between 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Easing, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Easing::QuadIn.apply(0.5), 0.25);
+    /// assert_eq!(Easing::EASE_IN_OUT.apply(0.25), 0.12916192);
+    ///
+    /// let color_a = Color::new(Rgb {r: 0, g: 0, b: 0});
+    /// let color_b = Color::new(Rgb {r: 200, g: 100, b: 40});
+    /// let amount = Easing::EASE.apply(0.5);
+    ///
+    /// assert_eq!(Color::rgb_linear_interpolate(color_a, color_b, amount),
+    ///     Color::new(Rgb::new(160, 80, 32)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn apply(&self, amount: f32) -> f32 {
+        let t = clamped(amount, 0.0, 1.0);
+        match *self {
+            Easing::Linear     => t,
+            Easing::Smoothstep => t * t * (3.0 - 2.0 * t),
+            Easing::QuadIn     => t * t,
+            Easing::QuadOut    => t * (2.0 - t),
+            Easing::QuadInOut  => if t < 0.5 {
+                2.0 * t * t
+            } else {
+                1.0 - (2.0 - 2.0 * t).powi(2) / 2.0
+            },
+            Easing::CubicIn    => t * t * t,
+            Easing::CubicOut   => 1.0 - (1.0 - t).powi(3),
+            Easing::CubicInOut => if t < 0.5 {
+                4.0 * t * t * t
+            } else {
+                1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+            },
+            Easing::SineIn     => 1.0 - (t * FRAC_PI_2).cos(),
+            Easing::SineOut    => (t * FRAC_PI_2).sin(),
+            Easing::SineInOut  => (1.0 - (t * PI).cos()) / 2.0,
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(
+                clamped(x1, 0.0, 1.0),
+                y1,
+                clamped(x2, 0.0, 1.0),
+                y2,
+                t),
+        }
+    }
+
+    /// Returns the easing which progresses through the same amounts when the
+    /// interpolation runs backwards, so that `e.reversed().apply(t)` equals
+    /// `1.0 - e.apply(1.0 - t)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Easing;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Easing::CubicIn.reversed(), Easing::CubicOut);
+    /// assert_eq!(Easing::CubicBezier(0.25, 0.0, 0.75, 0.5).reversed(),
+    ///     Easing::CubicBezier(0.25, 0.5, 0.75, 1.0));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn reversed(&self) -> Self {
+        match *self {
+            Easing::QuadIn   => Easing::QuadOut,
+            Easing::QuadOut  => Easing::QuadIn,
+            Easing::CubicIn  => Easing::CubicOut,
+            Easing::CubicOut => Easing::CubicIn,
+            Easing::SineIn   => Easing::SineOut,
+            Easing::SineOut  => Easing::SineIn,
+            Easing::CubicBezier(x1, y1, x2, y2)
+                => Easing::CubicBezier(1.0 - x2, 1.0 - y2, 1.0 - x1, 1.0 - y1),
+            other => other,
+        }
+    }
+}
+
+/// Evaluates the cubic Bézier timing function with the given control points
+/// at `x`, by solving for the curve parameter whose x coordinate is `x`.
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    // Polynomial coefficients of each coordinate.
+    let cx = 3.0 * x1;
+    let bx = 3.0 * (x2 - x1) - cx;
+    let ax = 1.0 - cx - bx;
+    let cy = 3.0 * y1;
+    let by = 3.0 * (y2 - y1) - cy;
+    let ay = 1.0 - cy - by;
+
+    let curve_x = |t: f32| ((ax * t + bx) * t + cx) * t;
+    let curve_y = |t: f32| ((ay * t + by) * t + cy) * t;
+    let slope_x = |t: f32| (3.0 * ax * t + 2.0 * bx) * t + cx;
+
+    const EPSILON: f32 = 1e-6;
+
+    // Newton's method converges quickly unless the slope is near zero.
+    let mut t = x;
+    for _ in 0..8 {
+        let error = curve_x(t) - x;
+        if error.abs() < EPSILON { return curve_y(t); }
+        let slope = slope_x(t);
+        if slope.abs() < EPSILON { break; }
+        t -= error / slope;
+    }
+
+    // Fall back to bisection, which always converges because x(t) is
+    // monotone when the control point x coordinates are in [0, 1].
+    let (mut low, mut high) = (0.0, 1.0);
+    t = x;
+    for _ in 0..32 {
+        let value = curve_x(t);
+        if (value - x).abs() < EPSILON { break; }
+        if value < x { low = t; } else { high = t; }
+        t = (low + high) / 2.0;
+    }
+    curve_y(t)
+}
@@ -0,0 +1,235 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Error types.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Standard library imports.
+use std::error::Error;
+use std::fmt;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ParseColorError
+////////////////////////////////////////////////////////////////////////////////
+/// An error which can be returned while parsing a color from a hex code or a
+/// CSS color string.
+///
+/// The error records the byte offset and text of the offending token, along
+/// with the reason it was rejected.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::{ Color, ParseColorErrorReason };
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let error = Color::from_hex_code("#7FFG40").unwrap_err();
+///
+/// assert_eq!(error.position(), 4);
+/// assert_eq!(error.token(), "G");
+/// assert_eq!(error.reason(), ParseColorErrorReason::InvalidDigit);
+/// assert_eq!(error.to_string(), "invalid hex digit `G` at byte 4");
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    /// The byte offset of the offending token.
+    position: usize,
+    /// The text of the offending token.
+    token: String,
+    /// The reason the token was rejected.
+    reason: ParseColorErrorReason,
+}
+
+impl ParseColorError {
+    /// Constructs a new `ParseColorError`.
+    pub(in crate) fn new(
+        position: usize,
+        token: &str,
+        reason: ParseColorErrorReason)
+        -> Self
+    {
+        ParseColorError {
+            position,
+            token: token.to_owned(),
+            reason,
+        }
+    }
+
+    /// Returns the error with its position moved forward by the given number
+    /// of bytes. Used when the error was produced by parsing a substring.
+    pub(in crate) fn offset_by(mut self, offset: usize) -> Self {
+        self.position += offset;
+        self
+    }
+
+    /// Returns the byte offset of the offending token.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Color;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let error = Color::from_hex_code("#12345").unwrap_err();
+    ///
+    /// assert_eq!(error.position(), 0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the text of the offending token. The token is empty if the
+    /// input ended unexpectedly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::Color;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let error = Color::from_hex_code("#12345").unwrap_err();
+    ///
+    /// assert_eq!(error.token(), "#12345");
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns the reason the token was rejected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, ParseColorErrorReason };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let error = Color::from_hex_code("#12345").unwrap_err();
+    ///
+    /// assert_eq!(error.reason(), ParseColorErrorReason::WrongLength);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn reason(&self) -> ParseColorErrorReason {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        if self.token.is_empty() {
+            write!(f, "{} at byte {}", self.reason, self.position)
+        } else {
+            write!(f, "{} `{}` at byte {}",
+                self.reason,
+                self.token,
+                self.position)
+        }
+    }
+}
+
+impl Error for ParseColorError {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ParseColorErrorReason
+////////////////////////////////////////////////////////////////////////////////
+/// The reason a [`ParseColorError`] was returned.
+///
+/// [`ParseColorError`]: struct.ParseColorError.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseColorErrorReason {
+    /// A hex code contains a character which is not a hex digit.
+    InvalidDigit,
+    /// A hex code does not have 3, 4, 6, or 8 digits.
+    WrongLength,
+    /// A color includes an alpha component, but the color type being parsed
+    /// cannot store one.
+    AlphaNotSupported,
+    /// A hex code does not start with `#`.
+    MissingHash,
+    /// A color function name is not recognized.
+    UnknownFunction,
+    /// A color name is not recognized.
+    UnknownName,
+    /// The `currentcolor` keyword was used, which refers to a color from the
+    /// surrounding context and cannot be parsed on its own.
+    CurrentColor,
+    /// A color space name within a `color()` function is not recognized.
+    UnknownColorSpace,
+    /// A numeric value is too large to be represented.
+    OutOfRange,
+    /// A dimension has a unit which is not recognized.
+    InvalidUnit,
+    /// A component has the wrong type, such as a percentage where an angle is
+    /// expected, or is not allowed in the syntax used.
+    InvalidComponent,
+    /// A color function has the wrong number of components.
+    WrongComponentCount,
+    /// A character or word appears where it is not expected.
+    UnexpectedToken,
+    /// The input ended before the color was complete.
+    UnexpectedEnd,
+}
+
+impl fmt::Display for ParseColorErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        use ParseColorErrorReason::*;
+        let msg = match self {
+            InvalidDigit        => "invalid hex digit",
+            WrongLength         => "hex code must have 3, 4, 6, or 8 digits",
+            AlphaNotSupported   => "color has an alpha component, which \
+                this color type cannot store",
+            MissingHash         => "hex code must start with '#'",
+            UnknownFunction     => "unknown color function",
+            UnknownName         => "unknown color name",
+            CurrentColor        => "`currentcolor` depends on context and \
+                cannot be parsed",
+            UnknownColorSpace   => "unknown color space",
+            OutOfRange          => "value out of range",
+            InvalidUnit         => "invalid unit",
+            InvalidComponent    => "invalid component",
+            WrongComponentCount => "wrong number of components",
+            UnexpectedToken     => "unexpected token",
+            UnexpectedEnd       => "unexpected end of input",
+        };
+        write!(f, "{}", msg)
+    }
+}
@@ -0,0 +1,640 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Multi-stop color gradients.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Color;
+use crate::Easing;
+use crate::HueInterpolation;
+use crate::InterpolationMode;
+use crate::InterpolationSpace;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use tracing::Level;
+use tracing::span;
+
+// Standard library imports.
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Gradient
+////////////////////////////////////////////////////////////////////////////////
+/// A color gradient defined by color stops at ordered positions.
+///
+/// Between stops, colors are interpolated in the gradient's
+/// [`InterpolationSpace`] using its [`InterpolationMode`], and hues in
+/// cylindrical spaces are interpolated using its [`HueInterpolation`]. The
+/// gradient's [`Easing`] is applied to the position between each pair of
+/// neighboring stops. Before the first stop and after the last, the gradient
+/// has the color of the nearest stop. Stops may share a position, which
+/// produces a hard edge between colors.
+///
+/// [`InterpolationSpace`]: enum.InterpolationSpace.html
+/// [`InterpolationMode`]: enum.InterpolationMode.html
+/// [`HueInterpolation`]: enum.HueInterpolation.html
+/// [`Easing`]: enum.Easing.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::{ Color, Gradient, InterpolationSpace, Rgb };
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let gradient = Gradient::new(
+///         Rgb {r: 0, g: 0, b: 255},
+///         Rgb {r: 255, g: 255, b: 0})
+///     .with_stop(0.5, Rgb {r: 255, g: 0, b: 0})
+///     .with_space(InterpolationSpace::LinearRgb);
+///
+/// assert_eq!(gradient.sample(0.25), Color::new(Rgb {r: 188, g: 0, b: 188}));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "GradientData"))]
+pub struct Gradient {
+    /// The color stops, ordered by position.
+    stops: Vec<(f32, Color)>,
+    /// The color space used for interpolation.
+    space: InterpolationSpace,
+    /// The interpolation mode.
+    mode: InterpolationMode,
+    /// The direction in which hues are interpolated.
+    hue_interpolation: HueInterpolation,
+    /// The easing applied between neighboring stops.
+    easing: Easing,
+}
+
+impl Gradient {
+    /// Constructs a new `Gradient` from the `start` color at position 0 to the
+    /// `end` color at position 1, using the default interpolation settings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Gradient, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let gradient = Gradient::new(
+    ///     Rgb {r: 0, g: 0, b: 0},
+    ///     Rgb {r: 255, g: 255, b: 255});
+    ///
+    /// assert_eq!(gradient.stops().len(), 2);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn new<C, D>(start: C, end: D) -> Self
+        where
+            C: Into<Color> + Sized,
+            D: Into<Color> + Sized,
+    {
+        Gradient {
+            stops: vec![(0.0, start.into()), (1.0, end.into())],
+            space: InterpolationSpace::default(),
+            mode: InterpolationMode::default(),
+            hue_interpolation: HueInterpolation::default(),
+            easing: Easing::default(),
+        }
+    }
+
+    /// Constructs a new `Gradient` from the given `(position, color)` stops,
+    /// using the default interpolation settings. The stops are sorted
+    /// by position, keeping stops with equal positions in their given order.
+    /// Returns `None` if there are no stops, or if any position is not
+    /// finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Gradient, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let red = Color::new(Rgb {r: 255, g: 0, b: 0});
+    /// let blue = Color::new(Rgb {r: 0, g: 0, b: 255});
+    ///
+    /// let gradient = Gradient::from_stops(vec![(100.0, blue), (0.0, red)])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(gradient.stops(), &[(0.0, red), (100.0, blue)]);
+    /// assert_eq!(Gradient::from_stops(vec![]), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn from_stops<I>(stops: I) -> Option<Self>
+        where I: IntoIterator<Item=(f32, Color)>
+    {
+        let mut stops: Vec<_> = stops.into_iter().collect();
+        if stops.is_empty() || stops.iter().any(|(p, _)| !p.is_finite()) {
+            return None;
+        }
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0)
+            .expect("finite stop positions"));
+
+        Some(Gradient {
+            stops,
+            space: InterpolationSpace::default(),
+            mode: InterpolationMode::default(),
+            hue_interpolation: HueInterpolation::default(),
+            easing: Easing::default(),
+        })
+    }
+
+    /// Returns the gradient with a color stop added at the given position.
+    /// The new stop is placed after any existing stops at the same position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Gradient, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let black = Color::new(Rgb {r: 0, g: 0, b: 0});
+    /// let white = Color::new(Rgb {r: 255, g: 255, b: 255});
+    /// let red = Color::new(Rgb {r: 255, g: 0, b: 0});
+    ///
+    /// let gradient = Gradient::new(black, white).with_stop(0.5, red);
+    ///
+    /// assert_eq!(gradient.stops(), &[(0.0, black), (0.5, red), (1.0, white)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn with_stop<C>(mut self, position: f32, color: C) -> Self
+        where C: Into<Color>
+    {
+        assert!(position.is_finite());
+        let index = self.stops.partition_point(|(p, _)| *p <= position);
+        self.stops.insert(index, (position, color.into()));
+        self
+    }
+
+    /// Returns the gradient with the given interpolation space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Gradient, InterpolationSpace, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let gradient = Gradient::new(
+    ///         Rgb {r: 0, g: 0, b: 0},
+    ///         Rgb {r: 255, g: 255, b: 255})
+    ///     .with_space(InterpolationSpace::Lab);
+    ///
+    /// assert_eq!(gradient.space(), InterpolationSpace::Lab);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn with_space(mut self, space: InterpolationSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Returns the gradient with the given interpolation mode.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Gradient, InterpolationMode, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let gradient = Gradient::new(
+    ///         Rgb {r: 0, g: 0, b: 0},
+    ///         Rgb {r: 255, g: 255, b: 255})
+    ///     .with_mode(InterpolationMode::Cubic);
+    ///
+    /// assert_eq!(gradient.mode(), InterpolationMode::Cubic);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn with_mode(mut self, mode: InterpolationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the gradient with the given hue interpolation, which is used
+    /// when the interpolation space is cylindrical.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Gradient, HueInterpolation, InterpolationSpace };
+    /// # use color::Rgb;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let red = Color::new(Rgb {r: 255, g: 0, b: 0});
+    /// let blue = Color::new(Rgb {r: 0, g: 0, b: 255});
+    ///
+    /// let gradient = Gradient::new(red, blue)
+    ///     .with_space(InterpolationSpace::Hsv)
+    ///     .with_hue_interpolation(HueInterpolation::Increasing);
+    ///
+    /// assert_eq!(gradient.sample(0.5), Color::new(Rgb {r: 0, g: 255, b: 0}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn with_hue_interpolation(mut self, hue_interpolation: HueInterpolation)
+        -> Self
+    {
+        self.hue_interpolation = hue_interpolation;
+        self
+    }
+
+    /// Returns the gradient with the given easing, which is applied to the
+    /// position between each pair of neighboring stops.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Easing, Gradient, InterpolationSpace, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let black = Color::new(Rgb {r: 0, g: 0, b: 0});
+    /// let white = Color::new(Rgb {r: 255, g: 255, b: 255});
+    ///
+    /// let gradient = Gradient::new(black, white)
+    ///     .with_space(InterpolationSpace::Rgb)
+    ///     .with_easing(Easing::QuadIn);
+    ///
+    /// assert_eq!(gradient.sample(0.5), Color::new(Rgb {r: 63, g: 63, b: 63}));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the color stops of the gradient, ordered by position.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Gradient, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let black = Color::new(Rgb {r: 0, g: 0, b: 0});
+    /// let white = Color::new(Rgb {r: 255, g: 255, b: 255});
+    ///
+    /// let gradient = Gradient::new(black, white);
+    ///
+    /// assert_eq!(gradient.stops(), &[(0.0, black), (1.0, white)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// Returns the interpolation space of the gradient.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Gradient, InterpolationSpace, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let gradient = Gradient::new(
+    ///     Rgb {r: 0, g: 0, b: 0},
+    ///     Rgb {r: 255, g: 255, b: 255});
+    ///
+    /// assert_eq!(gradient.space(), InterpolationSpace::Oklab);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn space(&self) -> InterpolationSpace {
+        self.space
+    }
+
+    /// Returns the interpolation mode of the gradient.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Gradient, InterpolationMode, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let gradient = Gradient::new(
+    ///     Rgb {r: 0, g: 0, b: 0},
+    ///     Rgb {r: 255, g: 255, b: 255});
+    ///
+    /// assert_eq!(gradient.mode(), InterpolationMode::Linear);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn mode(&self) -> InterpolationMode {
+        self.mode
+    }
+
+    /// Returns the hue interpolation of the gradient.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Gradient, HueInterpolation, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let gradient = Gradient::new(
+    ///     Rgb {r: 0, g: 0, b: 0},
+    ///     Rgb {r: 255, g: 255, b: 255});
+    ///
+    /// assert_eq!(gradient.hue_interpolation(), HueInterpolation::Shorter);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn hue_interpolation(&self) -> HueInterpolation {
+        self.hue_interpolation
+    }
+
+    /// Returns the easing of the gradient.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Easing, Gradient, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let gradient = Gradient::new(
+    ///     Rgb {r: 0, g: 0, b: 0},
+    ///     Rgb {r: 255, g: 255, b: 255});
+    ///
+    /// assert_eq!(gradient.easing(), Easing::Linear);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Returns the color of the gradient at the given position. Positions
+    /// outside of the stops have the color of the nearest stop, and a
+    /// position shared by several stops has the color of the last of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Gradient, InterpolationSpace, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let black = Color::new(Rgb {r: 0, g: 0, b: 0});
+    /// let white = Color::new(Rgb {r: 255, g: 255, b: 255});
+    ///
+    /// let gradient = Gradient::new(black, white)
+    ///     .with_space(InterpolationSpace::Rgb);
+    ///
+    /// assert_eq!(gradient.sample(0.5), Color::new(Rgb {r: 127, g: 127, b: 127}));
+    /// assert_eq!(gradient.sample(-1.0), black);
+    /// assert_eq!(gradient.sample(2.0), white);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn sample(&self, position: f32) -> Color {
+        let span = span!(Level::DEBUG, "Gradient::sample");
+        let _enter = span.enter();
+
+        let (first, last) = (self.stops[0], self.stops[self.stops.len() - 1]);
+        if position.is_nan() || position < first.0 {
+            return first.1;
+        }
+        if position >= last.0 {
+            return last.1;
+        }
+
+        let index = self.stops.partition_point(|(p, _)| *p <= position);
+        let (start, end) = (self.stops[index - 1], self.stops[index]);
+        let amount = self.easing.apply((position - start.0) / (end.0 - start.0));
+        self.space.interpolate(
+            start.1,
+            end.1,
+            self.mode,
+            self.hue_interpolation,
+            amount)
+    }
+
+    /// Returns an iterator over `count` colors sampled at evenly spaced
+    /// positions from the first stop to the last, inclusive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Gradient, InterpolationSpace, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let black = Color::new(Rgb {r: 0, g: 0, b: 0});
+    /// let white = Color::new(Rgb {r: 255, g: 255, b: 255});
+    ///
+    /// let gradient = Gradient::new(black, white)
+    ///     .with_space(InterpolationSpace::Rgb);
+    /// let colors: Vec<_> = gradient.take(3).collect();
+    ///
+    /// assert_eq!(colors, vec![black, gradient.sample(0.5), white]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn take(&self, count: usize) -> impl Iterator<Item=Color> + '_ {
+        let start = self.stops[0].0;
+        let end = self.stops[self.stops.len() - 1].0;
+        let step = if count > 1 {
+            (end - start) / (count - 1) as f32
+        } else {
+            0.0
+        };
+        (0..count).map(move |i| {
+            let position = if i + 1 == count && count > 1 {
+                end
+            } else {
+                start + step * i as f32
+            };
+            self.sample(position)
+        })
+    }
+
+    /// Returns the gradient reversed, with the stops mirrored so that the
+    /// gradient covers the same range of positions. An increasing or
+    /// decreasing hue interpolation is swapped, and the easing is reversed,
+    /// so that the reversed gradient passes through the same colors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ Color, Gradient, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let black = Color::new(Rgb {r: 0, g: 0, b: 0});
+    /// let white = Color::new(Rgb {r: 255, g: 255, b: 255});
+    /// let red = Color::new(Rgb {r: 255, g: 0, b: 0});
+    ///
+    /// let gradient = Gradient::new(black, white).with_stop(0.25, red);
+    ///
+    /// assert_eq!(gradient.reversed().stops(),
+    ///     &[(0.0, white), (0.75, red), (1.0, black)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn reversed(&self) -> Self {
+        let start = self.stops[0].0;
+        let end = self.stops[self.stops.len() - 1].0;
+        Gradient {
+            stops: self.stops
+                .iter()
+                .rev()
+                .map(|&(p, color)| (start + end - p, color))
+                .collect(),
+            space: self.space,
+            mode: self.mode,
+            hue_interpolation: self.hue_interpolation.reversed(),
+            easing: self.easing.reversed(),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// GradientData
+////////////////////////////////////////////////////////////////////////////////
+/// The deserialized fields of a `Gradient`, which are validated by
+/// `Gradient::from_stops` before a `Gradient` is constructed.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct GradientData {
+    /// The color stops, in any order.
+    stops: Vec<(f32, Color)>,
+    /// The color space used for interpolation.
+    space: InterpolationSpace,
+    /// The interpolation mode.
+    mode: InterpolationMode,
+    /// The direction in which hues are interpolated.
+    hue_interpolation: HueInterpolation,
+    /// The easing applied between neighboring stops.
+    easing: Easing,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<GradientData> for Gradient {
+    type Error = &'static str;
+
+    fn try_from(data: GradientData) -> Result<Self, Self::Error> {
+        let gradient = Gradient::from_stops(data.stops)
+            .ok_or("gradient stops must be non-empty with finite positions")?;
+        Ok(gradient
+            .with_space(data.space)
+            .with_mode(data.mode)
+            .with_hue_interpolation(data.hue_interpolation)
+            .with_easing(data.easing))
+    }
+}
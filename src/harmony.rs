@@ -0,0 +1,121 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Hue wheels for generating color harmonies.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Hsv;
+use crate::Lch;
+use crate::Rgb;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// HueWheel
+////////////////////////////////////////////////////////////////////////////////
+/// A color wheel used to rotate hues when generating color harmonies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HueWheel {
+    /// The [`Hsv`] hue wheel. Rotation preserves the saturation and value of
+    /// the color, but not its perceived lightness, so harmonies of saturated
+    /// colors can appear unbalanced.
+    ///
+    /// [`Hsv`]: struct.Hsv.html
+    Hsv,
+    /// The [`Lch`] hue wheel. Rotation preserves the perceived lightness and
+    /// chroma of the color, though colors with a high chroma may fall outside
+    /// of the RGB gamut, in which case the resulting components are clipped.
+    ///
+    /// [`Lch`]: struct.Lch.html
+    #[default]
+    Lch,
+    /// The traditional red-yellow-blue artist's wheel, on which red, yellow,
+    /// and blue are evenly spaced, and complementary pairs match those used
+    /// in painting, such as red and green, or blue and orange. Rotation
+    /// preserves the [`Hsv`] saturation and value of the color.
+    ///
+    /// [`Hsv`]: struct.Hsv.html
+    Ryb,
+}
+
+impl HueWheel {
+    /// Returns the color with its hue on this wheel rotated by the given
+    /// number of degrees.
+    pub(in crate) fn rotate(&self, rgb: Rgb, degrees: f32) -> Rgb {
+        match self {
+            HueWheel::Hsv => {
+                let hsv = Hsv::from(rgb);
+                let h = (hsv.h + degrees).rem_euclid(360.0);
+                Rgb::from(Hsv { h, ..hsv })
+            },
+            HueWheel::Lch => {
+                let lch = Lch::from(rgb);
+                let h = (lch.h + degrees).rem_euclid(360.0);
+                Rgb::from(Lch { h, ..lch })
+            },
+            HueWheel::Ryb => {
+                let hsv = Hsv::from(rgb);
+                let ryb = rgb_hue_to_ryb(hsv.h.rem_euclid(360.0));
+                let h = ryb_hue_to_rgb((ryb + degrees).rem_euclid(360.0));
+                Rgb::from(Hsv { h, ..hsv })
+            },
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RYB hue mapping
+////////////////////////////////////////////////////////////////////////////////
+/// Corresponding hues on the RYB and RGB wheels, in degrees. RYB hues between
+/// these are mapped by linear interpolation.
+const RYB_TO_RGB_HUES: [(f32, f32); 7] = [
+    (0.0, 0.0),     // Red.
+    (60.0, 30.0),   // Orange.
+    (120.0, 60.0),  // Yellow.
+    (180.0, 120.0), // Green.
+    (240.0, 240.0), // Blue.
+    (300.0, 280.0), // Violet.
+    (360.0, 360.0),
+];
+
+/// Converts a hue in the range `[0, 360)` on the RYB wheel to the RGB wheel.
+fn ryb_hue_to_rgb(hue: f32) -> f32 {
+    map_hue(hue, |pair| pair)
+}
+
+/// Converts a hue in the range `[0, 360)` on the RGB wheel to the RYB wheel.
+fn rgb_hue_to_ryb(hue: f32) -> f32 {
+    map_hue(hue, |(ryb, rgb)| (rgb, ryb))
+}
+
+/// Maps a hue through the piecewise linear function given by the
+/// `RYB_TO_RGB_HUES` table, with the `(from, to)` columns selected by
+/// `columns`.
+fn map_hue<F>(hue: f32, columns: F) -> f32
+    where F: Fn((f32, f32)) -> (f32, f32)
+{
+    for pair in RYB_TO_RGB_HUES.windows(2) {
+        let (from_start, to_start) = columns(pair[0]);
+        let (from_end, to_end) = columns(pair[1]);
+        if hue <= from_end {
+            let amount = (hue - from_start) / (from_end - from_start);
+            return to_start + (to_end - to_start) * amount;
+        }
+    }
+    hue
+}
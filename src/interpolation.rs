@@ -0,0 +1,547 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Color space and mode selection for interpolation.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Color;
+use crate::Hsl;
+use crate::Hsv;
+use crate::Lab;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::Oklab;
+use crate::Oklch;
+use crate::Rgb;
+use crate::utility::clamped;
+use crate::utility::lerp_f32;
+use crate::utility::normalize_hue;
+
+// External library imports.
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// InterpolationSpace
+////////////////////////////////////////////////////////////////////////////////
+/// The color space in which colors are interpolated.
+///
+/// Interpolating in different spaces gives noticeably different results.
+/// [`Rgb`] matches the behavior of many older tools but darkens the midpoints
+/// between saturated colors, [`LinearRgb`] matches the physical mixing of
+/// light, and [`Oklab`] gives the most perceptually even transitions. The
+/// cylindrical spaces interpolate hue around the color wheel.
+///
+/// [`Rgb`]: struct.Rgb.html
+/// [`LinearRgb`]: struct.LinearRgb.html
+/// [`Oklab`]: struct.Oklab.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InterpolationSpace {
+    /// Gamma-encoded sRGB.
+    Rgb,
+    /// Linear-light sRGB.
+    LinearRgb,
+    /// The HSL cylindrical form of sRGB.
+    Hsl,
+    /// The HSV cylindrical form of sRGB.
+    Hsv,
+    /// CIE L\*a\*b\*.
+    Lab,
+    /// Oklab.
+    #[default]
+    Oklab,
+    /// The LCh cylindrical form of CIE L\*a\*b\*.
+    Lch,
+    /// The LCh cylindrical form of Oklab.
+    Oklch,
+}
+
+impl InterpolationSpace {
+    /// Interpolates between the given colors in this color space, returning
+    /// the color located at the ratio given by `amount`, which is clamped
+    /// between 0 and 1. Hues are interpolated in the direction given by
+    /// `hue_interpolation`, and the alpha component is interpolated linearly.
+    pub(in crate) fn interpolate(
+        &self,
+        start: Color,
+        end: Color,
+        mode: InterpolationMode,
+        hue_interpolation: HueInterpolation,
+        amount: f32)
+        -> Color
+    {
+        let hue = hue_interpolation;
+        let rgb = match self {
+            InterpolationSpace::Rgb => interpolate_in(
+                start, end, mode, amount,
+                Rgb::linear_interpolate,
+                Rgb::cubic_interpolate),
+            InterpolationSpace::LinearRgb => interpolate_in(
+                start, end, mode, amount,
+                LinearRgb::linear_interpolate,
+                LinearRgb::cubic_interpolate),
+            InterpolationSpace::Hsl => interpolate_in(
+                start, end, mode, amount,
+                |s, e, a| Hsl::linear_interpolate(s, e, a, hue),
+                |s, e, ss, es, a| Hsl::cubic_interpolate(s, e, ss, es, a, hue)),
+            InterpolationSpace::Hsv => interpolate_in(
+                start, end, mode, amount,
+                |s, e, a| Hsv::linear_interpolate(s, e, a, hue),
+                |s, e, ss, es, a| Hsv::cubic_interpolate(s, e, ss, es, a, hue)),
+            InterpolationSpace::Lab => interpolate_in(
+                start, end, mode, amount,
+                Lab::linear_interpolate,
+                Lab::cubic_interpolate),
+            InterpolationSpace::Oklab => interpolate_in(
+                start, end, mode, amount,
+                Oklab::linear_interpolate,
+                Oklab::cubic_interpolate),
+            InterpolationSpace::Lch => interpolate_in(
+                start, end, mode, amount,
+                |s, e, a| Lch::linear_interpolate(s, e, a, hue),
+                |s, e, ss, es, a| Lch::cubic_interpolate(s, e, ss, es, a, hue)),
+            InterpolationSpace::Oklch => interpolate_in(
+                start, end, mode, amount,
+                |s, e, a| Oklch::linear_interpolate(s, e, a, hue),
+                |s, e, ss, es, a| Oklch::cubic_interpolate(s, e, ss, es, a, hue)),
+        };
+
+        let alpha = lerp_f32(
+            f32::from(start.alpha()),
+            f32::from(end.alpha()),
+            amount);
+        Color::new(rgb).with_alpha(alpha.round() as u8)
+    }
+
+    /// Interpolates along a spline of the given kind through the given
+    /// colors in this color space, returning the color located at the ratio
+    /// given by `amount`, which is clamped between 0 and 1. The colors are
+    /// spaced evenly, and hues take the shorter way between neighboring
+    /// colors.
+    pub(in crate) fn spline(
+        &self,
+        colors: &[Color],
+        kind: SplineKind,
+        amount: f32)
+        -> Color
+    {
+        // Hue channels are given as in `premultiplied_interpolate`.
+        let rgb = match self {
+            InterpolationSpace::Rgb => spline_in(
+                colors, kind, amount, None,
+                Rgb::ratios,
+                rgb_from_ratios),
+            InterpolationSpace::LinearRgb => spline_in(
+                colors, kind, amount, None,
+                LinearRgb::components,
+                |c| Rgb::from(LinearRgb::from(c))),
+            InterpolationSpace::Hsl => spline_in(
+                colors, kind, amount, Some((0, 1, f32::EPSILON)),
+                Hsl::components,
+                |c| Rgb::from(Hsl::from(c))),
+            InterpolationSpace::Hsv => spline_in(
+                colors, kind, amount, Some((0, 1, f32::EPSILON)),
+                Hsv::components,
+                |c| Rgb::from(Hsv::from(c))),
+            InterpolationSpace::Lab => spline_in(
+                colors, kind, amount, None,
+                Lab::components,
+                |c| Rgb::from(Lab::from(c))),
+            InterpolationSpace::Oklab => spline_in(
+                colors, kind, amount, None,
+                Oklab::components,
+                |c| Rgb::from(Oklab::from(c))),
+            InterpolationSpace::Lch => spline_in(
+                colors, kind, amount, Some((2, 1, 0.0015)),
+                Lch::components,
+                |c| Rgb::from(Lch::from(c))),
+            InterpolationSpace::Oklch => spline_in(
+                colors, kind, amount, Some((2, 1, 0.000004)),
+                Oklch::components,
+                |c| Rgb::from(Oklch::from(c))),
+        };
+
+        let alphas: Vec<f32> = colors.iter()
+            .map(|c| f32::from(c.alpha()))
+            .collect();
+        let alpha = clamped(kind.interpolate(&alphas, amount), 0.0, 255.0);
+        Color::new(rgb).with_alpha(alpha.round() as u8)
+    }
+
+    /// Interpolates between the given colors in this color space with
+    /// premultiplied alpha, as in CSS, returning the color located at the
+    /// ratio given by `amount` together with its alpha between 0 and 1. Hues
+    /// are interpolated in the direction given by `hue_interpolation`, and
+    /// the hue of an achromatic color is replaced by the hue of the other
+    /// color.
+    pub(in crate) fn premultiplied_interpolate(
+        &self,
+        start: Color,
+        end: Color,
+        hue_interpolation: HueInterpolation,
+        amount: f32)
+        -> (Rgb, f32)
+    {
+        let hue = hue_interpolation;
+        let amount = clamped(amount, 0.0, 1.0);
+
+        // Hue channels are given as (hue index, chroma index, threshold), where
+        // the hue is powerless if the chroma is below the threshold.
+        match self {
+            InterpolationSpace::Rgb => premultiplied_in(
+                start, end, amount, hue, None,
+                Rgb::ratios,
+                rgb_from_ratios),
+            InterpolationSpace::LinearRgb => premultiplied_in(
+                start, end, amount, hue, None,
+                LinearRgb::components,
+                |c| Rgb::from(LinearRgb::from(c))),
+            InterpolationSpace::Hsl => premultiplied_in(
+                start, end, amount, hue, Some((0, 1, f32::EPSILON)),
+                Hsl::components,
+                |c| Rgb::from(Hsl::from(c))),
+            InterpolationSpace::Hsv => premultiplied_in(
+                start, end, amount, hue, Some((0, 1, f32::EPSILON)),
+                Hsv::components,
+                |c| Rgb::from(Hsv::from(c))),
+            InterpolationSpace::Lab => premultiplied_in(
+                start, end, amount, hue, None,
+                Lab::components,
+                |c| Rgb::from(Lab::from(c))),
+            InterpolationSpace::Oklab => premultiplied_in(
+                start, end, amount, hue, None,
+                Oklab::components,
+                |c| Rgb::from(Oklab::from(c))),
+            InterpolationSpace::Lch => premultiplied_in(
+                start, end, amount, hue, Some((2, 1, 0.0015)),
+                Lch::components,
+                |c| Rgb::from(Lch::from(c))),
+            InterpolationSpace::Oklch => premultiplied_in(
+                start, end, amount, hue, Some((2, 1, 0.000004)),
+                Oklch::components,
+                |c| Rgb::from(Oklch::from(c))),
+        }
+    }
+}
+
+/// Interpolates between the given colors in the color space `S`, using the
+/// given linear and cubic interpolation functions of that space.
+fn interpolate_in<S, L, K>(
+    start: Color,
+    end: Color,
+    mode: InterpolationMode,
+    amount: f32,
+    linear: L,
+    cubic: K)
+    -> Rgb
+    where
+        S: From<Color>,
+        Rgb: From<S>,
+        L: Fn(S, S, f32) -> S,
+        K: Fn(S, S, f32, f32, f32) -> S,
+{
+    let (s, e) = (S::from(start), S::from(end));
+    Rgb::from(match mode {
+        InterpolationMode::Linear => linear(s, e, amount),
+        InterpolationMode::Cubic  => cubic(s, e, 0.0, 0.0, amount),
+    })
+}
+
+/// Interpolates between the given colors with premultiplied alpha in the
+/// color space `S`, whose components are given by the `components` function
+/// and converted back by the `to_rgb` function. If `hue_channel` is given, it
+/// holds the index of the hue component, the index of the chroma component,
+/// and the chroma below which the hue is powerless.
+fn premultiplied_in<S, F, G>(
+    start: Color,
+    end: Color,
+    amount: f32,
+    hue_interpolation: HueInterpolation,
+    hue_channel: Option<(usize, usize, f32)>,
+    components: F,
+    to_rgb: G)
+    -> (Rgb, f32)
+    where
+        S: From<Color>,
+        F: Fn(&S) -> [f32; 3],
+        G: Fn([f32; 3]) -> Rgb,
+{
+    let mut s = components(&S::from(start));
+    let mut e = components(&S::from(end));
+    let start_alpha = f32::from(start.alpha()) / 255.0;
+    let end_alpha = f32::from(end.alpha()) / 255.0;
+    let alpha = start_alpha + (end_alpha - start_alpha) * amount;
+
+    let hue_index = hue_channel.map(|(hue, chroma, threshold)| {
+        let s_powerless = s[chroma].abs() < threshold;
+        let e_powerless = e[chroma].abs() < threshold;
+        if s_powerless && !e_powerless { s[hue] = e[hue]; }
+        if e_powerless && !s_powerless { e[hue] = s[hue]; }
+        hue
+    });
+
+    let mut result = [0.0; 3];
+    for (i, component) in result.iter_mut().enumerate() {
+        *component = if hue_index == Some(i) {
+            normalize_hue(s[i] + hue_interpolation.delta(s[i], e[i]) * amount)
+        } else if alpha > 0.0 {
+            let (s_pre, e_pre) = (s[i] * start_alpha, e[i] * end_alpha);
+            (s_pre + (e_pre - s_pre) * amount) / alpha
+        } else {
+            s[i] + (e[i] - s[i]) * amount
+        };
+    }
+    (to_rgb(result), alpha)
+}
+
+/// Interpolates along a spline through the given colors in the color space
+/// `S`, whose components are given by the `components` function and
+/// converted back by the `to_rgb` function. If `hue_channel` is given, it
+/// holds the index of the hue component, the index of the chroma component,
+/// and the chroma below which the hue is powerless. Powerless hues are
+/// replaced by the hue of the nearest color with a meaningful hue, and the
+/// hues are unwrapped before interpolating.
+fn spline_in<S, F, G>(
+    colors: &[Color],
+    kind: SplineKind,
+    amount: f32,
+    hue_channel: Option<(usize, usize, f32)>,
+    components: F,
+    to_rgb: G)
+    -> Rgb
+    where
+        S: From<Color>,
+        F: Fn(&S) -> [f32; 3],
+        G: Fn([f32; 3]) -> Rgb,
+{
+    let mut points: Vec<[f32; 3]> = colors.iter()
+        .map(|&c| components(&S::from(c)))
+        .collect();
+
+    let hue_index = hue_channel.map(|(hue, chroma, threshold)| {
+        let powerless: Vec<bool> = points.iter()
+            .map(|p| p[chroma].abs() < threshold)
+            .collect();
+        for j in 0..points.len() {
+            if !powerless[j] { continue; }
+            // Search outward, preferring the earlier color on ties.
+            let neighbor = (1..points.len()).find_map(|d| {
+                let before = j.checked_sub(d).filter(|&k| !powerless[k]);
+                let after = Some(j + d)
+                    .filter(|&k| k < points.len() && !powerless[k]);
+                before.or(after)
+            });
+            if let Some(k) = neighbor {
+                points[j][hue] = points[k][hue];
+            }
+        }
+        hue
+    });
+
+    let mut result = [0.0; 3];
+    for (i, component) in result.iter_mut().enumerate() {
+        let mut values: Vec<f32> = points.iter().map(|p| p[i]).collect();
+        if hue_index == Some(i) {
+            for j in 1..values.len() {
+                values[j] = values[j - 1]
+                    + HueInterpolation::Shorter.delta(values[j - 1], values[j]);
+            }
+            *component = normalize_hue(kind.interpolate(&values, amount));
+        } else {
+            *component = kind.interpolate(&values, amount);
+        }
+    }
+    to_rgb(result)
+}
+
+/// Converts sRGB component ratios into an `Rgb` color, rounding each
+/// component to the nearest octet.
+fn rgb_from_ratios([r, g, b]: [f32; 3]) -> Rgb {
+    Rgb::new(
+        (255.0 * clamped(r, 0.0, 1.0)).round() as u8,
+        (255.0 * clamped(g, 0.0, 1.0)).round() as u8,
+        (255.0 * clamped(b, 0.0, 1.0)).round() as u8)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// InterpolationMode
+////////////////////////////////////////////////////////////////////////////////
+/// The curve used to interpolate between two colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InterpolationMode {
+    /// Components change at a constant rate.
+    #[default]
+    Linear,
+    /// Components follow a cubic curve with zero slope at each end, easing
+    /// into and out of each color.
+    Cubic,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SplineKind
+////////////////////////////////////////////////////////////////////////////////
+/// The kind of spline used to interpolate through several colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SplineKind {
+    /// A Catmull-Rom spline, which passes through every color.
+    #[default]
+    CatmullRom,
+    /// A uniform cubic B-spline, which passes through the first and last
+    /// colors and is pulled toward the others. This gives the smoothest
+    /// curve, but does not reach the inner colors exactly.
+    BSpline,
+    /// A monotone cubic spline using the Fritsch-Carlson method, which passes
+    /// through every color and never overshoots between them.
+    Monotone,
+}
+
+impl SplineKind {
+    /// Interpolates along a spline of this kind through the given evenly
+    /// spaced values, returning the value located at the ratio given by
+    /// `amount`, which is clamped between 0 and 1.
+    pub(in crate) fn interpolate(&self, values: &[f32], amount: f32) -> f32 {
+        let n = values.len();
+        if n < 2 { return values[0]; }
+
+        let segments = (n - 1) as f32;
+        let amount = if amount.is_nan() { 0.0 } else { clamped(amount, 0.0, 1.0) };
+        let i = ((amount * segments).floor() as usize).min(n - 2);
+        let t = amount * segments - i as f32;
+
+        let (p1, p2) = (values[i], values[i + 1]);
+        // Reflect the end values to extend the spline past the ends.
+        let p0 = if i > 0 { values[i - 1] } else { 2.0 * p1 - p2 };
+        let p3 = if i + 2 < n { values[i + 2] } else { 2.0 * p2 - p1 };
+
+        match self {
+            SplineKind::CatmullRom
+                => hermite(p1, p2, (p2 - p0) / 2.0, (p3 - p1) / 2.0, t),
+            SplineKind::BSpline => {
+                let (t2, t3) = (t * t, t * t * t);
+                ((1.0 - 3.0 * t + 3.0 * t2 - t3) * p0
+                    + (4.0 - 6.0 * t2 + 3.0 * t3) * p1
+                    + (1.0 + 3.0 * t + 3.0 * t2 - 3.0 * t3) * p2
+                    + t3 * p3) / 6.0
+            },
+            SplineKind::Monotone => {
+                let slopes = monotone_slopes(values);
+                hermite(p1, p2, slopes[i], slopes[i + 1], t)
+            },
+        }
+    }
+}
+
+/// Evaluates the cubic Hermite curve between the given values with the given
+/// slopes at the ratio `t`.
+fn hermite(start: f32, end: f32, start_slope: f32, end_slope: f32, t: f32)
+    -> f32
+{
+    let (t2, t3) = (t * t, t * t * t);
+    (2.0 * t3 - 3.0 * t2 + 1.0) * start
+        + (t3 - 2.0 * t2 + t) * start_slope
+        + (-2.0 * t3 + 3.0 * t2) * end
+        + (t3 - t2) * end_slope
+}
+
+/// Returns the slopes at each of the given evenly spaced values which keep a
+/// Hermite spline through them monotone, using the Fritsch-Carlson method.
+fn monotone_slopes(values: &[f32]) -> Vec<f32> {
+    let secants: Vec<f32> = values.windows(2).map(|w| w[1] - w[0]).collect();
+
+    let mut slopes = Vec::with_capacity(values.len());
+    slopes.push(secants[0]);
+    for w in secants.windows(2) {
+        if w[0] * w[1] <= 0.0 {
+            slopes.push(0.0);
+        } else {
+            slopes.push((w[0] + w[1]) / 2.0);
+        }
+    }
+    slopes.push(secants[secants.len() - 1]);
+
+    for (i, &secant) in secants.iter().enumerate() {
+        if secant == 0.0 {
+            slopes[i] = 0.0;
+            slopes[i + 1] = 0.0;
+            continue;
+        }
+        let alpha = slopes[i] / secant;
+        let beta = slopes[i + 1] / secant;
+        let magnitude = alpha * alpha + beta * beta;
+        if magnitude > 9.0 {
+            let tau = 3.0 / magnitude.sqrt();
+            slopes[i] = tau * alpha * secant;
+            slopes[i + 1] = tau * beta * secant;
+        }
+    }
+    slopes
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// HueInterpolation
+////////////////////////////////////////////////////////////////////////////////
+/// The direction taken around the hue circle when interpolating hues, as in
+/// [CSS Color 4].
+///
+/// [CSS Color 4]: https://www.w3.org/TR/css-color-4/#hue-interpolation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HueInterpolation {
+    /// The hue takes the shorter way around the circle, which is at most 180
+    /// degrees.
+    #[default]
+    Shorter,
+    /// The hue takes the longer way around the circle, which is at least 180
+    /// degrees. Identical hues go all the way around.
+    Longer,
+    /// The hue always increases, wrapping from 360 to 0 degrees if needed.
+    Increasing,
+    /// The hue always decreases, wrapping from 0 to 360 degrees if needed.
+    Decreasing,
+}
+
+impl HueInterpolation {
+    /// Returns the signed difference in degrees from the `start` hue to the
+    /// `end` hue when going around the hue circle in this direction.
+    pub(in crate) fn delta(&self, start: f32, end: f32) -> f32 {
+        let delta = normalize_hue(end) - normalize_hue(start);
+        match self {
+            HueInterpolation::Shorter if delta > 180.0   => delta - 360.0,
+            HueInterpolation::Shorter if delta < -180.0  => delta + 360.0,
+            HueInterpolation::Longer if delta > 0.0 && delta < 180.0
+                => delta - 360.0,
+            HueInterpolation::Longer if delta > -180.0 && delta <= 0.0
+                => delta + 360.0,
+            HueInterpolation::Increasing if delta < 0.0 => delta + 360.0,
+            HueInterpolation::Decreasing if delta > 0.0 => delta - 360.0,
+            _ => delta,
+        }
+    }
+
+    /// Returns the hue interpolation which traverses the same hues when the
+    /// start and end are swapped.
+    pub(in crate) fn reversed(&self) -> Self {
+        match self {
+            HueInterpolation::Increasing => HueInterpolation::Decreasing,
+            HueInterpolation::Decreasing => HueInterpolation::Increasing,
+            other                        => *other,
+        }
+    }
+}
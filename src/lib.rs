@@ -13,6 +13,8 @@
 //! | ------- | ----------- |
 //! | "serde" | Enables serialization and deserialization of data using [serde](https://crates.io/crates/serde). |
 //! | "parse" | Enables FromStr implementations for colors. |
+//! | "x11" | Enables the X11 `rgb.txt` color name dictionary. |
+//! | "xkcd" | Enables the xkcd color survey name dictionary. |
 //!
 //! Only the "parse" feature is enabled by default.
 ////////////////////////////////////////////////////////////////////////////////
@@ -49,9 +51,20 @@
 
 
 // Internal modules.
+pub mod contrast;
+pub mod cvd;
+pub mod delta_e;
 pub mod utility;
+mod blend;
 mod color_space;
 mod color;
+mod composite;
+mod easing;
+mod error;
+mod gradient;
+mod harmony;
+mod interpolation;
+mod names;
 #[cfg(feature = "parse")]
 mod parse;
 
@@ -59,12 +72,33 @@ mod parse;
 mod test;
 
 // Exports
+pub use color_space::alpha::Alpha;
+pub use color_space::alpha::Hsla;
+pub use color_space::alpha::Hsva;
+pub use color_space::alpha::Rgba;
 pub use color_space::cmyk::Cmyk;
 pub use color_space::hsl::Hsl;
 pub use color_space::hsv::Hsv;
+pub use color_space::lab::Lab;
+pub use color_space::lch::Lch;
+pub use color_space::linear_rgb::LinearRgb;
+pub use color_space::oklab::Oklab;
+pub use color_space::oklch::Oklch;
 pub use color_space::rgb::Rgb;
 pub use color_space::xyz::Xyz;
+pub use crate::blend::BlendMode;
 pub use crate::color::Color;
+pub use crate::composite::CompositeOperator;
+pub use crate::easing::Easing;
+pub use crate::error::ParseColorError;
+pub use crate::error::ParseColorErrorReason;
+pub use crate::gradient::Gradient;
+pub use crate::harmony::HueWheel;
+pub use crate::interpolation::HueInterpolation;
+pub use crate::interpolation::InterpolationMode;
+pub use crate::interpolation::InterpolationSpace;
+pub use crate::interpolation::SplineKind;
+pub use crate::names::ColorNameDictionary;
 
 
 
@@ -0,0 +1,760 @@
+// Copyright 2020 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Named colors and color name dictionaries.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Oklab;
+use crate::ParseColorError;
+use crate::ParseColorErrorReason;
+use crate::Rgb;
+
+// External library imports.
+use tracing::Level;
+use tracing::span;
+
+// Standard library imports.
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CSS named colors
+////////////////////////////////////////////////////////////////////////////////
+/// The 148 named colors from the [CSS Color Module Level 4] specification,
+/// sorted by name.
+///
+/// Several colors have more than one name, such as `aqua` and `cyan`, or
+/// `gray` and `grey`.
+///
+/// [CSS Color Module Level 4]: https://www.w3.org/TR/css-color-4/#named-colors
+pub(in crate) const CSS_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xF0F8FF),
+    ("antiquewhite", 0xFAEBD7),
+    ("aqua", 0x00FFFF),
+    ("aquamarine", 0x7FFFD4),
+    ("azure", 0xF0FFFF),
+    ("beige", 0xF5F5DC),
+    ("bisque", 0xFFE4C4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xFFEBCD),
+    ("blue", 0x0000FF),
+    ("blueviolet", 0x8A2BE2),
+    ("brown", 0xA52A2A),
+    ("burlywood", 0xDEB887),
+    ("cadetblue", 0x5F9EA0),
+    ("chartreuse", 0x7FFF00),
+    ("chocolate", 0xD2691E),
+    ("coral", 0xFF7F50),
+    ("cornflowerblue", 0x6495ED),
+    ("cornsilk", 0xFFF8DC),
+    ("crimson", 0xDC143C),
+    ("cyan", 0x00FFFF),
+    ("darkblue", 0x00008B),
+    ("darkcyan", 0x008B8B),
+    ("darkgoldenrod", 0xB8860B),
+    ("darkgray", 0xA9A9A9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xA9A9A9),
+    ("darkkhaki", 0xBDB76B),
+    ("darkmagenta", 0x8B008B),
+    ("darkolivegreen", 0x556B2F),
+    ("darkorange", 0xFF8C00),
+    ("darkorchid", 0x9932CC),
+    ("darkred", 0x8B0000),
+    ("darksalmon", 0xE9967A),
+    ("darkseagreen", 0x8FBC8F),
+    ("darkslateblue", 0x483D8B),
+    ("darkslategray", 0x2F4F4F),
+    ("darkslategrey", 0x2F4F4F),
+    ("darkturquoise", 0x00CED1),
+    ("darkviolet", 0x9400D3),
+    ("deeppink", 0xFF1493),
+    ("deepskyblue", 0x00BFFF),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1E90FF),
+    ("firebrick", 0xB22222),
+    ("floralwhite", 0xFFFAF0),
+    ("forestgreen", 0x228B22),
+    ("fuchsia", 0xFF00FF),
+    ("gainsboro", 0xDCDCDC),
+    ("ghostwhite", 0xF8F8FF),
+    ("gold", 0xFFD700),
+    ("goldenrod", 0xDAA520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xADFF2F),
+    ("grey", 0x808080),
+    ("honeydew", 0xF0FFF0),
+    ("hotpink", 0xFF69B4),
+    ("indianred", 0xCD5C5C),
+    ("indigo", 0x4B0082),
+    ("ivory", 0xFFFFF0),
+    ("khaki", 0xF0E68C),
+    ("lavender", 0xE6E6FA),
+    ("lavenderblush", 0xFFF0F5),
+    ("lawngreen", 0x7CFC00),
+    ("lemonchiffon", 0xFFFACD),
+    ("lightblue", 0xADD8E6),
+    ("lightcoral", 0xF08080),
+    ("lightcyan", 0xE0FFFF),
+    ("lightgoldenrodyellow", 0xFAFAD2),
+    ("lightgray", 0xD3D3D3),
+    ("lightgreen", 0x90EE90),
+    ("lightgrey", 0xD3D3D3),
+    ("lightpink", 0xFFB6C1),
+    ("lightsalmon", 0xFFA07A),
+    ("lightseagreen", 0x20B2AA),
+    ("lightskyblue", 0x87CEFA),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xB0C4DE),
+    ("lightyellow", 0xFFFFE0),
+    ("lime", 0x00FF00),
+    ("limegreen", 0x32CD32),
+    ("linen", 0xFAF0E6),
+    ("magenta", 0xFF00FF),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66CDAA),
+    ("mediumblue", 0x0000CD),
+    ("mediumorchid", 0xBA55D3),
+    ("mediumpurple", 0x9370DB),
+    ("mediumseagreen", 0x3CB371),
+    ("mediumslateblue", 0x7B68EE),
+    ("mediumspringgreen", 0x00FA9A),
+    ("mediumturquoise", 0x48D1CC),
+    ("mediumvioletred", 0xC71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xF5FFFA),
+    ("mistyrose", 0xFFE4E1),
+    ("moccasin", 0xFFE4B5),
+    ("navajowhite", 0xFFDEAD),
+    ("navy", 0x000080),
+    ("oldlace", 0xFDF5E6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6B8E23),
+    ("orange", 0xFFA500),
+    ("orangered", 0xFF4500),
+    ("orchid", 0xDA70D6),
+    ("palegoldenrod", 0xEEE8AA),
+    ("palegreen", 0x98FB98),
+    ("paleturquoise", 0xAFEEEE),
+    ("palevioletred", 0xDB7093),
+    ("papayawhip", 0xFFEFD5),
+    ("peachpuff", 0xFFDAB9),
+    ("peru", 0xCD853F),
+    ("pink", 0xFFC0CB),
+    ("plum", 0xDDA0DD),
+    ("powderblue", 0xB0E0E6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xFF0000),
+    ("rosybrown", 0xBC8F8F),
+    ("royalblue", 0x4169E1),
+    ("saddlebrown", 0x8B4513),
+    ("salmon", 0xFA8072),
+    ("sandybrown", 0xF4A460),
+    ("seagreen", 0x2E8B57),
+    ("seashell", 0xFFF5EE),
+    ("sienna", 0xA0522D),
+    ("silver", 0xC0C0C0),
+    ("skyblue", 0x87CEEB),
+    ("slateblue", 0x6A5ACD),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xFFFAFA),
+    ("springgreen", 0x00FF7F),
+    ("steelblue", 0x4682B4),
+    ("tan", 0xD2B48C),
+    ("teal", 0x008080),
+    ("thistle", 0xD8BFD8),
+    ("tomato", 0xFF6347),
+    ("turquoise", 0x40E0D0),
+    ("violet", 0xEE82EE),
+    ("wheat", 0xF5DEB3),
+    ("white", 0xFFFFFF),
+    ("whitesmoke", 0xF5F5F5),
+    ("yellow", 0xFFFF00),
+    ("yellowgreen", 0x9ACD32),
+];
+
+/// Returns the RGB value of the CSS named color with the given name, ignoring
+/// ASCII case.
+pub(in crate) fn css_color(name: &str) -> Option<Rgb> {
+    CSS_COLORS
+        .binary_search_by(|&(entry, _)| cmp_ignore_ascii_case(entry, name))
+        .ok()
+        .map(|i| Rgb::from(CSS_COLORS[i].1))
+}
+
+/// Returns the name of the CSS named color with the given RGB value. If the
+/// color has more than one name, the alphabetically first is returned.
+pub(in crate) fn css_name(rgb: Rgb) -> Option<&'static str> {
+    let hex = rgb.hex();
+    CSS_COLORS.iter()
+        .find(|&&(_, value)| value == hex)
+        .map(|&(name, _)| name)
+}
+
+/// Returns the name of the CSS named color which is perceptually nearest to
+/// the given RGB value, as measured by distance in the Oklab color space.
+pub(in crate) fn nearest_css_name(rgb: Rgb) -> &'static str {
+    let target = Oklab::from(rgb);
+    let mut nearest = CSS_COLORS[0].0;
+    let mut nearest_distance = f32::INFINITY;
+    for &(name, value) in CSS_COLORS.iter() {
+        let distance = Oklab::distance(target, Rgb::from(value));
+        if distance < nearest_distance {
+            nearest = name;
+            nearest_distance = distance;
+        }
+    }
+    nearest
+}
+
+/// Compares two strings, ignoring ASCII case.
+fn cmp_ignore_ascii_case(a: &str, b: &str) -> Ordering {
+    a.bytes().map(|c| c.to_ascii_lowercase())
+        .cmp(b.bytes().map(|c| c.to_ascii_lowercase()))
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ColorNameDictionary
+////////////////////////////////////////////////////////////////////////////////
+/// A dictionary of color names, supporting lookup by name, by exact color, and
+/// by perceptually nearest color.
+///
+/// Names are matched ignoring ASCII case, and nearest-name queries measure
+/// distance in the [`Oklab`] color space.
+///
+/// [`Oklab`]: struct.Oklab.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::{ ColorNameDictionary, Rgb };
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let brand = ColorNameDictionary::from_pairs(vec![
+///     ("Brand Orange", Rgb::new(0xF2, 0x6B, 0x1D)),
+///     ("Brand Navy", Rgb::new(0x1B, 0x2A, 0x4A)),
+/// ]);
+///
+/// assert_eq!(brand.get("brand navy"), Some(Rgb::new(0x1B, 0x2A, 0x4A)));
+/// assert_eq!(brand.nearest(Rgb::new(255, 100, 0)), Some("Brand Orange"));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ColorNameDictionary {
+    /// The names and colors, in insertion order.
+    entries: Vec<(String, Rgb)>,
+    /// The index of each entry, keyed by its lowercase name.
+    names: HashMap<String, usize>,
+    /// The Oklab coordinates and index of each entry, sorted by lightness.
+    by_lightness: Vec<(Oklab, usize)>,
+}
+
+impl ColorNameDictionary {
+    /// Constructs a new empty `ColorNameDictionary`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::ColorNameDictionary;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::new();
+    ///
+    /// assert!(dictionary.is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        ColorNameDictionary::default()
+    }
+
+    /// Constructs a new `ColorNameDictionary` from the given names and colors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ ColorNameDictionary, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::from_pairs(vec![
+    ///     ("Sky", Rgb::new(0x87, 0xCE, 0xEB)),
+    ///     ("Grass", Rgb::new(0x4C, 0x9A, 0x2A)),
+    /// ]);
+    ///
+    /// assert_eq!(dictionary.len(), 2);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn from_pairs<I, S>(pairs: I) -> Self
+        where
+            I: IntoIterator<Item=(S, Rgb)>,
+            S: Into<String>,
+    {
+        let mut dictionary = ColorNameDictionary::new();
+        for (name, rgb) in pairs {
+            dictionary.insert(name, rgb);
+        }
+        dictionary
+    }
+
+    /// Constructs a new `ColorNameDictionary` by parsing the given text.
+    ///
+    /// Each line of the text holds one entry, in either of two forms:
+    ///
+    /// + `R G B name`, with decimal components, as in the X11 `rgb.txt` file.
+    /// + `name #RRGGBB`, as in the xkcd color survey `rgb.txt` file.
+    ///
+    /// Blank lines and lines starting with `!` or `#` are ignored. Any
+    /// [`ParseColorError`] returned gives the byte offset within the whole
+    /// text.
+    ///
+    /// [`ParseColorError`]: struct.ParseColorError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ ColorNameDictionary, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::from_text("
+    ///     ! Brand colors.
+    ///     242 107  29   Brand Orange
+    ///     Brand Navy    #1B2A4A
+    /// ")?;
+    ///
+    /// assert_eq!(dictionary.get("Brand Orange"), Some(Rgb::new(242, 107, 29)));
+    /// assert_eq!(dictionary.get("Brand Navy"), Some(Rgb::new(27, 42, 74)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn from_text(text: &str) -> Result<Self, ParseColorError> {
+        let span = span!(Level::DEBUG, "ColorNameDictionary::from_text");
+        let _enter = span.enter();
+
+        let mut dictionary = ColorNameDictionary::new();
+        let mut line_start = 0;
+        for line in text.split('\n') {
+            let position = line_start;
+            line_start += line.len() + 1;
+
+            let entry = line.trim();
+            if entry.is_empty() || entry.starts_with('!') || entry.starts_with('#') {
+                continue;
+            }
+            let offset = position + (line.len() - line.trim_start().len());
+            let (name, rgb) = parse_entry(entry)
+                .map_err(|e| e.offset_by(offset))?;
+            dictionary.insert(name, rgb);
+        }
+        Ok(dictionary)
+    }
+
+    /// Returns a `ColorNameDictionary` containing the 148 [CSS named colors].
+    ///
+    /// [CSS named colors]: https://www.w3.org/TR/css-color-4/#named-colors
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ ColorNameDictionary, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::css();
+    ///
+    /// assert_eq!(dictionary.get("teal"), Some(Rgb::new(0, 128, 128)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn css() -> Self {
+        ColorNameDictionary::from_pairs(CSS_COLORS.iter()
+            .map(|&(name, hex)| (name, Rgb::from(hex))))
+    }
+
+    /// Returns a `ColorNameDictionary` containing the X11 `rgb.txt` colors.
+    ///
+    /// Several X11 colors share a name with a CSS color, but not its value;
+    /// for example, X11 `gray` is `#BEBEBE`, while CSS `gray` is `#808080`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ ColorNameDictionary, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::x11();
+    ///
+    /// assert_eq!(dictionary.get("gray"), Some(Rgb::new(190, 190, 190)));
+    /// assert_eq!(dictionary.get("MediumSeaGreen"), Some(Rgb::new(60, 179, 113)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    #[cfg(feature = "x11")]
+    pub fn x11() -> Self {
+        ColorNameDictionary::from_text(include_str!("data/x11_rgb.txt"))
+            .expect("parse X11 color names")
+    }
+
+    /// Returns a `ColorNameDictionary` containing the 949 colors named in the
+    /// [xkcd color survey].
+    ///
+    /// [xkcd color survey]: https://xkcd.com/color/rgb/
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ ColorNameDictionary, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::xkcd();
+    ///
+    /// assert_eq!(dictionary.len(), 949);
+    /// assert_eq!(dictionary.get("cloudy blue"), Some(Rgb::new(172, 194, 217)));
+    /// assert_eq!(dictionary.get("Purple"), Some(Rgb::new(126, 30, 156)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    #[cfg(feature = "xkcd")]
+    pub fn xkcd() -> Self {
+        ColorNameDictionary::from_text(include_str!("data/xkcd_rgb.txt"))
+            .expect("parse xkcd color names")
+    }
+
+    /// Returns the number of names in the dictionary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::ColorNameDictionary;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::css();
+    ///
+    /// assert_eq!(dictionary.len(), 148);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the dictionary contains no names.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::ColorNameDictionary;
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::css();
+    ///
+    /// assert!(!dictionary.is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts a name into the dictionary. If the name is already present,
+    /// ignoring ASCII case, its color is replaced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ ColorNameDictionary, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut dictionary = ColorNameDictionary::new();
+    /// dictionary.insert("Accent", Rgb::new(255, 0, 0));
+    /// dictionary.insert("accent", Rgb::new(0, 0, 255));
+    ///
+    /// assert_eq!(dictionary.len(), 1);
+    /// assert_eq!(dictionary.get("Accent"), Some(Rgb::new(0, 0, 255)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn insert<S>(&mut self, name: S, rgb: Rgb) where S: Into<String> {
+        let name = name.into();
+        let key = name.to_ascii_lowercase();
+        let index = match self.names.get(&key) {
+            Some(&index) => {
+                self.entries[index].1 = rgb;
+                self.by_lightness.retain(|&(_, i)| i != index);
+                index
+            },
+            None => {
+                let index = self.entries.len();
+                self.entries.push((name, rgb));
+                let _ = self.names.insert(key, index);
+                index
+            },
+        };
+
+        let oklab = Oklab::from(rgb);
+        let pos = self.by_lightness
+            .partition_point(|&(entry, _)| entry.l <= oklab.l);
+        self.by_lightness.insert(pos, (oklab, index));
+    }
+
+    /// Returns the color with the given name, ignoring ASCII case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ ColorNameDictionary, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::css();
+    ///
+    /// assert_eq!(dictionary.get("Navy"), Some(Rgb::new(0, 0, 128)));
+    /// assert_eq!(dictionary.get("bluish"), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, name: &str) -> Option<Rgb> {
+        self.names.get(&name.to_ascii_lowercase())
+            .map(|&index| self.entries[index].1)
+    }
+
+    /// Returns the first inserted name for the given color, if the color is
+    /// in the dictionary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ ColorNameDictionary, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::css();
+    ///
+    /// assert_eq!(dictionary.name_of(Rgb::new(255, 0, 255)), Some("fuchsia"));
+    /// assert_eq!(dictionary.name_of(Rgb::new(254, 0, 255)), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn name_of(&self, rgb: Rgb) -> Option<&str> {
+        self.entries.iter()
+            .find(|&&(_, entry)| entry == rgb)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the name of the color in the dictionary which is perceptually
+    /// nearest to the given color, or `None` if the dictionary is empty. If
+    /// several colors are equally near, the first inserted is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ ColorNameDictionary, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::css();
+    ///
+    /// assert_eq!(dictionary.nearest(Rgb::new(250, 10, 20)), Some("red"));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn nearest<C>(&self, color: C) -> Option<&str> where C: Into<Oklab> {
+        let span = span!(Level::DEBUG, "ColorNameDictionary::nearest");
+        let _enter = span.enter();
+
+        let target = color.into();
+        let mut nearest: Option<(f32, usize)> = None;
+
+        // Search outward from the target lightness. Lightness difference is a
+        // lower bound on distance, so each search stops once it exceeds the
+        // nearest distance found so far.
+        let start = self.by_lightness
+            .partition_point(|&(entry, _)| entry.l < target.l);
+        search_nearest(target, self.by_lightness[start..].iter(), &mut nearest);
+        search_nearest(target, self.by_lightness[..start].iter().rev(), &mut nearest);
+
+        nearest.map(|(_, index)| self.entries[index].0.as_str())
+    }
+
+    /// Returns an iterator over the names and colors in the dictionary, in
+    /// insertion order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use color::{ ColorNameDictionary, Rgb };
+    /// # fn example() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let dictionary = ColorNameDictionary::css();
+    ///
+    /// assert_eq!(dictionary.iter().next(),
+    ///     Some(("aliceblue", Rgb::new(0xF0, 0xF8, 0xFF))));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     example().unwrap();
+    /// # }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item=(&str, Rgb)> + '_ {
+        self.entries.iter().map(|(name, rgb)| (name.as_str(), *rgb))
+    }
+}
+
+/// Updates `nearest` with the distance and index of the entry nearest to
+/// `target`, stopping once the entries' lightness is too far from the target
+/// to improve on it.
+fn search_nearest<'a, I>(
+    target: Oklab,
+    entries: I,
+    nearest: &mut Option<(f32, usize)>)
+    where I: Iterator<Item=&'a (Oklab, usize)>
+{
+    for &(oklab, index) in entries {
+        let distance = Oklab::distance(target, oklab);
+        match *nearest {
+            Some((d, _)) if (oklab.l - target.l).abs() > d => break,
+            Some((d, i)) if distance > d || (distance == d && index > i) => (),
+            _ => *nearest = Some((distance, index)),
+        }
+    }
+}
+
+/// Parses a single trimmed dictionary entry.
+fn parse_entry(entry: &str) -> Result<(&str, Rgb), ParseColorError> {
+    let last = entry.split_whitespace().last().unwrap_or("");
+    if last.starts_with('#') {
+        // A `name #RRGGBB` entry.
+        let position = entry.len() - last.len();
+        let rgb = Rgb::from_hex_code(last)
+            .map_err(|e| e.offset_by(position))?;
+        // The entry does not start with `#`, so the name is not empty.
+        return Ok((entry[..position].trim(), rgb));
+    }
+
+    // An `R G B name` entry.
+    let mut rest = entry;
+    let mut components = [0u8; 3];
+    for component in components.iter_mut() {
+        let trimmed = rest.trim_start();
+        let position = entry.len() - trimmed.len();
+        let token = trimmed.split_whitespace().next().unwrap_or("");
+        if token.is_empty() {
+            return Err(ParseColorError::new(
+                position,
+                "",
+                ParseColorErrorReason::UnexpectedEnd));
+        }
+        if !token.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseColorError::new(
+                position,
+                token,
+                ParseColorErrorReason::UnexpectedToken));
+        }
+        *component = token.parse().map_err(|_| ParseColorError::new(
+            position,
+            token,
+            ParseColorErrorReason::OutOfRange))?;
+        rest = &trimmed[token.len()..];
+    }
+    let name = rest.trim();
+    if name.is_empty() {
+        return Err(ParseColorError::new(
+            entry.len(),
+            "",
+            ParseColorErrorReason::UnexpectedEnd));
+    }
+    Ok((name, Rgb::from(components)))
+}
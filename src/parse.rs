@@ -9,4 +9,782 @@
 //!
 //! Parsing functions for color values.
 //!
+//! Colors are parsed using the [CSS Color Module Level 4] syntax. The
+//! following forms are accepted:
+//!
+//! + Hex codes with 3, 4, 6, or 8 digits: `#7f4`, `#7FFF4080`.
+//! + The CSS named colors, such as `rebeccapurple`, and `transparent`.
+//! + `rgb()` and `rgba()`, with numbers or percentages.
+//! + `hsl()`, `hsla()`, and `hwb()`.
+//! + `lab()`, `lch()`, `oklab()`, and `oklch()`.
+//! + `color()` with the `srgb`, `srgb-linear`, `xyz`, and `xyz-d65` spaces.
+//!
+//! Both the legacy comma-separated syntax and the modern space-separated
+//! syntax with an optional `/ alpha` are supported, and any component of the
+//! modern syntax may be given as `none`.
+//!
+//! Invalid input is reported with a [`ParseColorError`] giving the byte
+//! offset and text of the offending token. Color types without an alpha
+//! component reject colors which are not fully opaque with
+//! `ParseColorErrorReason::AlphaNotSupported`; parse them as a `Color` or an
+//! `Alpha` color instead.
+//!
+//! [CSS Color Module Level 4]: https://www.w3.org/TR/css-color-4/
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::Alpha;
+use crate::Cmyk;
+use crate::Color;
+use crate::Hsl;
+use crate::Hsv;
+use crate::Lab;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::names;
+use crate::Oklab;
+use crate::Oklch;
+use crate::ParseColorError;
+use crate::ParseColorErrorReason;
+use crate::Rgb;
+use crate::utility::clamped;
+use crate::utility::normalize_hue;
+use crate::utility::srgb_to_linear;
+use crate::Xyz;
+
+// External library imports.
+use tracing::Level;
+use tracing::span;
+
+// Standard library imports.
+use std::str::FromStr;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FromStr implementations
+////////////////////////////////////////////////////////////////////////////////
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let span = span!(Level::DEBUG, "Color::from_str");
+        let _enter = span.enter();
+
+        let parsed = parse_color(text)?;
+        let alpha = parsed.alpha;
+        let mut color: Color = parsed.convert();
+        color.set_alpha_ratio(alpha);
+        Ok(color)
+    }
+}
+
+impl<C> FromStr for Alpha<C>
+    where C: From<Hsl> + From<Hsv> + From<Lab> + From<Lch> + From<LinearRgb>
+        + From<Oklab> + From<Oklch> + From<Xyz>
+{
+    type Err = ParseColorError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let span = span!(Level::DEBUG, "Alpha::from_str");
+        let _enter = span.enter();
+
+        let parsed = parse_color(text)?;
+        let alpha = parsed.alpha;
+        Ok(Alpha::new(parsed.convert(), alpha))
+    }
+}
+
+macro_rules! impl_from_str {
+    ($($t:ident),*) => {
+        $(
+            /// Parses a CSS color. Colors which are not fully opaque are
+            /// rejected with `ParseColorErrorReason::AlphaNotSupported`, as
+            /// the alpha component cannot be stored.
+            impl FromStr for $t {
+                type Err = ParseColorError;
+
+                fn from_str(text: &str) -> Result<Self, Self::Err> {
+                    let span = span!(Level::DEBUG,
+                        concat!(stringify!($t), "::from_str"));
+                    let _enter = span.enter();
+
+                    let parsed = parse_color(text)?;
+                    if parsed.alpha < 1.0 {
+                        return Err(parsed.alpha_error(text));
+                    }
+                    Ok(parsed.convert())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_str!(Cmyk, Hsl, Hsv, Lab, Lch, LinearRgb, Oklab, Oklch, Rgb, Xyz);
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ParsedColor
+////////////////////////////////////////////////////////////////////////////////
+/// A parsed color in the color space it was specified in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Space {
+    /// Gamma-encoded sRGB component ratios.
+    Srgb([f32; 3]),
+    /// Linear-light sRGB component ratios.
+    LinearSrgb([f32; 3]),
+    /// Hue, saturation ratio, and lightness ratio.
+    Hsl([f32; 3]),
+    /// Hue, whiteness ratio, and blackness ratio.
+    Hwb([f32; 3]),
+    /// CIE lightness, a, and b.
+    Lab([f32; 3]),
+    /// CIE lightness, chroma, and hue.
+    Lch([f32; 3]),
+    /// Oklab lightness, a, and b.
+    Oklab([f32; 3]),
+    /// Oklab lightness, chroma, and hue.
+    Oklch([f32; 3]),
+    /// CIE XYZ components.
+    Xyz([f32; 3]),
+}
+
+/// A parsed color with its alpha component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ParsedColor {
+    space: Space,
+    alpha: f32,
+    /// The byte range of the source text which gave the alpha component.
+    alpha_span: (usize, usize),
+}
+
+impl ParsedColor {
+    /// Returns an error for the alpha component of the color parsed from the
+    /// given text, for color types which cannot store one.
+    fn alpha_error(&self, text: &str) -> ParseColorError {
+        let (start, end) = self.alpha_span;
+        ParseColorError::new(
+            start,
+            &text[start..end],
+            ParseColorErrorReason::AlphaNotSupported)
+    }
+
+    /// Converts the parsed color into the given color type.
+    fn convert<C>(self) -> C
+        where C: From<Hsl> + From<Hsv> + From<Lab> + From<Lch>
+            + From<LinearRgb> + From<Oklab> + From<Oklch> + From<Xyz>
+    {
+        match self.space {
+            Space::Srgb([r, g, b]) => LinearRgb {
+                r: srgb_to_linear(clamped(r, 0.0, 1.0)),
+                g: srgb_to_linear(clamped(g, 0.0, 1.0)),
+                b: srgb_to_linear(clamped(b, 0.0, 1.0)),
+            }.into(),
+            Space::LinearSrgb([r, g, b]) => LinearRgb::new(r, g, b).into(),
+            Space::Hsl([h, s, l]) => Hsl::new(normalize_hue(h), s, l).into(),
+            Space::Hwb([h, w, b]) => {
+                let (w, b) = (clamped(w, 0.0, 1.0), clamped(b, 0.0, 1.0));
+                if w + b >= 1.0 {
+                    let gray = w / (w + b);
+                    Hsv::new(0.0, 0.0, gray).into()
+                } else {
+                    let v = 1.0 - b;
+                    Hsv::new(normalize_hue(h), 1.0 - w / v, v).into()
+                }
+            },
+            Space::Lab([l, a, b]) => Lab::new(l, a, b).into(),
+            Space::Lch([l, c, h]) => Lch::new(l, c, h).into(),
+            Space::Oklab([l, a, b]) => Oklab::new(l, a, b).into(),
+            Space::Oklch([l, c, h]) => Oklch::new(l, c, h).into(),
+            Space::Xyz([x, y, z]) => Xyz {x, y, z}.into(),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Component
+////////////////////////////////////////////////////////////////////////////////
+/// The value of a single component within a color function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ComponentValue {
+    /// A plain number.
+    Number(f32),
+    /// A percentage, stored as given (i.e., `50%` is stored as `50.0`).
+    Percentage(f32),
+    /// An angle, converted to degrees.
+    Angle(f32),
+    /// The `none` keyword.
+    None,
+}
+
+/// A single component within a color function, along with its source text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Component<'a> {
+    /// The component value.
+    value: ComponentValue,
+    /// The byte offset of the component.
+    position: usize,
+    /// The source text of the component.
+    token: &'a str,
+}
+
+impl<'a> Component<'a> {
+    /// Returns an error for the component with the given reason.
+    fn error(&self, reason: ParseColorErrorReason) -> ParseColorError {
+        ParseColorError::new(self.position, self.token, reason)
+    }
+
+    /// Returns true if the component is a percentage.
+    fn is_percentage(&self) -> bool {
+        matches!(self.value, ComponentValue::Percentage(_))
+    }
+
+    /// Returns true if the component is a plain number.
+    fn is_number(&self) -> bool {
+        matches!(self.value, ComponentValue::Number(_))
+    }
+
+    /// Returns the value of a component which accepts numbers or percentages,
+    /// scaling percentages so that `100%` is equal to `percent_scale`.
+    fn number_or_percentage(&self, percent_scale: f32)
+        -> Result<f32, ParseColorError>
+    {
+        match self.value {
+            ComponentValue::Number(v) => Ok(v),
+            ComponentValue::Percentage(v) => {
+                // The scaling may overflow a finite percentage.
+                let v = v / 100.0 * percent_scale;
+                if v.is_finite() {
+                    Ok(v)
+                } else {
+                    Err(self.error(ParseColorErrorReason::OutOfRange))
+                }
+            },
+            ComponentValue::None => Ok(0.0),
+            ComponentValue::Angle(_) => Err(
+                self.error(ParseColorErrorReason::InvalidComponent)),
+        }
+    }
+
+    /// Returns the value of a hue component in degrees.
+    fn hue(&self) -> Result<f32, ParseColorError> {
+        match self.value {
+            ComponentValue::Number(v) | ComponentValue::Angle(v) => Ok(v),
+            ComponentValue::None => Ok(0.0),
+            ComponentValue::Percentage(_) => Err(
+                self.error(ParseColorErrorReason::InvalidComponent)),
+        }
+    }
+
+    /// Returns the value of an alpha component as a ratio.
+    fn alpha(&self) -> Result<f32, ParseColorError> {
+        Ok(clamped(self.number_or_percentage(1.0)?, 0.0, 1.0))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Arguments
+////////////////////////////////////////////////////////////////////////////////
+/// The arguments of a color function.
+#[derive(Debug, Clone, PartialEq)]
+struct Arguments<'a> {
+    /// The color components.
+    values: Vec<Component<'a>>,
+    /// The alpha component, if given.
+    alpha: Option<Component<'a>>,
+    /// The byte offset of the first comma, if the legacy comma-separated
+    /// syntax was used.
+    legacy: Option<usize>,
+    /// The byte offset of the arguments.
+    position: usize,
+    /// The source text of the arguments.
+    token: &'a str,
+}
+
+impl<'a> Arguments<'a> {
+    /// Returns the three color components, checking that the argument count is
+    /// valid for the syntax used.
+    fn components(&self, allow_legacy: bool)
+        -> Result<[Component<'a>; 3], ParseColorError>
+    {
+        if let Some(comma) = self.legacy {
+            if !allow_legacy {
+                return Err(ParseColorError::new(
+                    comma,
+                    ",",
+                    ParseColorErrorReason::UnexpectedToken));
+            }
+            if let Some(none) = self.values.iter()
+                .chain(self.alpha.iter())
+                .find(|c| c.value == ComponentValue::None)
+            {
+                return Err(none.error(ParseColorErrorReason::InvalidComponent));
+            }
+        }
+        match self.values.as_slice() {
+            &[a, b, c] => Ok([a, b, c]),
+            _ => Err(ParseColorError::new(
+                self.position,
+                self.token,
+                ParseColorErrorReason::WrongComponentCount)),
+        }
+    }
+
+    /// Returns the alpha component as a ratio, defaulting to opaque.
+    fn alpha(&self) -> Result<f32, ParseColorError> {
+        self.alpha.map_or(Ok(1.0), |a| a.alpha())
+    }
+
+    /// Returns the byte range of the alpha component, or of the arguments if
+    /// none was given.
+    fn alpha_span(&self) -> (usize, usize) {
+        match self.alpha {
+            Some(a) => (a.position, a.position + a.token.len()),
+            None    => (self.position, self.position + self.token.len()),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Parser
 ////////////////////////////////////////////////////////////////////////////////
+/// Parses a CSS color string.
+fn parse_color(text: &str) -> Result<ParsedColor, ParseColorError> {
+    let mut parser = Parser {text, pos: 0};
+    let _ = parser.skip_whitespace();
+
+    let parsed = if parser.peek() == Some('#') {
+        let start = parser.pos;
+        while parser.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '#') {
+            parser.pos += 1;
+        }
+        let mut parsed = parse_hex(&text[start..parser.pos])
+            .map_err(|e| e.offset_by(start))?;
+        parsed.alpha_span = (start, parser.pos);
+        parsed
+    } else {
+        let start = parser.pos;
+        let name = parser.identifier()?;
+        if parser.peek() == Some('(') {
+            parser.expect('(')?;
+            parse_function(name, start, &mut parser)?
+        } else {
+            parse_name(name, start)?
+        }
+    };
+
+    let _ = parser.skip_whitespace();
+    if parser.pos != text.len() {
+        return Err(ParseColorError::new(
+            parser.pos,
+            text[parser.pos..].trim_end(),
+            ParseColorErrorReason::UnexpectedToken));
+    }
+    Ok(parsed)
+}
+
+/// Parses a hex code color.
+fn parse_hex(hex: &str) -> Result<ParsedColor, ParseColorError> {
+    let (rgb, alpha) = Rgb::from_hex_code_with_alpha(hex)?;
+    Ok(ParsedColor {
+        space: Space::Srgb(rgb.ratios()),
+        alpha: alpha.map_or(1.0, |a| a as f32 / u8::MAX as f32),
+        alpha_span: (0, hex.len()),
+    })
+}
+
+/// Parses a named color, which starts at the given byte offset.
+fn parse_name(name: &str, position: usize)
+    -> Result<ParsedColor, ParseColorError>
+{
+    let alpha_span = (position, position + name.len());
+    if name.eq_ignore_ascii_case("transparent") {
+        return Ok(ParsedColor {
+            space: Space::Srgb([0.0; 3]),
+            alpha: 0.0,
+            alpha_span,
+        });
+    }
+    if let Some(rgb) = names::css_color(name) {
+        return Ok(ParsedColor {
+            space: Space::Srgb(rgb.ratios()),
+            alpha: 1.0,
+            alpha_span,
+        });
+    }
+
+    let reason = if name.eq_ignore_ascii_case("currentcolor") {
+        ParseColorErrorReason::CurrentColor
+    } else if name.bytes().all(|b| b.is_ascii_hexdigit()) {
+        ParseColorErrorReason::MissingHash
+    } else {
+        ParseColorErrorReason::UnknownName
+    };
+    Err(ParseColorError::new(position, name, reason))
+}
+
+/// Parses the arguments of the color function with the given name, which
+/// starts at the given byte offset.
+fn parse_function(name: &str, position: usize, parser: &mut Parser<'_>)
+    -> Result<ParsedColor, ParseColorError>
+{
+    let space: fn(&Arguments<'_>) -> Result<Space, ParseColorError>
+        = match name.to_ascii_lowercase().as_str()
+    {
+        "rgb" | "rgba" => rgb_space,
+        "hsl" | "hsla" => hsl_space,
+        "hwb"          => hwb_space,
+        "lab"          => lab_space,
+        "lch"          => lch_space,
+        "oklab"        => oklab_space,
+        "oklch"        => oklch_space,
+        "color"        => return parse_color_function(parser),
+        _ => return Err(ParseColorError::new(
+            position,
+            name,
+            ParseColorErrorReason::UnknownFunction)),
+    };
+
+    let args = parser.arguments()?;
+    Ok(ParsedColor {
+        space: space(&args)?,
+        alpha: args.alpha()?,
+        alpha_span: args.alpha_span(),
+    })
+}
+
+/// Parses the arguments of the `color()` function.
+fn parse_color_function(parser: &mut Parser<'_>)
+    -> Result<ParsedColor, ParseColorError>
+{
+    let _ = parser.skip_whitespace();
+    let start = parser.pos;
+    let name = parser.identifier()?;
+    let space: fn([f32; 3]) -> Space = match name.to_ascii_lowercase().as_str() {
+        "srgb"             => Space::Srgb,
+        "srgb-linear"      => Space::LinearSrgb,
+        "xyz" | "xyz-d65"  => Space::Xyz,
+        _ => return Err(ParseColorError::new(
+            start,
+            name,
+            ParseColorErrorReason::UnknownColorSpace)),
+    };
+
+    let args = parser.arguments()?;
+    let [a, b, c] = args.components(false)?;
+    let components = [
+        a.number_or_percentage(1.0)?,
+        b.number_or_percentage(1.0)?,
+        c.number_or_percentage(1.0)?,
+    ];
+
+    Ok(ParsedColor {
+        space: space(components),
+        alpha: args.alpha()?,
+        alpha_span: args.alpha_span(),
+    })
+}
+
+/// Converts the arguments of an `rgb()` or `rgba()` function.
+fn rgb_space(args: &Arguments<'_>) -> Result<Space, ParseColorError> {
+    let [r, g, b] = args.components(true)?;
+    if args.legacy.is_some() {
+        // The legacy syntax requires all numbers or all percentages.
+        let same_kind = if r.is_percentage() {
+            Component::is_percentage
+        } else {
+            Component::is_number
+        };
+        if let Some(c) = [g, b].iter().find(|c| !same_kind(c)) {
+            return Err(c.error(ParseColorErrorReason::InvalidComponent));
+        }
+    }
+    Ok(Space::Srgb([
+        r.number_or_percentage(255.0)? / 255.0,
+        g.number_or_percentage(255.0)? / 255.0,
+        b.number_or_percentage(255.0)? / 255.0,
+    ]))
+}
+
+/// Converts the arguments of an `hsl()` or `hsla()` function.
+fn hsl_space(args: &Arguments<'_>) -> Result<Space, ParseColorError> {
+    let [h, s, l] = args.components(true)?;
+    if args.legacy.is_some() {
+        // The legacy syntax requires percentages for saturation and
+        // lightness.
+        if let Some(c) = [s, l].iter().find(|c| !c.is_percentage()) {
+            return Err(c.error(ParseColorErrorReason::InvalidComponent));
+        }
+    }
+    Ok(Space::Hsl([
+        h.hue()?,
+        s.number_or_percentage(100.0)? / 100.0,
+        l.number_or_percentage(100.0)? / 100.0,
+    ]))
+}
+
+/// Converts the arguments of an `hwb()` function.
+fn hwb_space(args: &Arguments<'_>) -> Result<Space, ParseColorError> {
+    let [h, w, b] = args.components(false)?;
+    Ok(Space::Hwb([
+        h.hue()?,
+        w.number_or_percentage(100.0)? / 100.0,
+        b.number_or_percentage(100.0)? / 100.0,
+    ]))
+}
+
+/// Converts the arguments of a `lab()` function.
+fn lab_space(args: &Arguments<'_>) -> Result<Space, ParseColorError> {
+    let [l, a, b] = args.components(false)?;
+    Ok(Space::Lab([
+        l.number_or_percentage(100.0)?,
+        a.number_or_percentage(125.0)?,
+        b.number_or_percentage(125.0)?,
+    ]))
+}
+
+/// Converts the arguments of an `lch()` function.
+fn lch_space(args: &Arguments<'_>) -> Result<Space, ParseColorError> {
+    let [l, c, h] = args.components(false)?;
+    Ok(Space::Lch([
+        l.number_or_percentage(100.0)?,
+        c.number_or_percentage(150.0)?,
+        h.hue()?,
+    ]))
+}
+
+/// Converts the arguments of an `oklab()` function.
+fn oklab_space(args: &Arguments<'_>) -> Result<Space, ParseColorError> {
+    let [l, a, b] = args.components(false)?;
+    Ok(Space::Oklab([
+        l.number_or_percentage(1.0)?,
+        a.number_or_percentage(0.4)?,
+        b.number_or_percentage(0.4)?,
+    ]))
+}
+
+/// Converts the arguments of an `oklch()` function.
+fn oklch_space(args: &Arguments<'_>) -> Result<Space, ParseColorError> {
+    let [l, c, h] = args.components(false)?;
+    Ok(Space::Oklch([
+        l.number_or_percentage(1.0)?,
+        c.number_or_percentage(0.4)?,
+        h.hue()?,
+    ]))
+}
+
+
+/// A simple cursor over the text being parsed.
+#[derive(Debug)]
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Returns the next character without consuming it.
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    /// Returns an error for the next character, or for the end of the input.
+    fn unexpected(&self) -> ParseColorError {
+        match self.peek() {
+            Some(c) => ParseColorError::new(
+                self.pos,
+                &self.text[self.pos..self.pos + c.len_utf8()],
+                ParseColorErrorReason::UnexpectedToken),
+            None => ParseColorError::new(
+                self.pos,
+                "",
+                ParseColorErrorReason::UnexpectedEnd),
+        }
+    }
+
+    /// Skips any whitespace, returning true if any was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += self.peek().map_or(0, char::len_utf8);
+        }
+        self.pos != start
+    }
+
+    /// Consumes the given character, or returns an error.
+    fn expect(&mut self, c: char) -> Result<(), ParseColorError> {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// Consumes an identifier made of letters, digits, and dashes.
+    fn identifier(&mut self) -> Result<&'a str, ParseColorError> {
+        let start = self.pos;
+        while self.peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(self.unexpected());
+        }
+        Ok(&self.text[start..self.pos])
+    }
+
+    /// Consumes the arguments of a color function, including the closing
+    /// parenthesis.
+    fn arguments(&mut self) -> Result<Arguments<'a>, ParseColorError> {
+        let _ = self.skip_whitespace();
+        let mut args = Arguments {
+            values: Vec::new(),
+            alpha: None,
+            legacy: None,
+            position: self.pos,
+            token: "",
+        };
+
+        if self.peek() != Some(')') {
+            args.values.push(self.component()?);
+        }
+
+        loop {
+            let spaced = self.skip_whitespace();
+            match self.peek() {
+                Some(')') => break,
+
+                Some(',') if args.legacy.is_some() || args.values.len() == 1 => {
+                    if args.legacy.is_none() {
+                        args.legacy = Some(self.pos);
+                    }
+                    self.pos += 1;
+                    let _ = self.skip_whitespace();
+                    let component = self.component()?;
+                    if args.alpha.is_some() {
+                        return Err(ParseColorError::new(
+                            component.position,
+                            component.token,
+                            ParseColorErrorReason::WrongComponentCount));
+                    } else if args.values.len() == 3 {
+                        args.alpha = Some(component);
+                    } else {
+                        args.values.push(component);
+                    }
+                },
+
+                Some('/') if args.legacy.is_none() && args.alpha.is_none() => {
+                    self.pos += 1;
+                    let _ = self.skip_whitespace();
+                    args.alpha = Some(self.component()?);
+                },
+
+                Some(_) if spaced
+                    && args.legacy.is_none()
+                    && args.alpha.is_none() =>
+                {
+                    args.values.push(self.component()?);
+                },
+
+                _ => return Err(self.unexpected()),
+            }
+        }
+
+        args.token = self.text[args.position..self.pos].trim_end();
+        self.expect(')')?;
+        Ok(args)
+    }
+
+    /// Consumes a single component value.
+    fn component(&mut self) -> Result<Component<'a>, ParseColorError> {
+        let start = self.pos;
+        let bytes = self.text.as_bytes();
+        let mut end = start;
+
+        if end < bytes.len() && (bytes[end] == b'+' || bytes[end] == b'-') {
+            end += 1;
+        }
+        let digits_start = end;
+        while end < bytes.len() && bytes[end].is_ascii_digit() { end += 1; }
+        if end < bytes.len() && bytes[end] == b'.' {
+            end += 1;
+            while end < bytes.len() && bytes[end].is_ascii_digit() { end += 1; }
+        }
+        if end == digits_start || &self.text[digits_start..end] == "." {
+            // Not a number, so it must be the `none` keyword.
+            let ident = self.identifier()?;
+            return if ident.eq_ignore_ascii_case("none") {
+                Ok(Component {
+                    value: ComponentValue::None,
+                    position: start,
+                    token: ident,
+                })
+            } else {
+                Err(ParseColorError::new(
+                    start,
+                    ident,
+                    ParseColorErrorReason::UnexpectedToken))
+            };
+        }
+        if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+            let mut exp = end + 1;
+            if exp < bytes.len() && (bytes[exp] == b'+' || bytes[exp] == b'-') {
+                exp += 1;
+            }
+            if exp < bytes.len() && bytes[exp].is_ascii_digit() {
+                while exp < bytes.len() && bytes[exp].is_ascii_digit() {
+                    exp += 1;
+                }
+                end = exp;
+            }
+        }
+
+        let number = &self.text[start..end];
+        let value: f32 = number.parse()
+            .ok()
+            .filter(|v: &f32| v.is_finite())
+            .ok_or_else(|| ParseColorError::new(
+                start,
+                number,
+                ParseColorErrorReason::OutOfRange))?;
+        self.pos = end;
+
+        let value = if self.peek() == Some('%') {
+            self.pos += 1;
+            ComponentValue::Percentage(value)
+        } else if self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            let unit_start = self.pos;
+            let unit = self.identifier()?;
+            let degrees = match unit.to_ascii_lowercase().as_str() {
+                "deg"  => value,
+                "rad"  => value.to_degrees(),
+                "grad" => value * 0.9,
+                "turn" => value * 360.0,
+                _ => return Err(ParseColorError::new(
+                    unit_start,
+                    unit,
+                    ParseColorErrorReason::InvalidUnit)),
+            };
+            // The unit conversion may overflow a finite number.
+            if !degrees.is_finite() {
+                return Err(ParseColorError::new(
+                    start,
+                    number,
+                    ParseColorErrorReason::OutOfRange));
+            }
+            ComponentValue::Angle(degrees)
+        } else {
+            ComponentValue::Number(value)
+        };
+
+        Ok(Component {
+            value,
+            position: start,
+            token: &self.text[start..self.pos],
+        })
+    }
+}
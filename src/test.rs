@@ -12,11 +12,36 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 // Local imports.
+use crate::Alpha;
+use crate::BlendMode;
 use crate::Cmyk;
+use crate::Color;
+use crate::ColorNameDictionary;
+use crate::CompositeOperator;
+use crate::contrast;
+use crate::cvd;
+use crate::cvd::CvdKind;
+use crate::delta_e;
+use crate::Easing;
+use crate::Gradient;
 use crate::Hsl;
+use crate::Hsla;
 use crate::Hsv;
+use crate::HueInterpolation;
+use crate::HueWheel;
+use crate::InterpolationMode;
+use crate::InterpolationSpace;
+use crate::Lab;
+use crate::Lch;
+use crate::LinearRgb;
+use crate::Oklab;
+use crate::Oklch;
+use crate::ParseColorErrorReason;
 use crate::Rgb;
+use crate::Rgba;
+use crate::SplineKind;
 use crate::utility::close;
+use crate::Xyz;
 
 ////////////////////////////////////////////////////////////////////////////////
 // UNIT
@@ -486,3 +511,1793 @@ fn color_conversions_navy() {
     assert!(close(navy_hsv.saturation(), 1.0, UNIT));
     assert!(close(navy_hsv.value(), 0.5, UNIT));
 }
+
+/// Tests Lab conversions for the D65 reference white.
+#[test]
+fn lab_conversions_white_point() {
+    let white = Xyz {x: 0.95047, y: 1.0, z: 1.08883};
+    let white_lab = Lab::from(white);
+
+    assert!(close(white_lab.lightness(), 100.0, 0.001));
+    assert!(close(white_lab.a(), 0.0, 0.001));
+    assert!(close(white_lab.b(), 0.0, 0.001));
+}
+
+/// Tests Lab conversions against the CIE reference values for sRGB red.
+#[test]
+fn lab_conversions_red() {
+    let red = Xyz {x: 0.4124564, y: 0.2126729, z: 0.0193339};
+    let red_lab = Lab::from(red);
+
+    assert!(close(red_lab.lightness(), 53.2408, 0.01));
+    assert!(close(red_lab.a(), 80.0925, 0.01));
+    assert!(close(red_lab.b(), 67.2032, 0.01));
+
+    let red_xyz = Xyz::from(red_lab);
+    assert!(close(red_xyz.x, red.x, 0.0001));
+    assert!(close(red_xyz.y, red.y, 0.0001));
+    assert!(close(red_xyz.z, red.z, 0.0001));
+}
+
+/// Tests Lab conversions for dark colors in the linear segment.
+#[test]
+fn lab_conversions_dark() {
+    let dark = Xyz {x: 0.001, y: 0.002, z: 0.0005};
+    let dark_lab = Lab::from(dark);
+
+    assert!(dark_lab.lightness() < 8.0);
+
+    let dark_xyz = Xyz::from(dark_lab);
+    assert!(close(dark_xyz.x, dark.x, 0.00001));
+    assert!(close(dark_xyz.y, dark.y, 0.00001));
+    assert!(close(dark_xyz.z, dark.z, 0.00001));
+}
+
+/// Tests Lch conversions to and from Lab.
+#[test]
+fn lch_conversions_lab() {
+    let lab = Lab::new(53.2408, 80.0925, 67.2032);
+    let lch = Lch::from(lab);
+
+    assert!(close(lch.lightness(), 53.2408, 0.001));
+    assert!(close(lch.chroma(), 104.5518, 0.001));
+    assert!(close(lch.hue(), 39.9990, 0.001));
+
+    let lab_b = Lab::from(lch);
+    assert!(close(lab_b.lightness(), lab.lightness(), 0.001));
+    assert!(close(lab_b.a(), lab.a(), 0.001));
+    assert!(close(lab_b.b(), lab.b(), 0.001));
+}
+
+/// Tests Lch conversions for achromatic colors.
+#[test]
+fn lch_conversions_achromatic() {
+    let gray = Lch::from(Lab::new(50.0, 0.0, 0.0));
+
+    assert!(close(gray.chroma(), 0.0, 0.001));
+    assert!(close(gray.hue(), 0.0, 0.001));
+
+    let negative = Lch::from(Lab::new(50.0, 0.0, -20.0));
+    assert!(close(negative.hue(), 270.0, 0.001));
+}
+
+/// Tests that Lch interpolation takes the shorter way around the hue circle.
+#[test]
+fn lch_interpolate_hue_shorter() {
+    let a = Lch::new(50.0, 40.0, 350.0);
+    let b = Lch::new(50.0, 40.0, 10.0);
+
+    let mid = Lch::linear_interpolate(a, b, 0.5, HueInterpolation::Shorter);
+    assert!(close(mid.hue(), 0.0, 0.001));
+
+    let quarter = Lch::linear_interpolate(b, a, 0.25, HueInterpolation::Shorter);
+    assert!(close(quarter.hue(), 5.0, 0.001));
+
+    // An achromatic endpoint does not contribute a hue.
+    let gray = Lch::new(50.0, 0.0, 0.0);
+    let toward_gray = Lch::linear_interpolate(
+        gray,
+        Lch::new(50.0, 40.0, 200.0),
+        0.5,
+        HueInterpolation::Shorter);
+    assert!(close(toward_gray.hue(), 200.0, 0.001));
+}
+
+/// Tests that shifting the Lch hue of a color preserves its lightness.
+#[test]
+fn color_lch_shift_hue_lightness() {
+    let mut color = Color::new(Rgb::new(120, 100, 90));
+    let l = color.lab_lightness();
+
+    color.lch_shift_hue(30.0);
+
+    assert!(close(color.lab_lightness(), l, 1.0));
+    let h = Color::new(Rgb::new(120, 100, 90)).lch_hue();
+    assert!(close(color.lch_hue(), h + 30.0, 3.0));
+}
+
+/// Tests Oklab conversions for sRGB white and red.
+#[test]
+fn oklab_conversions_white_red() {
+    let white = Oklab::from(Rgb::new(255, 255, 255));
+    assert!(close(white.lightness(), 1.0, 0.001));
+    assert!(close(white.a(), 0.0, 0.001));
+    assert!(close(white.b(), 0.0, 0.001));
+
+    let red = Oklab::from(Rgb::new(255, 0, 0));
+    assert!(close(red.lightness(), 0.62796, 0.001));
+    assert!(close(red.a(), 0.22486, 0.001));
+    assert!(close(red.b(), 0.12585, 0.001));
+}
+
+/// Tests that Rgb colors survive a round trip through Oklab and Oklch.
+#[test]
+fn oklab_conversions_round_trip() {
+    for &(r, g, b) in &[(0, 0, 0), (255, 255, 255), (127, 255, 64),
+        (15, 144, 99), (200, 30, 180), (128, 128, 128)]
+    {
+        let rgb = Rgb::new(r, g, b);
+        assert_eq!(Rgb::from(Oklab::from(rgb)), rgb);
+        assert_eq!(Rgb::from(Oklch::from(rgb)), rgb);
+    }
+}
+
+/// Tests that Oklch treats grays as achromatic.
+#[test]
+fn oklch_conversions_achromatic() {
+    let gray = Oklch::from(Rgb::new(128, 128, 128));
+
+    assert!(close(gray.chroma(), 0.0, 0.001));
+    assert!(close(gray.hue(), 0.0, 0.001));
+}
+
+/// Tests that Oklch interpolation takes the shorter way around the hue circle.
+#[test]
+fn oklch_interpolate_hue_shorter() {
+    let a = Oklch::new(0.5, 0.1, 350.0);
+    let b = Oklch::new(0.5, 0.1, 10.0);
+
+    let mid = Oklch::linear_interpolate(a, b, 0.5, HueInterpolation::Shorter);
+    assert!(close(mid.hue(), 0.0, 0.001));
+}
+
+/// Tests Xyz conversions against the reference values for sRGB primaries.
+#[test]
+fn xyz_conversions_reference() {
+    let white = Xyz::from(Rgb::new(255, 255, 255));
+    assert!(close(white.x, 0.95047, 0.0001));
+    assert!(close(white.y, 1.0, 0.0001));
+    assert!(close(white.z, 1.08883, 0.0001));
+
+    let red = Xyz::from(Rgb::new(255, 0, 0));
+    assert!(close(red.x, 0.4124564, 0.0001));
+    assert!(close(red.y, 0.2126729, 0.0001));
+    assert!(close(red.z, 0.0193339, 0.0001));
+
+    let gray = Xyz::from(Rgb::new(128, 128, 128));
+    assert!(close(gray.y, 0.21586, 0.0001));
+}
+
+/// Tests that Rgb colors survive a round trip through Xyz.
+#[test]
+fn xyz_conversions_round_trip() {
+    for r in (0..=255).step_by(15) {
+        for g in (0..=255).step_by(15) {
+            for b in (0..=255).step_by(15) {
+                let rgb = Rgb::new(r, g, b);
+                assert_eq!(Rgb::from(Xyz::from(rgb)), rgb);
+            }
+        }
+    }
+}
+
+/// Tests that Xyz colors survive a round trip through the Xyz constructor.
+#[test]
+fn xyz_constructor_round_trip() {
+    for &rgb in &[
+        Rgb::new(255, 255, 255),
+        Rgb::new(0, 0, 255),
+        Rgb::new(200, 100, 250),
+        Rgb::new(0, 0, 0),
+    ] {
+        let xyz = Xyz::from(rgb);
+        let rebuilt = Xyz::new(xyz.x(), xyz.y(), xyz.z());
+        assert_eq!(rebuilt, xyz);
+        assert_eq!(Xyz::from(xyz.components()), xyz);
+        assert_eq!(Rgb::from(rebuilt), rgb);
+    }
+
+    let white = Xyz::new(0.95047, 1.0, 1.08883);
+    assert_eq!(white.z(), 1.08883);
+    assert_eq!(Rgb::from(white), Rgb::new(255, 255, 255));
+
+    let mut negative = Xyz::new(-0.5, 0.5, 0.5);
+    assert_eq!(negative.x(), 0.0);
+    negative.set_z(-1.0);
+    assert_eq!(negative.z(), 0.0);
+}
+
+/// Tests that Rgb colors survive a round trip through LinearRgb.
+#[test]
+fn linear_rgb_conversions_round_trip() {
+    for v in 0..=255 {
+        let rgb = Rgb::new(v, 255 - v, v / 2);
+        assert_eq!(Rgb::from(LinearRgb::from(rgb)), rgb);
+    }
+
+    let gray = LinearRgb::from(Rgb::new(128, 128, 128));
+    assert!(close(gray.r(), 0.21586, 0.0001));
+}
+
+/// Tests that LinearRgb interpolation blends in linear light.
+#[test]
+fn linear_rgb_interpolate_midpoint() {
+    let black = Color::new(Rgb::new(0, 0, 0));
+    let white = Color::new(Rgb::new(255, 255, 255));
+
+    let mid = Color::linear_rgb_linear_interpolate(black, white, 0.5);
+    assert_eq!(mid, Color::new(Rgb::new(188, 188, 188)));
+
+    let mid = LinearRgb::linear_interpolate(
+        Rgb::new(255, 0, 0),
+        Rgb::new(0, 255, 0),
+        0.5);
+    assert_eq!(Rgb::from(mid), Rgb::new(188, 188, 0));
+}
+
+/// Tests that LinearRgb components are clamped by its constructors and
+/// conversions.
+#[test]
+fn linear_rgb_clamping() {
+    let expected = LinearRgb::new(1.0, 0.0, 0.5);
+    assert_eq!(LinearRgb::new(2.0, -1.0, 0.5), expected);
+    assert_eq!(LinearRgb::from([2.0, -1.0, 0.5]), expected);
+    assert_eq!("color(srgb-linear 2 -1 0.5)".parse::<LinearRgb>().unwrap(),
+        expected);
+
+    let from_xyz = LinearRgb::from(Xyz::new(0.95, 1.0, 1.08));
+    let from_oklab = LinearRgb::from(Oklab::new(0.9, -0.3, 0.3));
+    for linear in &[from_xyz, from_oklab] {
+        for &v in &linear.components() {
+            assert!((0.0..=1.0).contains(&v), "{:?}", linear);
+        }
+    }
+    assert_eq!(from_xyz.r(), 1.0);
+}
+
+/// Tests parsing hex codes with and without alpha components.
+#[test]
+fn color_hex_code_alpha() {
+    let short = Color::from_hex_code("#7f4").unwrap();
+    assert_eq!(short, Color::new(Rgb::new(0x77, 0xFF, 0x44)));
+    assert_eq!(short.alpha(), 255);
+
+    let short_alpha = Color::from_hex_code("#7f48").unwrap();
+    assert_eq!(short_alpha.rgb_hex(), 0x77FF44);
+    assert_eq!(short_alpha.alpha(), 0x88);
+
+    let long_alpha = Color::from_hex_code("#7FFF4080").unwrap();
+    assert_eq!(long_alpha.rgba_hex(), 0x7FFF4080);
+
+    assert!(Color::from_hex_code("7FFF40").is_err());
+    assert!(Color::from_hex_code("#7FFF4").is_err());
+    assert!(Color::from_hex_code("#+FFF40").is_err());
+
+    // Rgb cannot carry an alpha component.
+    assert!(Rgb::from_hex_code("#7f48").is_err());
+    assert!(Rgb::from_hex_code("#7FFF4080").is_err());
+    assert_eq!(Rgb::from_hex_code("#7f4"), Ok(Rgb::new(0x77, 0xFF, 0x44)));
+}
+
+/// Tests formatting colors with alpha components as hex codes.
+#[test]
+fn color_hex_format_alpha() {
+    let opaque = Color::new(Rgb::new(127, 255, 64));
+    assert_eq!(format!("{:X}", opaque), "#7FFF40");
+
+    let translucent = opaque.with_alpha(0x80);
+    assert_eq!(format!("{:X}", translucent), "#7FFF4080");
+    assert_eq!(format!("{:x}", translucent), "#7fff4080");
+    assert_eq!(
+        Color::from_hex_code(&format!("{:x}", translucent)),
+        Ok(translucent));
+
+    let rgba = Rgba::new(Rgb::new(127, 255, 64), 0.5);
+    assert_eq!(format!("{:X}", rgba), "#7FFF4080");
+}
+
+/// Tests that alpha is preserved when converting between Color and Alpha.
+#[test]
+fn color_alpha_conversions() {
+    let color = Color::new(Rgb::new(255, 0, 0)).with_alpha(51);
+
+    let hsla: Hsla = color.into();
+    assert!(close(hsla.alpha_ratio(), 0.2, 0.0001));
+    assert!(close(hsla.color().hue(), 0.0, 0.0001));
+
+    let back = Color::from(hsla);
+    assert_eq!(back, color);
+
+    // Conversions from plain colors are opaque.
+    assert_eq!(Color::from(Rgb::new(1, 2, 3)).alpha(), 255);
+    assert_eq!(Color::default().alpha(), 255);
+}
+
+/// Tests the Porter-Duff operators on partially overlapping colors.
+#[test]
+fn composite_operators() {
+    use CompositeOperator::*;
+    let source = Alpha::new(LinearRgb::new(1.0, 0.0, 0.0), 0.6);
+    let destination = Alpha::new(LinearRgb::new(0.0, 0.0, 1.0), 0.5);
+
+    let expected = [
+        (SourceOver,      0.8, [0.75, 0.0, 0.25]),
+        (DestinationOver, 0.8, [0.375, 0.0, 0.625]),
+        (SourceIn,        0.3, [1.0, 0.0, 0.0]),
+        (DestinationIn,   0.3, [0.0, 0.0, 1.0]),
+        (SourceOut,       0.3, [1.0, 0.0, 0.0]),
+        (DestinationOut,  0.2, [0.0, 0.0, 1.0]),
+        (SourceAtop,      0.5, [0.6, 0.0, 0.4]),
+        (DestinationAtop, 0.6, [0.5, 0.0, 0.5]),
+        (Xor,             0.5, [0.6, 0.0, 0.4]),
+        (Plus,            1.0, [0.6, 0.0, 0.5]),
+    ];
+
+    for &(op, alpha, [r, g, b]) in &expected {
+        let result = op.composite(source, destination);
+        assert!(close(result.alpha_ratio(), alpha, 0.0001), "{:?}", op);
+        assert!(close(result.color().r(), r, 0.0001), "{:?}", op);
+        assert!(close(result.color().g(), g, 0.0001), "{:?}", op);
+        assert!(close(result.color().b(), b, 0.0001), "{:?}", op);
+
+        let premultiplied = op
+            .composite_premultiplied(
+                source.premultiplied(),
+                destination.premultiplied())
+            .unpremultiplied();
+        assert_eq!(premultiplied, result);
+    }
+}
+
+/// Tests compositing Colors in linear light.
+#[test]
+fn color_composite() {
+    let opaque = Color::new(Rgb::new(10, 200, 30));
+    let clear = Color::new(Rgb::new(90, 20, 250)).with_alpha(0);
+
+    assert_eq!(
+        Color::composite(opaque, clear, CompositeOperator::SourceOver),
+        opaque);
+    assert_eq!(
+        Color::composite(clear, opaque, CompositeOperator::SourceOver),
+        opaque);
+    assert_eq!(
+        Color::composite(opaque, clear, CompositeOperator::SourceIn).alpha(),
+        0);
+
+    // Half-transparent white over black is brighter than the gamma midpoint.
+    let white = Color::new(Rgb::new(255, 255, 255)).with_alpha(128);
+    let black = Color::new(Rgb::new(0, 0, 0));
+    let result = Color::composite(white, black, CompositeOperator::SourceOver);
+    assert_eq!(result, Color::new(Rgb::new(188, 188, 188)));
+}
+
+/// Tests the separable blend functions against the W3C definitions.
+#[test]
+fn blend_separable_modes() {
+    use BlendMode::*;
+    let cb = [0.2, 0.5, 0.8];
+    let cs = [0.6, 0.3, 0.9];
+
+    let expected = [
+        (Normal,     [0.6, 0.3, 0.9]),
+        (Multiply,   [0.12, 0.15, 0.72]),
+        (Screen,     [0.68, 0.65, 0.98]),
+        (Overlay,    [0.24, 0.3, 0.96]),
+        (Darken,     [0.2, 0.3, 0.8]),
+        (Lighten,    [0.6, 0.5, 0.9]),
+        (ColorDodge, [0.5, 0.71428573, 1.0]),
+        (ColorBurn,  [0.0, 0.0, 0.7777778]),
+        (HardLight,  [0.36, 0.3, 0.96]),
+        (SoftLight,  [0.2496, 0.4, 0.87554175]),
+        (Difference, [0.4, 0.2, 0.1]),
+        (Exclusion,  [0.56, 0.5, 0.26]),
+    ];
+
+    for &(mode, result) in &expected {
+        let blended = mode.blend_ratios(cb, cs);
+        for i in 0..3 {
+            assert!(close(blended[i], result[i], 0.0001),
+                "{:?} {:?} != {:?}", mode, blended, result);
+        }
+    }
+}
+
+/// Tests that the non-separable blend modes preserve the expected properties.
+#[test]
+fn blend_non_separable_modes() {
+    let lum = |c: [f32; 3]| 0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2];
+    let cb = [0.8, 0.4, 0.2];
+    let cs = [0.1, 0.3, 0.9];
+
+    // Hue, saturation, and color keep the backdrop luminosity.
+    for &mode in &[BlendMode::Hue, BlendMode::Saturation, BlendMode::Color] {
+        let blended = BlendMode::blend_ratios(&mode, cb, cs);
+        assert!(close(lum(blended), lum(cb), 0.0001), "{:?}", mode);
+    }
+
+    // Luminosity keeps the source luminosity.
+    let blended = BlendMode::Luminosity.blend_ratios(cb, cs);
+    assert!(close(lum(blended), lum(cs), 0.0001));
+
+    // Blending a gray with the color mode yields a gray.
+    let gray = BlendMode::Color.blend_ratios(cb, [0.5, 0.5, 0.5]);
+    assert!(close(gray[0], gray[1], 0.0001));
+    assert!(close(gray[1], gray[2], 0.0001));
+}
+
+/// Tests blending Colors with transparency.
+#[test]
+fn color_blend_alpha() {
+    let backdrop = Color::new(Rgb::new(200, 100, 50));
+    let source = Color::new(Rgb::new(128, 255, 0));
+
+    // A transparent source leaves the backdrop unchanged.
+    assert_eq!(
+        Color::blend(backdrop, source.with_alpha(0), BlendMode::Multiply),
+        backdrop);
+
+    // A transparent backdrop leaves the source unchanged.
+    assert_eq!(
+        Color::blend(backdrop.with_alpha(0), source, BlendMode::Multiply),
+        source);
+
+    assert_eq!(
+        Color::blend(backdrop, source, BlendMode::Multiply),
+        Color::new(Rgb::new(100, 100, 0)));
+}
+
+/// Tests parsing CSS hex codes and rgb functions.
+#[cfg(feature = "parse")]
+#[test]
+fn parse_css_rgb() {
+    let expected = Color::new(Rgb::new(127, 255, 64));
+    let inputs = [
+        "#7FFF40",
+        "#7fff40ff",
+        "rgb(127, 255, 64)",
+        "rgba(127,255,64,1)",
+        "RGB(127 255 64)",
+        "rgb(127 255 64 / 100%)",
+        "  rgb( 127  255  64 )  ",
+        "rgb(49.8% 100% 25.1%)",
+        "rgb(127 300 64)",
+        "color(srgb 0.498 1 0.251)",
+    ];
+    for input in &inputs {
+        assert_eq!(input.parse::<Color>(), Ok(expected), "{}", input);
+    }
+
+    let translucent: Color = "rgb(127 255 64 / 50%)".parse().unwrap();
+    assert_eq!(translucent.alpha(), 128);
+    let translucent: Color = "rgba(127, 255, 64, 0.2)".parse().unwrap();
+    assert_eq!(translucent.alpha(), 51);
+    let short: Color = "#7f48".parse().unwrap();
+    assert_eq!(short.rgba_hex(), 0x77FF4488);
+
+    let none: Rgb = "rgb(none 255 64)".parse().unwrap();
+    assert_eq!(none, Rgb::new(0, 255, 64));
+}
+
+/// Tests parsing CSS cylindrical and perceptual color functions.
+#[cfg(feature = "parse")]
+#[test]
+fn parse_css_functions() {
+    let red = Color::new(Rgb::new(255, 0, 0));
+    let inputs = [
+        "hsl(0, 100%, 50%)",
+        "hsla(360, 100%, 50%, 1)",
+        "hsl(0deg 100% 50%)",
+        "hsl(1turn 100 50)",
+        "hsl(-360 100% 50%)",
+        "hwb(0 0% 0%)",
+        "hwb(400grad 0 0)",
+        "lab(53.2408 80.0925 67.2032)",
+        "lch(53.2408 104.5518 39.999)",
+        "oklab(0.62796 0.22486 0.12585)",
+        "oklab(62.796% 56.215% 31.4625%)",
+        "oklch(0.62796 0.25768 29.2339)",
+        "oklch(0.62796 0.25768 0.5102rad)",
+        "color(srgb-linear 1 0 0)",
+        "color(xyz-d65 0.4124564 0.2126729 0.0193339)",
+    ];
+    for input in &inputs {
+        assert_eq!(input.parse::<Color>(), Ok(red), "{}", input);
+    }
+
+    let gray: Color = "hwb(120 100% 50%)".parse().unwrap();
+    assert_eq!(gray, Color::new(Rgb::new(170, 170, 170)));
+
+    let lab: Lab = "lab(50% 40 -20 / 1)".parse().unwrap();
+    assert!(close(lab.lightness(), 50.0, 0.001));
+    assert!(close(lab.a(), 40.0, 0.001));
+    assert!(close(lab.b(), -20.0, 0.001));
+
+    let hsla: Hsla = "hsl(120 50% 25% / 0.25)".parse().unwrap();
+    assert!(close(hsla.alpha_ratio(), 0.25, 0.0001));
+    assert!(close(hsla.color().hue(), 120.0, 0.0001));
+
+    let oklch: Oklch = "oklch(0.7 none 200)".parse().unwrap();
+    assert!(close(oklch.chroma(), 0.0, 0.0001));
+}
+
+/// Tests that malformed CSS colors are rejected.
+#[cfg(feature = "parse")]
+#[test]
+fn parse_css_invalid() {
+    let inputs = [
+        "",
+        "#",
+        "#12",
+        "#12345",
+        "#1234567",
+        "#abcdefgh",
+        "7FFF40",
+        "rgb",
+        "rgb(",
+        "rgb()",
+        "rgb(1 2)",
+        "rgb(1 2 3 4)",
+        "rgb(1, 2 3)",
+        "rgb(1 2, 3)",
+        "rgb(1, 2%, 3)",
+        "rgb(1, 2, none)",
+        "rgb(1 2 3 / 4 / 5)",
+        "rgb(1, 2, 3) x",
+        "rgb(1deg 2 3)",
+        "hsl(10% 20% 30%)",
+        "hsl(10, 20, 30)",
+        "hwb(10, 20%, 30%)",
+        "lab(1, 2, 3)",
+        "color(display-p3 1 0 0)",
+        "colour(1 2 3)",
+        "rgb(1e999 0 0)",
+        "rgb(1px 0 0)",
+        "hsl(1e38turn 50% 50%)",
+        "hsl(3e38rad 50% 50%)",
+        "hwb(1e38turn 10% 10%)",
+        "lch(50 30 1e38turn)",
+        "lab(50 3e38% 0)",
+    ];
+    for input in &inputs {
+        assert!(input.parse::<Color>().is_err(), "{}", input);
+    }
+}
+
+/// Tests that color types without an alpha component reject translucent
+/// colors when parsing, as `Rgb::from_hex_code` does.
+#[test]
+fn parse_css_alpha_not_supported() {
+    use ParseColorErrorReason::*;
+    let cases = [
+        ("#abcd", 0, "#abcd"),
+        (" #7FFF4080", 1, "#7FFF4080"),
+        ("rgb(0 0 0 / 0.5)", 12, "0.5"),
+        ("rgba(0, 0, 0, 50%)", 14, "50%"),
+        ("color(srgb 1 0 0 / 0)", 19, "0"),
+        ("transparent", 0, "transparent"),
+    ];
+    for &(input, position, token) in &cases {
+        let error = input.parse::<Rgb>().unwrap_err();
+        assert_eq!(error.position(), position, "{}", input);
+        assert_eq!(error.token(), token, "{}", input);
+        assert_eq!(error.reason(), AlphaNotSupported, "{}", input);
+        assert!(input.parse::<Lab>().is_err(), "{}", input);
+        assert!(input.parse::<Color>().is_ok(), "{}", input);
+        assert!(input.parse::<Rgba>().is_ok(), "{}", input);
+    }
+    assert_eq!(Rgb::from_hex_code("#abcd").unwrap_err().reason(),
+        AlphaNotSupported);
+
+    // Fully opaque alpha components are accepted.
+    assert_eq!("rgb(255 0 0 / 1)".parse::<Rgb>(), Ok(Rgb::new(255, 0, 0)));
+}
+
+/// Tests the position, token, and reason of hex code parse errors.
+#[test]
+fn hex_code_parse_errors() {
+    use ParseColorErrorReason::*;
+    let cases = [
+        ("7FFF40", 0, "7FFF40", MissingHash),
+        ("#7FFG40", 4, "G", InvalidDigit),
+        ("#7F\u{e9}F40", 3, "\u{e9}", InvalidDigit),
+        ("#", 0, "#", WrongLength),
+        ("#7FFF4", 0, "#7FFF4", WrongLength),
+        ("#7FFF40801", 0, "#7FFF40801", WrongLength),
+    ];
+    for &(input, position, token, reason) in &cases {
+        let error = Color::from_hex_code(input).unwrap_err();
+        assert_eq!(error.position(), position, "{}", input);
+        assert_eq!(error.token(), token, "{}", input);
+        assert_eq!(error.reason(), reason, "{}", input);
+    }
+
+    let error = Rgb::from_hex_code("#abcd").unwrap_err();
+    assert_eq!(error.reason(), AlphaNotSupported);
+    assert_eq!(error.token(), "#abcd");
+    assert!(Rgba::from_hex_code("#abcd").is_ok());
+}
+
+/// Tests the position, token, and reason of CSS color parse errors.
+#[cfg(feature = "parse")]
+#[test]
+fn parse_css_errors() {
+    use ParseColorErrorReason::*;
+    let cases = [
+        ("", 0, "", UnexpectedEnd),
+        ("  #12g", 5, "g", InvalidDigit),
+        ("7FFF40", 0, "7FFF40", MissingHash),
+        ("rgb(1 2", 7, "", UnexpectedEnd),
+        ("rgb(1 2)", 4, "1 2", WrongComponentCount),
+        ("rgb(1, 2, 3, 4, 5)", 16, "5", WrongComponentCount),
+        ("rgb(1, 2%, 3)", 7, "2%", InvalidComponent),
+        ("rgb(1, 2, none)", 10, "none", InvalidComponent),
+        ("rgb(1deg 2 3)", 4, "1deg", InvalidComponent),
+        ("rgb(1px 2 3)", 5, "px", InvalidUnit),
+        ("rgb(1e999 0 0)", 4, "1e999", OutOfRange),
+        ("hsl(1e38turn 50% 50%)", 4, "1e38", OutOfRange),
+        ("lab(50 3e38% 0)", 7, "3e38%", OutOfRange),
+        ("rgb(red 0 0)", 4, "red", UnexpectedToken),
+        ("rgb(1 2 3) x", 11, "x", UnexpectedToken),
+        ("rgb(1 2, 3)", 7, ",", UnexpectedToken),
+        ("hsl(10% 20% 30%)", 4, "10%", InvalidComponent),
+        ("hwb(0, 0%, 0%)", 5, ",", UnexpectedToken),
+        (" colour(1 2 3)", 1, "colour", UnknownFunction),
+        ("color(display-p3 1 0 0)", 6, "display-p3", UnknownColorSpace),
+    ];
+    for &(input, position, token, reason) in &cases {
+        let error = input.parse::<Color>().unwrap_err();
+        assert_eq!(error.position(), position, "{}", input);
+        assert_eq!(error.token(), token, "{}", input);
+        assert_eq!(error.reason(), reason, "{}", input);
+    }
+
+    let error = "hsl(120 50 25%) 1".parse::<Color>().unwrap_err();
+    assert_eq!(error.to_string(), "unexpected token `1` at byte 16");
+}
+
+/// Tests CSS named color lookup.
+#[test]
+fn css_named_colors() {
+    let basic = [
+        ("black", 0x000000), ("silver", 0xC0C0C0), ("gray", 0x808080),
+        ("white", 0xFFFFFF), ("maroon", 0x800000), ("red", 0xFF0000),
+        ("purple", 0x800080), ("fuchsia", 0xFF00FF), ("green", 0x008000),
+        ("lime", 0x00FF00), ("olive", 0x808000), ("yellow", 0xFFFF00),
+        ("navy", 0x000080), ("blue", 0x0000FF), ("teal", 0x008080),
+        ("aqua", 0x00FFFF),
+    ];
+    for &(name, hex) in &basic {
+        let color = Color::new(Rgb::from(hex));
+        assert_eq!(Color::from_name(name), Some(color), "{}", name);
+        assert_eq!(color.name(), Some(name));
+        assert_eq!(color.nearest_name(), name);
+    }
+
+    assert_eq!(Color::from_name("DarkSlateGrey"), Color::from_name("darkslategray"));
+    assert_eq!(Color::new(Rgb::from(0x2F4F4F)).name(), Some("darkslategray"));
+    assert_eq!(Color::from_name("reddish"), None);
+    assert_eq!(Color::from_name(""), None);
+
+    let transparent = Color::from_name("Transparent").unwrap();
+    assert_eq!(transparent.rgba_hex(), 0x00000000);
+    assert_eq!(transparent.name(), Some("transparent"));
+    assert_eq!(Color::from_name("currentcolor"), None);
+
+    assert_eq!(Color::new(Rgb::new(0x66, 0x33, 0x98)).name(), None);
+    assert_eq!(Color::new(Rgb::new(0x66, 0x33, 0x98)).nearest_name(), "rebeccapurple");
+    assert_eq!(Color::new(Rgb::new(250, 128, 5)).nearest_name(), "darkorange");
+    assert_eq!(Color::new(Rgb::new(1, 1, 1)).nearest_name(), "black");
+}
+
+/// Tests parsing CSS named colors.
+#[cfg(feature = "parse")]
+#[test]
+fn parse_css_named_colors() {
+    assert_eq!("rebeccapurple".parse::<Color>(), Ok(Color::new(Rgb::from(0x663399))));
+    assert_eq!(" Teal ".parse::<Rgb>(), Ok(Rgb::from(0x008080)));
+    assert_eq!("transparent".parse::<Color>().map(|c| c.rgba_hex()), Ok(0));
+
+    let error = "currentColor".parse::<Color>().unwrap_err();
+    assert_eq!(error.reason(), ParseColorErrorReason::CurrentColor);
+    let error = " reddish".parse::<Color>().unwrap_err();
+    assert_eq!(error.reason(), ParseColorErrorReason::UnknownName);
+    assert_eq!(error.position(), 1);
+    assert_eq!(error.token(), "reddish");
+}
+
+/// Tests that dictionary nearest-name queries match a brute force search.
+#[test]
+fn color_name_dictionary_nearest() {
+    let dictionary = ColorNameDictionary::css();
+    for r in (0..=255).step_by(15) {
+        for g in (0..=255).step_by(15) {
+            for b in (0..=255).step_by(15) {
+                let rgb = Rgb::new(r as u8, g as u8, b as u8);
+                let expected = dictionary.iter()
+                    .map(|(name, entry)| (Oklab::distance(rgb, entry), name))
+                    .fold((f32::INFINITY, ""), |a, b| if b.0 < a.0 { b } else { a })
+                    .1;
+                assert_eq!(dictionary.nearest(rgb), Some(expected), "{:?}", rgb);
+                assert_eq!(Color::new(rgb).nearest_name(), expected, "{:?}", rgb);
+            }
+        }
+    }
+
+    assert_eq!(ColorNameDictionary::new().nearest(Rgb::new(0, 0, 0)), None);
+}
+
+/// Tests building color name dictionaries.
+#[test]
+fn color_name_dictionary_build() {
+    let mut dictionary = ColorNameDictionary::from_pairs(vec![
+        ("Ink", Rgb::new(10, 10, 20)),
+        ("Paper", Rgb::new(250, 248, 240)),
+    ]);
+    assert_eq!(dictionary.nearest(Rgb::new(200, 200, 200)), Some("Paper"));
+
+    dictionary.insert("PAPER", Rgb::new(0, 0, 0));
+    assert_eq!(dictionary.len(), 2);
+    assert_eq!(dictionary.nearest(Rgb::new(200, 200, 200)), Some("Ink"));
+    assert_eq!(dictionary.name_of(Rgb::new(0, 0, 0)), Some("Paper"));
+
+    dictionary.insert("Snow", Rgb::new(255, 255, 255));
+    assert_eq!(dictionary.nearest(Rgb::new(200, 200, 200)), Some("Snow"));
+    assert_eq!(dictionary.iter().map(|(n, _)| n).collect::<Vec<_>>(),
+        ["Ink", "Paper", "Snow"]);
+}
+
+/// Tests parsing color name dictionaries from text.
+#[test]
+fn color_name_dictionary_from_text() {
+    let text = "! comment\n\
+        # comment\n\
+        \n\
+        255 250 250\t\tsnow\n  \
+        0 0 128 navy blue\n\
+        cloudy blue\t#acc2d9\t\n";
+    let dictionary = ColorNameDictionary::from_text(text).unwrap();
+    assert_eq!(dictionary.len(), 3);
+    assert_eq!(dictionary.get("Snow"), Some(Rgb::new(255, 250, 250)));
+    assert_eq!(dictionary.get("navy blue"), Some(Rgb::new(0, 0, 128)));
+    assert_eq!(dictionary.get("cloudy blue"), Some(Rgb::new(0xAC, 0xC2, 0xD9)));
+
+    use ParseColorErrorReason::*;
+    let cases = [
+        ("1 2 3 ok\n1 2 256 bad", 13, "256", OutOfRange),
+        ("1 2", 3, "", UnexpectedEnd),
+        ("1 2 3", 5, "", UnexpectedEnd),
+        ("1 x 3 bad", 2, "x", UnexpectedToken),
+        ("\n  bad #12345", 7, "#12345", WrongLength),
+        ("bad #12g456", 7, "g", InvalidDigit),
+        ("bad", 0, "bad", UnexpectedToken),
+    ];
+    for &(input, position, token, reason) in &cases {
+        let error = ColorNameDictionary::from_text(input).unwrap_err();
+        assert_eq!(error.position(), position, "{:?}", input);
+        assert_eq!(error.token(), token, "{:?}", input);
+        assert_eq!(error.reason(), reason, "{:?}", input);
+    }
+}
+
+/// Tests the X11 color name dictionary.
+#[cfg(feature = "x11")]
+#[test]
+fn color_name_dictionary_x11() {
+    let dictionary = ColorNameDictionary::x11();
+    assert_eq!(dictionary.get("ghost white"), Some(Rgb::new(248, 248, 255)));
+    assert_eq!(dictionary.get("GhostWhite"), Some(Rgb::new(248, 248, 255)));
+    assert_eq!(dictionary.get("gray"), Some(Rgb::new(190, 190, 190)));
+    assert_eq!(dictionary.nearest(Rgb::new(255, 250, 251)), Some("snow"));
+}
+
+/// Tests the xkcd color name dictionary.
+#[cfg(feature = "xkcd")]
+#[test]
+fn color_name_dictionary_xkcd() {
+    let dictionary = ColorNameDictionary::xkcd();
+    assert_eq!(dictionary.len(), 949);
+    assert_eq!(dictionary.get("cloudy blue"), Some(Rgb::new(172, 194, 217)));
+    assert_eq!(dictionary.get("green/yellow"), Some(Rgb::new(181, 206, 8)));
+    assert_eq!(dictionary.get("robin's egg"), Some(Rgb::new(109, 237, 253)));
+    assert_eq!(dictionary.nearest(Rgb::new(126, 30, 156)), Some("purple"));
+}
+
+/// Tests CIEDE2000 against the reference data from Sharma, Wu, and Dalal
+/// (2005).
+#[test]
+fn delta_e_2000_reference() {
+    let pairs = [
+        ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+        ([50.0, 3.1571, -77.2803], [50.0, 0.0, -82.7485], 2.8615),
+        ([50.0, 2.8361, -74.0200], [50.0, 0.0, -82.7485], 3.4412),
+        ([50.0, 0.0, 0.0], [50.0, -1.0, 2.0], 2.3669),
+        ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0009], 7.1792),
+        ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0011], 7.2195),
+        ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0], 27.1492),
+        ([50.0, 2.5, 0.0], [61.0, -5.0, 29.0], 22.8977),
+        ([50.0, 2.5, 0.0], [56.0, -27.0, -3.0], 31.9030),
+        ([50.0, 2.5, 0.0], [58.0, 24.0, 15.0], 19.4535),
+        ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+    ];
+    for &(a, b, expected) in &pairs {
+        let a = Lab::new(a[0], a[1], a[2]);
+        let b = Lab::new(b[0], b[1], b[2]);
+        assert!(close(delta_e::delta_e_2000(a, b), expected, 0.0005),
+            "{:?} {:?}", a, b);
+        assert!(close(delta_e::delta_e_2000(b, a), expected, 0.0005),
+            "{:?} {:?}", b, a);
+    }
+}
+
+/// Tests general properties of the color difference metrics.
+#[test]
+fn delta_e_properties() {
+    use delta_e::Cie94Application::*;
+    let a = Color::new(Rgb::new(127, 255, 64));
+    let b = Color::new(Rgb::new(15, 144, 99));
+
+    assert_eq!(Color::delta_e_76(a, a), 0.0);
+    assert_eq!(Color::delta_e_94(a, a, GraphicArts), 0.0);
+    assert_eq!(Color::delta_e_2000(a, a), 0.0);
+    assert_eq!(Color::delta_e_cmc(a, a, 2.0, 1.0), 0.0);
+
+    assert_eq!(Color::delta_e_76(a, b), Color::lab_distance(a, b));
+    assert_eq!(Color::delta_e_76(a, b), Color::delta_e_76(b, a));
+
+    // With a neutral reference, CIE94 graphic arts weighting reduces to CIE76.
+    let gray = Lab::new(50.0, 0.0, 0.0);
+    let sample = Lab::new(55.0, 10.0, -5.0);
+    assert!(close(
+        delta_e::delta_e_94(gray, sample, GraphicArts),
+        delta_e::delta_e_76(gray, sample),
+        0.0001));
+    // Textiles weighting halves the lightness difference.
+    let lighter = Lab::new(60.0, 0.0, 0.0);
+    assert!(close(delta_e::delta_e_94(gray, lighter, Textiles), 5.0, 0.0001));
+    // CMC lightness weight scales the lightness term.
+    assert!(close(
+        delta_e::delta_e_cmc(gray, lighter, 2.0, 1.0) * 2.0,
+        delta_e::delta_e_cmc(gray, lighter, 1.0, 1.0),
+        0.0001));
+
+    // Near-identical colors are below the just noticeable difference.
+    let c = Color::new(Rgb::new(127, 255, 64));
+    let d = Color::new(Rgb::new(127, 254, 64));
+    assert!(Color::delta_e_2000(c, d) < 1.0);
+    assert!(Color::delta_e_2000(a, b) > 10.0);
+}
+
+/// Tests WCAG 2.1 relative luminance and contrast ratios.
+#[test]
+fn wcag_contrast() {
+    use contrast::TextSize::*;
+    use contrast::WcagLevel;
+    let white = Color::new(Rgb::new(255, 255, 255));
+    let black = Color::new(Rgb::new(0, 0, 0));
+
+    assert!(close(white.relative_luminance(), 1.0, 0.00001));
+    assert_eq!(black.relative_luminance(), 0.0);
+    assert!(close(Color::contrast_ratio(black, white), 21.0, 0.0001));
+    assert_eq!(Color::contrast_ratio(white, white), 1.0);
+
+    // #767676 is the lightest gray passing AA on white, and #777777 fails.
+    let passing = Color::new(Rgb::from(0x767676));
+    let failing = Color::new(Rgb::from(0x777777));
+    assert!(close(Color::contrast_ratio(passing, white), 4.54, 0.005));
+    assert!(close(Color::contrast_ratio(failing, white), 4.48, 0.005));
+    assert_eq!(Color::wcag_level(passing, white, Normal), WcagLevel::Aa);
+    assert_eq!(Color::wcag_level(failing, white, Normal), WcagLevel::Fail);
+    assert_eq!(Color::wcag_level(failing, white, Large), WcagLevel::Aa);
+    assert_eq!(Color::wcag_level(black, white, Normal), WcagLevel::Aaa);
+    assert!(Color::wcag_level(passing, white, Large) >= WcagLevel::Aa);
+
+    // Relative luminance matches the CIE Y component.
+    let color = Color::new(Rgb::new(127, 255, 64));
+    assert!(close(color.relative_luminance(), Xyz::from(color).y(), 0.0005));
+}
+
+/// Tests APCA lightness contrast against the reference implementation.
+#[test]
+fn apca_contrast_reference() {
+    let pairs = [
+        (0x888888, 0xFFFFFF, 63.056),
+        (0xFFFFFF, 0x888888, -68.541),
+        (0x000000, 0xAAAAAA, 58.146),
+        (0xAAAAAA, 0x000000, -56.241),
+        (0x112233, 0xDDEEFF, 91.668),
+        (0xDDEEFF, 0x112233, -93.068),
+        (0x000000, 0xFFFFFF, 106.04),
+        (0xFFFFFF, 0x000000, -107.88),
+    ];
+    for &(text, background, expected) in &pairs {
+        let lc = Color::apca_contrast(Rgb::from(text), Rgb::from(background));
+        assert!(close(lc, expected, 0.01), "{:06X} on {:06X}: {}",
+            text, background, lc);
+    }
+
+    // Identical and near-identical colors have no contrast.
+    let gray = Rgb::from(0x777777);
+    assert_eq!(Color::apca_contrast(gray, gray), 0.0);
+    assert_eq!(Color::apca_contrast(gray, Rgb::from(0x7A7A7A)), 0.0);
+}
+
+/// Tests adjusting colors to meet a target contrast.
+#[test]
+fn adjust_for_contrast() {
+    let white = Color::new(Rgb::new(255, 255, 255));
+    let black = Color::new(Rgb::new(0, 0, 0));
+    let navy = Color::new(Rgb::new(0x1B, 0x2A, 0x4A));
+
+    for &hex in &[0x3399FF, 0xFF6600, 0x22AA44, 0xCC00CC, 0x808080, 0xFFFF00] {
+        let color = Color::new(Rgb::from(hex)).with_alpha(200);
+        for &background in &[white, black, navy] {
+            for &target in &[3.0, 4.5, 7.0] {
+                let adjusted = match color.adjust_for_contrast(background, target) {
+                    Some(adjusted) => adjusted,
+                    None => continue,
+                };
+                assert!(Color::contrast_ratio(adjusted, background) >= target,
+                    "{:06X} {:?} {}", hex, background, target);
+                assert_eq!(adjusted.alpha(), 200);
+                // Chromatic colors keep their hue.
+                if color.lch_chroma() > 20.0 && adjusted.lch_chroma() > 20.0 {
+                    let shift = (adjusted.lch_hue() - color.lch_hue()).abs();
+                    assert!(shift.min(360.0 - shift) < 15.0,
+                        "{:06X} {:?} {}", hex, adjusted, target);
+                }
+            }
+        }
+    }
+
+    // Colors which already pass are unchanged.
+    assert_eq!(black.adjust_for_contrast(white, 7.0), Some(black));
+    // Black and white are always reachable against extreme backgrounds.
+    let gray = Color::new(Rgb::new(128, 128, 128));
+    assert!(gray.adjust_for_contrast(white, 7.0).is_some());
+    assert!(gray.adjust_for_contrast(black, 7.0).is_some());
+    // No color has more than 21:1 contrast, and mid-gray limits both ways.
+    assert_eq!(gray.adjust_for_contrast(white, 21.5), None);
+    assert_eq!(white.adjust_for_contrast(gray, 7.0), None);
+}
+
+/// Tests choosing the best text color for a background.
+#[test]
+fn best_text_color() {
+    let light = Color::new(Rgb::new(0xEE, 0xEE, 0xDD));
+    let dark = Color::new(Rgb::new(0x22, 0x22, 0x33));
+    let candidates = [light, dark];
+
+    assert_eq!(Color::best_text_color(Rgb::new(255, 255, 255), &candidates),
+        Some(dark));
+    assert_eq!(Color::best_text_color(Rgb::new(0, 0, 40), &candidates),
+        Some(light));
+    assert_eq!(Color::best_text_color(Rgb::new(0, 0, 0), &[]), None);
+    assert_eq!(Color::best_text_color(dark, &[light, light.with_alpha(0)]),
+        Some(light));
+}
+
+/// Tests color vision deficiency simulation.
+#[test]
+fn simulate_cvd() {
+    let kinds = [CvdKind::Protan, CvdKind::Deutan, CvdKind::Tritan];
+
+    // Neutral colors are unaffected at any severity.
+    for &kind in &kinds {
+        for &severity in &[0.0, 0.35, 1.0] {
+            for &gray in &[0x000000, 0x808080, 0xFFFFFF] {
+                let color = Color::new(Rgb::from(gray));
+                let simulated = color.simulate_cvd(kind, severity);
+                assert_eq!(simulated, color, "{:?} {} {:06X}", kind, severity, gray);
+            }
+        }
+    }
+
+    let red = Color::new(Rgb::new(0xD0, 0x20, 0x20));
+    let green = Color::new(Rgb::new(0x20, 0xA0, 0x20));
+    for &kind in &kinds {
+        // Zero severity is the identity, and severity is clamped.
+        assert_eq!(red.simulate_cvd(kind, 0.0), red);
+        assert_eq!(red.simulate_cvd(kind, -1.0), red);
+        assert_eq!(red.simulate_cvd(kind, 2.0), red.simulate_cvd(kind, 1.0));
+        // Severities between tabulated matrices are interpolated linearly.
+        let pastel = Rgb::new(0xC0, 0x90, 0x80);
+        let mid = cvd::simulate(pastel, kind, 0.55);
+        let low = cvd::simulate(pastel, kind, 0.5);
+        let high = cvd::simulate(pastel, kind, 0.6);
+        assert!((mid.r - (low.r + high.r) / 2.0).abs() < 1e-6);
+        assert!((mid.g - (low.g + high.g) / 2.0).abs() < 1e-6);
+        assert!((mid.b - (low.b + high.b) / 2.0).abs() < 1e-6);
+    }
+
+    // Red and green become harder to tell apart with increasing severity for
+    // red-green deficiencies, but not for tritan deficiencies.
+    for &kind in &[CvdKind::Protan, CvdKind::Deutan] {
+        let mut previous = Color::delta_e_2000(red, green);
+        for &severity in &[0.25, 0.5, 0.75, 1.0] {
+            let distance = Color::delta_e_2000(
+                red.simulate_cvd(kind, severity),
+                green.simulate_cvd(kind, severity));
+            assert!(distance < previous, "{:?} {}", kind, severity);
+            previous = distance;
+        }
+    }
+    let tritan = Color::delta_e_2000(
+        red.simulate_cvd(CvdKind::Tritan, 1.0),
+        green.simulate_cvd(CvdKind::Tritan, 1.0));
+    assert!(tritan > 0.5 * Color::delta_e_2000(red, green));
+}
+
+/// Tests daltonization of colors for color vision deficiencies.
+#[test]
+fn daltonize() {
+    for &kind in &CvdKind::ALL {
+        // Neutral colors are unaffected.
+        for &gray in &[0x000000, 0x808080, 0xFFFFFF] {
+            let color = Color::new(Rgb::from(gray));
+            assert_eq!(color.daltonize(kind), color, "{:?} {:06X}", kind, gray);
+        }
+        assert_eq!(Color::new(Rgb::from(0x3399FF)).with_alpha(10)
+            .daltonize(kind).alpha(), 10);
+    }
+
+    // Daltonized colors are easier to tell apart for the affected viewer.
+    let pairs = [
+        (CvdKind::Protan, 0xC04040, 0x609040),
+        (CvdKind::Deutan, 0xC04040, 0x609040),
+        (CvdKind::Deutan, 0xE08030, 0x80A040),
+        (CvdKind::Tritan, 0x4080C0, 0x40A080),
+    ];
+    for &(kind, a, b) in &pairs {
+        let (a, b) = (Color::new(Rgb::from(a)), Color::new(Rgb::from(b)));
+        let before = Color::delta_e_2000(
+            a.simulate_cvd(kind, 1.0),
+            b.simulate_cvd(kind, 1.0));
+        let after = Color::delta_e_2000(
+            a.daltonize(kind).simulate_cvd(kind, 1.0),
+            b.daltonize(kind).simulate_cvd(kind, 1.0));
+        assert!(after > before, "{:?} {:?} {:?}", kind, a, b);
+    }
+}
+
+/// Tests checking palettes for colors which conflict under color vision
+/// deficiencies.
+#[test]
+fn palette_conflicts() {
+    let palette: Vec<Color> = [0xD03030, 0x30A030, 0x3060D0, 0xC08000]
+        .iter()
+        .map(|&hex| Color::new(Rgb::from(hex)))
+        .collect();
+
+    // Every pair is distinguishable with normal vision.
+    for (i, &a) in palette.iter().enumerate() {
+        for &b in &palette[i + 1..] {
+            assert!(Color::delta_e_2000(a, b) > 20.0);
+        }
+    }
+
+    let conflicts = cvd::palette_conflicts(&palette, 10.0);
+    let pairs: Vec<_> = conflicts
+        .iter()
+        .map(|c| (c.kind, c.first, c.second))
+        .collect();
+    assert_eq!(pairs, vec![
+        (CvdKind::Protan, 1, 3),
+        (CvdKind::Deutan, 0, 1),
+        (CvdKind::Deutan, 1, 3),
+    ]);
+    for conflict in &conflicts {
+        assert!(conflict.delta_e < 10.0);
+        assert!(conflict.first < conflict.second);
+    }
+
+    // The threshold bounds the reported differences.
+    assert!(cvd::palette_conflicts(&palette, 0.0).is_empty());
+    assert_eq!(cvd::palette_conflicts(&palette, 1000.0).len(), 18);
+    assert!(cvd::palette_conflicts(&palette[..1], 1000.0).is_empty());
+    assert!(cvd::palette_conflicts(&[], 1000.0).is_empty());
+}
+
+/// Tests generating color harmonies on each hue wheel.
+#[test]
+fn color_harmonies() {
+    let red = Color::new(Rgb::new(255, 0, 0));
+    let green = Color::new(Rgb::new(0, 255, 0));
+    let blue = Color::new(Rgb::new(0, 0, 255));
+    let yellow = Color::new(Rgb::new(255, 255, 0));
+
+    // The RYB wheel pairs the painter's complements.
+    assert_eq!(red.complement(HueWheel::Ryb), green);
+    assert_eq!(green.complement(HueWheel::Ryb), red);
+    assert_eq!(blue.complement(HueWheel::Ryb), Color::new(Rgb::new(255, 127, 0)));
+    assert_eq!(yellow.complement(HueWheel::Ryb), Color::new(Rgb::new(169, 0, 255)));
+    assert_eq!(red.triadic(HueWheel::Ryb), [red, yellow, blue]);
+
+    // The HSV wheel pairs the additive complements.
+    assert_eq!(red.complement(HueWheel::Hsv), Color::new(Rgb::new(0, 255, 255)));
+    assert_eq!(red.triadic(HueWheel::Hsv), [red, green, blue]);
+    assert_eq!(red.square(HueWheel::Hsv)[2], red.complement(HueWheel::Hsv));
+    assert_eq!(red.tetradic(HueWheel::Hsv)[2], red.complement(HueWheel::Hsv));
+
+    // The LCh wheel preserves lightness and chroma for in-gamut results.
+    let muted = Color::new(Rgb::new(0x99, 0x77, 0x66)).with_alpha(100);
+    for &wheel in &[HueWheel::Hsv, HueWheel::Lch, HueWheel::Ryb] {
+        for harmony in &[
+            muted.triadic(wheel).to_vec(),
+            muted.tetradic(wheel).to_vec(),
+            muted.square(wheel).to_vec(),
+            muted.split_complementary(wheel).to_vec(),
+            muted.analogous(5, 90.0, wheel),
+        ] {
+            for color in harmony {
+                assert_eq!(color.alpha(), 100);
+                if wheel == HueWheel::Lch {
+                    assert!((color.lab_lightness() - muted.lab_lightness()).abs() < 1.0);
+                    assert!((color.lch_chroma() - muted.lch_chroma()).abs() < 1.0);
+                }
+            }
+        }
+        let back = muted.complement(wheel).complement(wheel);
+        assert!(Color::delta_e_2000(back, muted) < 1.0, "{:?}", wheel);
+    }
+
+    // Analogous colors are centered on the color.
+    assert!(red.analogous(0, 60.0, HueWheel::Hsv).is_empty());
+    assert_eq!(red.analogous(1, 60.0, HueWheel::Hsv), vec![red]);
+    let analogous = muted.analogous(5, 120.0, HueWheel::Lch);
+    assert_eq!(analogous[2], muted);
+    let hues: Vec<f32> = analogous.iter().map(|c| c.lch_hue()).collect();
+    for pair in hues.windows(2) {
+        let step = (pair[1] - pair[0]).rem_euclid(360.0);
+        assert!((step - 30.0).abs() < 1.0, "{:?}", hues);
+    }
+}
+
+/// Tests generating monochromatic colors.
+#[test]
+fn monochromatic() {
+    let color = Color::new(Rgb::new(0xCC, 0x33, 0x66)).with_alpha(50);
+    assert!(color.monochromatic(0).is_empty());
+
+    let colors = color.monochromatic(7);
+    assert_eq!(colors.len(), 7);
+    for pair in colors.windows(2) {
+        assert!(pair[0].lab_lightness() < pair[1].lab_lightness());
+    }
+    for c in &colors {
+        assert_eq!(c.alpha(), 50);
+        assert_ne!(c.rgb_hex(), 0x000000);
+        assert_ne!(c.rgb_hex(), 0xFFFFFF);
+        let shift = (c.lch_hue() - color.lch_hue()).abs();
+        assert!(shift.min(360.0 - shift) < 10.0, "{:?}", c);
+    }
+}
+
+/// Tests generating tints, shades, and tones.
+#[test]
+fn tints_shades_tones() {
+    let color = Color::new(Rgb::new(0xCC, 0x33, 0x66)).with_alpha(80);
+    let white = Color::new(Rgb::new(255, 255, 255)).with_alpha(80);
+    let black = Color::new(Rgb::new(0, 0, 0)).with_alpha(80);
+
+    assert!(color.tints(0).is_empty());
+    assert_eq!(color.tints(1), vec![
+        Color::rgb_linear_interpolate(color, white, 0.5).with_alpha(80)]);
+
+    let tints = color.tints(4);
+    let shades = color.shades(4);
+    let tones = color.tones(4);
+    assert_eq!((tints.len(), shades.len(), tones.len()), (4, 4, 4));
+    assert!(!tints.contains(&white) && !tints.contains(&color));
+    assert!(!shades.contains(&black) && !shades.contains(&color));
+
+    let mut previous = color;
+    for &tint in &tints {
+        assert!(tint.lab_lightness() > previous.lab_lightness());
+        assert_eq!(tint.alpha(), 80);
+        previous = tint;
+    }
+    let mut previous = color;
+    for &shade in &shades {
+        assert!(shade.lab_lightness() < previous.lab_lightness());
+        assert_eq!(shade.alpha(), 80);
+        previous = shade;
+    }
+    let mut previous = color;
+    for &tone in &tones {
+        assert!(tone.lch_chroma() < previous.lch_chroma());
+        assert_eq!(tone.alpha(), 80);
+        previous = tone;
+    }
+}
+
+/// Tests generating color scales.
+#[test]
+fn color_scale() {
+    let brand = Color::new(Rgb::new(0x25, 0x63, 0xEB)).with_alpha(40);
+    let scale = brand.scale(&Color::SCALE_STEPS);
+    assert_eq!(scale.len(), Color::SCALE_STEPS.len());
+    assert!(brand.scale(&[]).is_empty());
+
+    // Lightness decreases in even steps, apart from the half steps at each
+    // end.
+    let lightness: Vec<f32> = scale.iter()
+        .map(|c| c.oklch_components()[0])
+        .collect();
+    assert!((lightness[0] - 0.97).abs() < 0.01);
+    assert!((lightness[10] - 0.27).abs() < 0.01);
+    for i in 1..9 {
+        let step = lightness[i] - lightness[i + 1];
+        assert!((step - 0.0778).abs() < 0.01, "{:?}", lightness);
+    }
+
+    // The scale keeps the hue of the color.
+    let hue = brand.oklch_components()[2];
+    for color in &scale {
+        assert_eq!(color.alpha(), 40);
+        let shift = (color.oklch_components()[2] - hue).abs();
+        assert!(shift.min(360.0 - shift) < 10.0, "{:?}", color);
+    }
+
+    // Steps outside of the conventional range saturate at black and white.
+    let ends = brand.scale(&[0, 1000]);
+    assert!(ends[0].lab_lightness() > 98.0);
+    assert!(ends[1].lab_lightness() < 25.0);
+}
+
+/// Tests sampling multi-stop gradients.
+#[test]
+fn gradient_sample() {
+    let red = Color::new(Rgb::new(255, 0, 0));
+    let green = Color::new(Rgb::new(0, 255, 0));
+    let blue = Color::new(Rgb::new(0, 0, 255)).with_alpha(0);
+    let spaces = [
+        InterpolationSpace::Rgb,
+        InterpolationSpace::LinearRgb,
+        InterpolationSpace::Hsl,
+        InterpolationSpace::Hsv,
+        InterpolationSpace::Lab,
+        InterpolationSpace::Oklab,
+        InterpolationSpace::Lch,
+        InterpolationSpace::Oklch,
+    ];
+
+    for &space in &spaces {
+        for &mode in &[InterpolationMode::Linear, InterpolationMode::Cubic] {
+            let gradient = Gradient::from_stops(vec![
+                    (10.0, red),
+                    (30.0, blue),
+                    (20.0, green),
+                ])
+                .unwrap()
+                .with_space(space)
+                .with_mode(mode);
+
+            // Stops are reproduced exactly, and the ends are extended.
+            assert_eq!(gradient.sample(10.0), red, "{:?}", space);
+            assert_eq!(gradient.sample(20.0), green, "{:?}", space);
+            assert_eq!(gradient.sample(30.0), blue, "{:?}", space);
+            assert_eq!(gradient.sample(-5.0), red);
+            assert_eq!(gradient.sample(f32::INFINITY), blue);
+            assert_eq!(gradient.sample(f32::NAN), red);
+
+            // Alpha is interpolated linearly.
+            assert_eq!(gradient.sample(25.0).alpha(), 128);
+
+            // Samples near a stop are close to it.
+            assert!(Color::delta_e_2000(gradient.sample(19.9), green) < 2.0,
+                "{:?} {:?}", space, mode);
+            assert!(Color::delta_e_2000(gradient.sample(20.1), green) < 2.0,
+                "{:?} {:?}", space, mode);
+        }
+    }
+
+    // Cubic interpolation eases into and out of each stop.
+    let black = Color::new(Rgb::new(0, 0, 0));
+    let white = Color::new(Rgb::new(255, 255, 255));
+    let linear = Gradient::new(black, white)
+        .with_space(InterpolationSpace::Rgb);
+    let cubic = linear.clone().with_mode(InterpolationMode::Cubic);
+    assert_eq!(linear.sample(0.5), cubic.sample(0.5));
+    assert!(cubic.sample(0.2).red() < linear.sample(0.2).red());
+    assert!(cubic.sample(0.8).red() > linear.sample(0.8).red());
+
+    // Stops at the same position give a hard edge.
+    let edge = Gradient::new(black, white)
+        .with_stop(0.5, red)
+        .with_stop(0.5, green);
+    assert_eq!(edge.stops()[1..3], [(0.5, red), (0.5, green)]);
+    assert_eq!(edge.sample(0.5), green);
+    assert!(Color::delta_e_2000(edge.sample(0.4999), red) < 1.0);
+
+    // A single stop has a constant color.
+    let single = Gradient::from_stops(vec![(0.3, red)]).unwrap();
+    assert_eq!(single.sample(0.0), red);
+    assert_eq!(single.sample(0.3), red);
+    assert_eq!(single.sample(1.0), red);
+
+    assert_eq!(Gradient::from_stops(vec![]), None);
+    assert_eq!(Gradient::from_stops(vec![(f32::NAN, red)]), None);
+    assert_eq!(Gradient::from_stops(vec![(0.0, red), (f32::INFINITY, red)]),
+        None);
+}
+
+/// Tests taking evenly spaced samples from a gradient and reversing it.
+#[test]
+fn gradient_take_and_reverse() {
+    let red = Color::new(Rgb::new(255, 0, 0));
+    let yellow = Color::new(Rgb::new(255, 255, 0));
+    let blue = Color::new(Rgb::new(0, 0, 255));
+    let gradient = Gradient::from_stops(vec![
+            (-1.0, red),
+            (0.0, yellow),
+            (3.0, blue),
+        ])
+        .unwrap()
+        .with_space(InterpolationSpace::Oklch);
+
+    assert_eq!(gradient.take(0).count(), 0);
+    assert_eq!(gradient.take(1).collect::<Vec<_>>(), vec![red]);
+    let colors: Vec<_> = gradient.take(5).collect();
+    assert_eq!(colors, vec![
+        red,
+        yellow,
+        gradient.sample(1.0),
+        gradient.sample(2.0),
+        blue,
+    ]);
+
+    let reversed = gradient.reversed();
+    assert_eq!(reversed.space(), InterpolationSpace::Oklch);
+    assert_eq!(reversed.stops(), &[(-1.0, blue), (2.0, yellow), (3.0, red)]);
+    assert_eq!(reversed.reversed(), gradient);
+    for &position in &[-1.0, -0.5, 0.0, 0.7, 1.5, 3.0] {
+        assert_eq!(reversed.sample(2.0 - position), gradient.sample(position));
+    }
+    let mut backward: Vec<_> = reversed.take(5).collect();
+    backward.reverse();
+    assert_eq!(backward, colors);
+}
+
+/// Tests interpolating hues in each direction around the hue circle.
+#[test]
+fn hue_interpolation() {
+    use HueInterpolation::*;
+    let cases = [
+        (350.0, 10.0, Shorter, 0.0),
+        (350.0, 10.0, Longer, 180.0),
+        (350.0, 10.0, Increasing, 0.0),
+        (350.0, 10.0, Decreasing, 180.0),
+        (10.0, 350.0, Shorter, 0.0),
+        (10.0, 350.0, Longer, 180.0),
+        (10.0, 350.0, Increasing, 180.0),
+        (10.0, 350.0, Decreasing, 0.0),
+        (90.0, 90.0, Longer, 270.0),
+        (90.0, 90.0, Increasing, 90.0),
+    ];
+    for &(start, end, direction, mid) in &cases {
+        let hsl = Hsl::linear_interpolate(
+            Hsl::new(start, 1.0, 0.5),
+            Hsl::new(end, 1.0, 0.5),
+            0.5,
+            direction);
+        let hsv = Hsv::cubic_interpolate(
+            Hsv::new(start, 1.0, 1.0),
+            Hsv::new(end, 1.0, 1.0),
+            0.0,
+            0.0,
+            0.5,
+            direction);
+        let lch = Lch::linear_interpolate(
+            Lch::new(50.0, 40.0, start),
+            Lch::new(50.0, 40.0, end),
+            0.5,
+            direction);
+        let oklch = Oklch::cubic_interpolate(
+            Oklch::new(0.5, 0.1, start),
+            Oklch::new(0.5, 0.1, end),
+            0.0,
+            0.0,
+            0.5,
+            direction);
+        for &hue in &[hsl.hue(), hsv.hue(), lch.hue(), oklch.hue()] {
+            assert!(close(hue, mid, 0.001),
+                "{} -> {} {:?}: {} != {}", start, end, direction, hue, mid);
+        }
+    }
+
+    // The hue of a gray is replaced by the hue of the other color.
+    let gray = Hsl::new(0.0, 0.0, 0.5);
+    let blue = Hsl::new(240.0, 1.0, 0.5);
+    for &direction in &[Shorter, Increasing, Decreasing] {
+        let mid = Hsl::linear_interpolate(gray, blue, 0.5, direction);
+        assert!(close(mid.hue(), 240.0, 0.001), "{:?}", direction);
+    }
+
+    // Color interpolation passes the direction through.
+    let red = Color::new(Rgb::new(255, 0, 0));
+    let green = Color::new(Rgb::new(0, 255, 0));
+    assert_eq!(Color::hsv_linear_interpolate(red, green, 0.5, Shorter),
+        Color::new(Rgb::new(255, 255, 0)));
+    assert_eq!(Color::hsv_linear_interpolate(red, green, 0.5, Longer),
+        Color::new(Rgb::new(0, 0, 255)));
+    assert_eq!(Color::hsl_linear_interpolate(red, green, 0.5, Decreasing),
+        Color::new(Rgb::new(0, 0, 255)));
+
+    // Reversed gradients pass through the same hues.
+    let gradient = Gradient::new(red, green)
+        .with_space(InterpolationSpace::Hsv)
+        .with_hue_interpolation(Increasing);
+    let reversed = gradient.reversed();
+    assert_eq!(reversed.hue_interpolation(), Decreasing);
+    for &position in &[0.0, 0.2, 0.5, 0.9, 1.0] {
+        assert_eq!(gradient.sample(position), reversed.sample(1.0 - position));
+    }
+}
+
+/// Tests that negative hues are wrapped into the hue circle.
+#[test]
+fn negative_hues() {
+    assert!(close(Hsl::new(-60.0, 1.0, 0.5).hue(), 300.0, UNIT));
+    assert!(close(Hsv::new(-390.0, 1.0, 1.0).hue(), 330.0, UNIT));
+
+    let mut hsl = Hsl::new(10.0, 1.0, 0.5);
+    hsl.set_hue(-10.0);
+    assert!(close(hsl.hue(), 350.0, UNIT));
+
+    let mut color = Color::new(Rgb::new(255, 0, 0));
+    color.shift_hue(-60.0);
+    assert_eq!(color, Color::new(Rgb::new(255, 0, 255)));
+}
+
+/// Tests spline interpolation through several values.
+#[test]
+fn spline_kinds() {
+    let values = [0.0, 10.0, 10.0, 40.0];
+    let kinds = [SplineKind::CatmullRom, SplineKind::BSpline, SplineKind::Monotone];
+
+    for &kind in &kinds {
+        assert_eq!(kind.interpolate(&[5.0], 0.3), 5.0, "{:?}", kind);
+        assert!(close(kind.interpolate(&values, 0.0), 0.0, 0.0001));
+        assert!(close(kind.interpolate(&values, 1.0), 40.0, 0.0001));
+        assert!(close(kind.interpolate(&values, -1.0), 0.0, 0.0001));
+        assert!(close(kind.interpolate(&values, 2.0), 40.0, 0.0001));
+        assert!(close(kind.interpolate(&values, f32::NAN), 0.0, 0.0001));
+        // Two values give a straight line.
+        assert!(close(kind.interpolate(&[2.0, 4.0], 0.25), 2.5, 0.0001));
+    }
+
+    // Interpolating splines pass through every value.
+    for &kind in &[SplineKind::CatmullRom, SplineKind::Monotone] {
+        for (i, &value) in values.iter().enumerate() {
+            let amount = i as f32 / 3.0;
+            assert!(close(kind.interpolate(&values, amount), value, 0.0001),
+                "{:?} {}", kind, i);
+        }
+    }
+
+    // Only the monotone spline stays flat between equal values.
+    let flat = 1.0 / 3.0 + 0.05;
+    assert!(SplineKind::CatmullRom.interpolate(&values, flat) > 10.0);
+    assert!(close(SplineKind::Monotone.interpolate(&values, flat), 10.0, 0.0001));
+    for step in 0..=30 {
+        let amount = step as f32 / 30.0;
+        let value = SplineKind::Monotone.interpolate(&values, amount);
+        assert!((0.0..=40.0).contains(&value), "{} {}", amount, value);
+    }
+
+    // The B-spline is pulled toward the inner values.
+    let middle = SplineKind::BSpline.interpolate(&values, 1.0 / 3.0);
+    assert!(close(middle, (0.0 + 4.0 * 10.0 + 10.0) / 6.0, 0.0001));
+}
+
+/// Tests spline interpolation through several colors.
+#[test]
+fn spline_interpolate() {
+    let colors = [
+        Color::new(Rgb::new(0, 0, 0)).with_alpha(0),
+        Color::new(Rgb::new(0, 0, 255)),
+        Color::new(Rgb::new(255, 255, 0)).with_alpha(100),
+    ];
+
+    let mid = Color::spline_interpolate(
+        &colors, 0.5, SplineKind::Monotone, InterpolationSpace::LinearRgb);
+    assert_eq!(mid, Color::new(Rgb::new(0, 0, 255)));
+    let end = Color::spline_interpolate(
+        &colors, 1.0, SplineKind::BSpline, InterpolationSpace::Lab);
+    assert_eq!(end.alpha(), 100);
+    let quarter = Color::spline_interpolate(
+        &colors, 0.25, SplineKind::CatmullRom, InterpolationSpace::Rgb);
+    assert!(quarter.alpha() > 0 && quarter.alpha() < 255);
+
+    // Hues take the shorter way between neighboring colors.
+    let hues = [
+        Color::from(Oklch::new(0.6, 0.1, 340.0)),
+        Color::from(Oklch::new(0.6, 0.1, 10.0)),
+        Color::from(Oklch::new(0.6, 0.1, 40.0)),
+    ];
+    let kinds = [SplineKind::CatmullRom, SplineKind::BSpline, SplineKind::Monotone];
+    for &kind in &kinds {
+        for step in 0..=10 {
+            let amount = step as f32 / 10.0;
+            let color = Color::spline_interpolate(
+                &hues, amount, kind, InterpolationSpace::Oklch);
+            let hue = Oklch::from(color).hue();
+            assert!(!(50.0..=330.0).contains(&hue), "{:?} {} {}", kind, amount, hue);
+        }
+    }
+}
+
+/// Tests that splines ignore the powerless hue of achromatic colors, and round
+/// sRGB components as mixing does.
+#[test]
+fn spline_interpolate_achromatic() {
+    use HueInterpolation::Shorter;
+    let red = Color::new(Rgb::new(255, 0, 0));
+    let white = Color::new(Rgb::new(255, 255, 255));
+    let black = Color::new(Rgb::new(0, 0, 0));
+    let kind = SplineKind::CatmullRom;
+
+    for &space in &[InterpolationSpace::Lch, InterpolationSpace::Oklch,
+        InterpolationSpace::Hsl, InterpolationSpace::Rgb]
+    {
+        assert_eq!(Color::spline_interpolate(&[red, white], 0.5, kind, space),
+            Color::mix(red, white, space, (None, None), Shorter).unwrap(),
+            "{:?}", space);
+    }
+    assert_eq!(Color::spline_interpolate(&[red, white], 0.5, kind,
+            InterpolationSpace::Lch),
+        Color::new(Rgb::new(255, 158, 129)));
+
+    // An achromatic inner color takes the hue of its earlier neighbor.
+    let blue = Color::new(Rgb::new(0, 0, 255));
+    let gray = Color::new(Rgb::new(128, 128, 128));
+    let quarter = Color::spline_interpolate(
+        &[red, gray, blue], 0.25, SplineKind::Monotone, InterpolationSpace::Oklch);
+    let hue = Oklch::from(quarter).hue();
+    assert!(close(hue, Oklch::from(red).hue(), 1.0), "{}", hue);
+
+    assert_eq!(Color::spline_interpolate(&[black, white], 0.5, kind,
+            InterpolationSpace::Rgb),
+        Color::new(Rgb::new(128, 128, 128)));
+}
+
+/// Tests easing functions.
+#[test]
+fn easing() {
+    let easings = [
+        Easing::Linear,
+        Easing::Smoothstep,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::SineIn,
+        Easing::SineOut,
+        Easing::SineInOut,
+        Easing::EASE,
+        Easing::EASE_IN,
+        Easing::EASE_OUT,
+        Easing::EASE_IN_OUT,
+        Easing::CubicBezier(0.1, 0.7, 0.9, 0.2),
+    ];
+
+    for &easing in &easings {
+        assert!(close(easing.apply(0.0), 0.0, 0.0001), "{:?}", easing);
+        assert!(close(easing.apply(1.0), 1.0, 0.0001), "{:?}", easing);
+        assert!(close(easing.apply(-1.0), 0.0, 0.0001), "{:?}", easing);
+        assert!(close(easing.apply(2.0), 1.0, 0.0001), "{:?}", easing);
+
+        let mut previous = 0.0;
+        for step in 1..=20 {
+            let amount = step as f32 / 20.0;
+            let eased = easing.apply(amount);
+            assert!(eased >= previous - 0.0001, "{:?} {}", easing, amount);
+            previous = eased;
+
+            let reversed = 1.0 - easing.reversed().apply(1.0 - amount);
+            assert!(close(eased, reversed, 0.0001), "{:?} {}", easing, amount);
+        }
+    }
+
+    // Reference values of the CSS timing functions.
+    assert!(close(Easing::EASE.apply(0.5), 0.8024, 0.0001));
+    assert!(close(Easing::EASE_IN.apply(0.5), 0.3153, 0.0001));
+    assert!(close(Easing::EASE_OUT.apply(0.5), 0.6847, 0.0001));
+    assert!(close(Easing::EASE_IN_OUT.apply(0.5), 0.5, 0.0001));
+
+    // Control points on the diagonal give a linear easing.
+    let diagonal = Easing::CubicBezier(0.25, 0.25, 0.75, 0.75);
+    assert!(close(diagonal.apply(0.3), 0.3, 0.0001));
+
+    // Only the y coordinates may leave the unit interval.
+    let back = Easing::CubicBezier(0.5, -0.5, 0.5, 1.5);
+    assert!(back.apply(0.1) < 0.0);
+    assert!(back.apply(0.9) > 1.0);
+    let clamped = Easing::CubicBezier(-1.0, 0.0, 2.0, 1.0);
+    assert_eq!(clamped.apply(0.5),
+        Easing::CubicBezier(0.0, 0.0, 1.0, 1.0).apply(0.5));
+}
+
+/// Tests easing between gradient stops.
+#[test]
+fn gradient_easing() {
+    let black = Color::new(Rgb::new(0, 0, 0));
+    let gray = Color::new(Rgb::new(100, 100, 100));
+    let white = Color::new(Rgb::new(200, 200, 200));
+    let gradient = Gradient::new(black, white)
+        .with_stop(0.5, gray)
+        .with_space(InterpolationSpace::Rgb)
+        .with_easing(Easing::QuadIn);
+
+    assert_eq!(gradient.easing(), Easing::QuadIn);
+    assert_eq!(gradient.sample(0.25), Color::new(Rgb::new(25, 25, 25)));
+    assert_eq!(gradient.sample(0.5), gray);
+    assert_eq!(gradient.sample(0.75), Color::new(Rgb::new(125, 125, 125)));
+
+    let reversed = gradient.reversed();
+    assert_eq!(reversed.easing(), Easing::QuadOut);
+    for &position in &[0.0, 0.1, 0.25, 0.6, 0.75, 1.0] {
+        let (a, b) = (gradient.sample(position), reversed.sample(1.0 - position));
+        let (a, b) = (Rgb::from(a), Rgb::from(b));
+        assert!((i16::from(a.r) - i16::from(b.r)).abs() <= 1,
+            "{} {:?} {:?}", position, a, b);
+    }
+}
+
+/// Tests that deserialized gradients are validated like `Gradient::from_stops`.
+#[cfg(feature = "serde")]
+#[test]
+fn gradient_deserialize() {
+    let black = Color::new(Rgb::new(0, 0, 0));
+    let white = Color::new(Rgb::new(255, 255, 255));
+    let gradient = Gradient::new(black, white)
+        .with_space(InterpolationSpace::Lab)
+        .with_easing(Easing::QuadIn);
+
+    let value = serde_json::to_value(&gradient).unwrap();
+    let round_trip: Gradient = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(round_trip, gradient);
+
+    // Stops are sorted by position.
+    let mut unsorted = value.clone();
+    unsorted["stops"].as_array_mut().unwrap().reverse();
+    let sorted: Gradient = serde_json::from_value(unsorted).unwrap();
+    assert_eq!(sorted, gradient);
+
+    // Empty stops are rejected.
+    let mut empty = value;
+    empty["stops"] = serde_json::json!([]);
+    let error = serde_json::from_value::<Gradient>(empty).unwrap_err();
+    assert!(error.to_string().contains("non-empty"), "{}", error);
+}
+
+/// Tests mixing colors as the CSS color-mix() function does.
+#[test]
+fn color_mix() {
+    use HueInterpolation::*;
+    let red = Color::new(Rgb::new(255, 0, 0));
+    let lime = Color::new(Rgb::new(0, 255, 0));
+    let blue = Color::new(Rgb::new(0, 0, 255));
+    let white = Color::new(Rgb::new(255, 255, 255));
+    let gray = Color::new(Rgb::new(128, 128, 128));
+    let transparent = Color::new(Rgb::new(0, 0, 0)).with_alpha(0);
+    let srgb = InterpolationSpace::Rgb;
+
+    // Percentage normalization.
+    let even = Color::mix(red, blue, srgb, (None, None), Shorter);
+    assert_eq!(even, Some(Color::new(Rgb::new(128, 0, 128))));
+    assert_eq!(Color::mix(red, blue, srgb, (Some(60.0), Some(60.0)), Shorter), even);
+    assert_eq!(Color::mix(red, blue, srgb, (Some(25.0), None), Shorter),
+        Color::mix(red, blue, srgb, (Some(25.0), Some(75.0)), Shorter));
+    assert_eq!(Color::mix(red, blue, srgb, (None, Some(25.0)), Shorter),
+        Some(Color::new(Rgb::new(191, 0, 64))));
+    assert_eq!(Color::mix(red, blue, srgb, (Some(100.0), None), Shorter),
+        Some(red));
+
+    // Percentages summing below 100 reduce the alpha.
+    assert_eq!(Color::mix(red, blue, srgb, (Some(20.0), Some(20.0)), Shorter),
+        Some(Color::new(Rgb::new(128, 0, 128)).with_alpha(102)));
+
+    // Invalid percentages.
+    for &percentages in &[
+        (Some(0.0), Some(0.0)),
+        (Some(-10.0), None),
+        (None, Some(150.0)),
+        (Some(f32::NAN), Some(50.0)),
+    ] {
+        assert_eq!(Color::mix(red, blue, srgb, percentages, Shorter), None,
+            "{:?}", percentages);
+    }
+
+    // Interpolation uses premultiplied alpha.
+    assert_eq!(Color::mix(red, transparent, srgb, (None, None), Shorter),
+        Some(red.with_alpha(128)));
+    assert_eq!(Color::mix(
+            red.with_alpha(51),
+            blue.with_alpha(204),
+            srgb,
+            (None, None),
+            Shorter),
+        Some(Color::new(Rgb::new(51, 0, 204)).with_alpha(128)));
+
+    // Opaque colors mix as they interpolate.
+    for &space in &[InterpolationSpace::LinearRgb, InterpolationSpace::Lab,
+        InterpolationSpace::Oklab]
+    {
+        assert_eq!(Color::mix(red, blue, space, (Some(30.0), None), Shorter),
+            Some(space.interpolate(
+                red, blue, InterpolationMode::Linear, Shorter, 0.7)),
+            "{:?}", space);
+    }
+
+    // Hue interpolation.
+    assert_eq!(Color::mix(red, lime, InterpolationSpace::Hsl, (None, None), Longer),
+        Some(blue));
+    assert_eq!(Color::mix(red, lime, InterpolationSpace::Hsv, (None, None), Decreasing),
+        Some(blue));
+
+    // Achromatic colors take the hue of the other color.
+    let mixed = Color::mix(gray, blue, InterpolationSpace::Hsl, (None, None), Shorter)
+        .unwrap();
+    assert!(close(Hsl::from(mixed).hue(), 240.0, 0.5));
+    let mixed = Color::mix(white, blue, InterpolationSpace::Lch, (None, None), Shorter)
+        .unwrap();
+    assert!(close(Lch::from(mixed).hue(), Lch::from(blue).hue(), 2.0));
+    let mixed = Color::mix(white, blue, InterpolationSpace::Oklch, (None, None), Shorter)
+        .unwrap();
+    assert!(close(Oklch::from(mixed).hue(), Oklch::from(blue).hue(), 2.0));
+}
+
+/// Tests that Color interpolation interpolates the alpha component.
+#[test]
+fn color_interpolate_alpha() {
+    use HueInterpolation::Shorter;
+    let red = Color::new(Rgb::new(255, 0, 0)).with_alpha(100);
+    let blue = Color::new(Rgb::new(0, 0, 255)).with_alpha(100);
+    let clear = Color::new(Rgb::new(0, 0, 255)).with_alpha(0);
+
+    let linear: [fn(Color, Color, f32) -> Color; 10] = [
+        Color::rgb_linear_interpolate,
+        Color::cmyk_linear_interpolate,
+        |a, b, t| Color::hsl_linear_interpolate(a, b, t, Shorter),
+        |a, b, t| Color::hsv_linear_interpolate(a, b, t, Shorter),
+        Color::xyz_linear_interpolate,
+        Color::linear_rgb_linear_interpolate,
+        Color::lab_linear_interpolate,
+        |a, b, t| Color::lch_linear_interpolate(a, b, t, Shorter),
+        Color::oklab_linear_interpolate,
+        |a, b, t| Color::oklch_linear_interpolate(a, b, t, Shorter),
+    ];
+    let cubic: [fn(Color, Color, f32) -> Color; 10] = [
+        |a, b, t| Color::rgb_cubic_interpolate(a, b, 0.0, 0.0, t),
+        |a, b, t| Color::cmyk_cubic_interpolate(a, b, 0.0, 0.0, t),
+        |a, b, t| Color::hsl_cubic_interpolate(a, b, 0.0, 0.0, t, Shorter),
+        |a, b, t| Color::hsv_cubic_interpolate(a, b, 0.0, 0.0, t, Shorter),
+        |a, b, t| Color::xyz_cubic_interpolate(a, b, 0.0, 0.0, t),
+        |a, b, t| Color::linear_rgb_cubic_interpolate(a, b, 0.0, 0.0, t),
+        |a, b, t| Color::lab_cubic_interpolate(a, b, 0.0, 0.0, t),
+        |a, b, t| Color::lch_cubic_interpolate(a, b, 0.0, 0.0, t, Shorter),
+        |a, b, t| Color::oklab_cubic_interpolate(a, b, 0.0, 0.0, t),
+        |a, b, t| Color::oklch_cubic_interpolate(a, b, 0.0, 0.0, t, Shorter),
+    ];
+
+    for (i, interpolate) in linear.iter().chain(cubic.iter()).enumerate() {
+        assert_eq!(interpolate(red, blue, 0.5).alpha(), 100, "{}", i);
+        assert_eq!(interpolate(red, clear, 0.0).alpha(), 100, "{}", i);
+        assert_eq!(interpolate(red, clear, 0.25).alpha(), 75, "{}", i);
+        assert_eq!(interpolate(red, clear, 1.0).alpha(), 0, "{}", i);
+    }
+}
@@ -10,6 +10,9 @@
 //! Defines general purpose functions common use.
 //!
 ////////////////////////////////////////////////////////////////////////////////
+// Local imports.
+use crate::HueInterpolation;
+
 // Standard library imports.
 use std::f32;
 use std::ops::Sub;
@@ -339,3 +342,188 @@ pub fn cerp_f32(
         + (-2.0*a3 + 3.0*a2) * e
         + (a3 - a2) * end_slope
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// normalize_hue
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the given hue in degrees wrapped into the range `[0, 360)`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::utility::normalize_hue;
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// assert_eq!(normalize_hue(370.0), 10.0);
+/// assert_eq!(normalize_hue(-30.0), 330.0);
+/// assert_eq!(normalize_hue(360.0), 0.0);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+#[inline]
+pub fn normalize_hue(hue: f32) -> f32 {
+    let h = hue.rem_euclid(360.0);
+    // Tiny negative values can round up to exactly 360.
+    if h >= 360.0 { 0.0 } else { h }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// lerp_hue
+////////////////////////////////////////////////////////////////////////////////
+/// Performs a linear interpolation between the hues `start` and `end` in
+/// degrees, going around the hue circle in the direction given by
+/// `interpolation` and returning the hue located at the ratio given by
+/// `amount`, which is clamped between 0 and 1.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::HueInterpolation;
+/// # use color::utility::lerp_hue;
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let shorter = HueInterpolation::Shorter;
+/// assert_eq!(lerp_hue(350.0, 10.0, 0.25, shorter), 355.0);
+/// assert_eq!(lerp_hue(10.0, 350.0, 0.75, shorter), 355.0);
+/// assert_eq!(lerp_hue(30.0, 90.0, 0.5, shorter), 60.0);
+///
+/// assert_eq!(lerp_hue(30.0, 90.0, 0.5, HueInterpolation::Longer), 240.0);
+/// assert_eq!(lerp_hue(90.0, 30.0, 0.5, HueInterpolation::Increasing), 240.0);
+/// assert_eq!(lerp_hue(30.0, 90.0, 0.5, HueInterpolation::Decreasing), 240.0);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+#[inline]
+pub fn lerp_hue(
+    start: f32,
+    end: f32,
+    amount: f32,
+    interpolation: HueInterpolation)
+    -> f32
+{
+    let a = clamped(amount, 0.0, 1.0);
+    normalize_hue(start + interpolation.delta(start, end) * a)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// cerp_hue
+////////////////////////////////////////////////////////////////////////////////
+/// Performs a cubic interpolation between the hues `start` and `end` in
+/// degrees, going around the hue circle in the direction given by
+/// `interpolation` and returning the hue located at the ratio given by
+/// `amount`, which is clamped between 0 and 1. The interpolation function will
+/// be consistent with the slopes given by `start_slope` and `end_slope`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::HueInterpolation;
+/// # use color::utility::cerp_hue;
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// assert_eq!(cerp_hue(350.0, 10.0, 0.0, 0.0, 0.5,
+///     HueInterpolation::Shorter), 0.0);
+/// assert_eq!(cerp_hue(350.0, 10.0, 0.0, 0.0, 0.5,
+///     HueInterpolation::Longer), 180.0);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+#[inline]
+pub fn cerp_hue(
+    start: f32,
+    end: f32,
+    start_slope: f32,
+    end_slope: f32,
+    amount: f32,
+    interpolation: HueInterpolation)
+    -> f32
+{
+    let e = start + interpolation.delta(start, end);
+    normalize_hue(cerp_f32(start, e, start_slope, end_slope, amount))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// srgb_to_linear
+////////////////////////////////////////////////////////////////////////////////
+/// Converts a gamma-encoded sRGB component ratio into linear light using the
+/// sRGB transfer function. Negative values are mirrored about zero.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::utility::srgb_to_linear;
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// assert_eq!(srgb_to_linear(0.0), 0.0);
+/// assert_eq!(srgb_to_linear(1.0), 1.0);
+/// assert_eq!(srgb_to_linear(0.5), 0.21404114);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+#[inline]
+pub fn srgb_to_linear(value: f32) -> f32 {
+    let abs = value.abs();
+    if abs <= 0.04045 {
+        value / 12.92
+    } else {
+        ((abs + 0.055) / 1.055).powf(2.4).copysign(value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// linear_to_srgb
+////////////////////////////////////////////////////////////////////////////////
+/// Converts a linear light component ratio into a gamma-encoded sRGB ratio
+/// using the inverse sRGB transfer function. Negative values are mirrored about
+/// zero.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use color::utility::linear_to_srgb;
+/// # fn example() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// assert_eq!(linear_to_srgb(0.0), 0.0);
+/// assert_eq!(linear_to_srgb(0.21404114), 0.5);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     example().unwrap();
+/// # }
+/// ```
+#[inline]
+pub fn linear_to_srgb(value: f32) -> f32 {
+    let abs = value.abs();
+    if abs <= 0.0031308 {
+        value * 12.92
+    } else {
+        (1.055 * abs.powf(1.0 / 2.4) - 0.055).copysign(value)
+    }
+}